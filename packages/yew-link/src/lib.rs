@@ -456,14 +456,14 @@ pub fn LinkProvider(props: &LinkProviderProps) -> Html {
     #[cfg(target_arch = "wasm32")]
     let cache: Cache = {
         let cap = NonZeroUsize::new(props.cache_capacity).unwrap_or(NonZeroUsize::MIN);
-        (*use_ref(|| Rc::new(RefCell::new(LruCache::new(cap))))).clone()
+        (*use_ref(|| -> Cache { Rc::new(RefCell::new(LruCache::new(cap))) })).clone()
     };
     #[cfg(not(target_arch = "wasm32"))]
-    let cache: Cache = (*use_ref(|| Rc::new(RefCell::new(HashMap::new())))).clone();
+    let cache: Cache = (*use_ref(Cache::default)).clone();
     #[cfg(target_arch = "wasm32")]
-    let in_flight: InFlight = (*use_ref(|| Rc::new(RefCell::new(HashMap::new())))).clone();
+    let in_flight: InFlight = (*use_ref(InFlight::default)).clone();
     #[cfg(target_arch = "wasm32")]
-    let refreshing: Refreshing = (*use_ref(|| Rc::new(RefCell::new(HashSet::new())))).clone();
+    let refreshing: Refreshing = (*use_ref(Refreshing::default)).clone();
 
     let ctx = LinkContextInner {
         cache,
@@ -117,6 +117,18 @@ impl PropField {
         }
     }
 
+    /// Returns `false` from `ShallowEq::shallow_eq` if this field differs
+    pub fn to_shallow_eq_check(&self) -> proc_macro2::TokenStream {
+        let name = &self.name;
+        let extra_attrs = &self.extra_attrs;
+        quote! {
+            #( #extra_attrs )*
+            if !(&::yew::html::ShallowEqField(&self.#name, &other.#name)).shallow_eq() {
+                return false;
+            }
+        }
+    }
+
     /// Wrap all required props in `Option`
     pub fn to_field_def(&self) -> proc_macro2::TokenStream {
         let ty = &self.ty;
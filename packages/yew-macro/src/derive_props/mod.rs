@@ -28,6 +28,8 @@ pub struct DerivePropsInput {
     props_name: Ident,
     prop_fields: Vec<PropField>,
     preserved_attrs: Vec<Attribute>,
+    /// Whether `ShallowEq` is derived, see `#[shallow_eq]`.
+    shallow_eq: bool,
 }
 
 /// AST visitor that replaces all occurrences of the keyword `Self` with `new_self`
@@ -144,12 +146,23 @@ impl Parse for DerivePropsInput {
             .cloned()
             .collect();
 
+        let mut shallow_eq = false;
+        for attr in input
+            .attrs
+            .iter()
+            .filter(|a| a.path().is_ident("shallow_eq"))
+        {
+            attr.meta.require_path_only()?;
+            shallow_eq = true;
+        }
+
         Ok(Self {
             vis: input.vis,
             props_name: input.ident,
             generics: input.generics,
             prop_fields,
             preserved_attrs,
+            shallow_eq,
         })
     }
 }
@@ -175,6 +188,7 @@ impl ToTokens for DerivePropsInput {
             props_name,
             prop_fields,
             preserved_attrs,
+            shallow_eq,
             ..
         } = self;
 
@@ -211,5 +225,23 @@ impl ToTokens for DerivePropsInput {
             }
         };
         tokens.extend(properties);
+
+        // Comparing the fields requires them to implement `PartialEq`, unlike the properties
+        // themselves, so it is opt-in.
+        if *shallow_eq {
+            let shallow_eq_checks = prop_fields.iter().map(PropField::to_shallow_eq_check);
+            tokens.extend(quote! {
+                impl #impl_generics ::yew::html::ShallowEq for #props_name #ty_generics #where_clause {
+                    #[allow(unused_variables)]
+                    fn shallow_eq(&self, other: &Self) -> ::std::primitive::bool {
+                        #[allow(unused_imports)]
+                        use ::yew::html::{ShallowEqPtr as _, ShallowEqValue as _};
+
+                        #(#shallow_eq_checks)*
+                        true
+                    }
+                }
+            });
+        }
     }
 }
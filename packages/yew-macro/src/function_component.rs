@@ -1,11 +1,11 @@
 use proc_macro2::{Span, TokenStream};
-use quote::{ToTokens, quote};
+use quote::{ToTokens, quote, quote_spanned};
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
-use syn::token::{Comma, Fn};
+use syn::token::{Comma, Fn, Paren};
 use syn::{
-    Attribute, Block, FnArg, Generics, Ident, Item, ItemFn, LitStr, ReturnType, Type, Visibility,
-    parse_quote, parse_quote_spanned, visit_mut,
+    Attribute, Block, Expr, FnArg, Generics, Ident, Item, ItemFn, LitStr, ReturnType, Token, Type,
+    Visibility, parenthesized, parse_quote, parse_quote_spanned, visit_mut,
 };
 
 use crate::hook::BodyRewriter;
@@ -23,6 +23,7 @@ pub struct FunctionComponent {
     fn_token: Fn,
 
     component_name: Option<Ident>,
+    memo: Option<Memo>,
 }
 
 impl Parse for FunctionComponent {
@@ -148,6 +149,7 @@ impl Parse for FunctionComponent {
             return_type,
             fn_token: sig.fn_token,
             component_name: None,
+            memo: None,
         })
     }
 }
@@ -201,6 +203,7 @@ impl FunctionComponent {
         }
 
         self.component_name = name.component_name;
+        self.memo = name.memo;
 
         Ok(())
     }
@@ -277,6 +280,37 @@ impl FunctionComponent {
 
         let (impl_generics, ty_generics, where_clause) = static_comp_generics.split_for_impl();
 
+        let changed = match self.memo {
+            Some(ref memo) => {
+                let eq = match memo {
+                    Memo::PartialEq => quote! { <Self::Properties as ::std::cmp::PartialEq>::eq },
+                    Memo::Shallow(span) => {
+                        quote_spanned! {*span=> <Self::Properties as ::yew::html::ShallowEq>::shallow_eq }
+                    }
+                    Memo::With(eq) => eq.to_token_stream(),
+                };
+
+                quote! {
+                    #[inline]
+                    fn changed(&mut self, ctx: &::yew::html::Context<Self>, old_props: &Self::Properties) -> ::std::primitive::bool {
+                        let eq: fn(&Self::Properties, &Self::Properties) -> ::std::primitive::bool = #eq;
+                        !eq(old_props, ::yew::html::Context::<Self>::props(ctx))
+                    }
+                }
+            }
+            None => quote! {
+                #[inline]
+                fn changed(&mut self, _ctx: &::yew::html::Context<Self>, _old_props: &Self::Properties) -> ::std::primitive::bool {
+                    true
+                }
+            },
+        };
+
+        let new = match self.memo {
+            Some(_) => Ident::new("new_memo", Span::mixed_site()),
+            None => Ident::new("new", Span::mixed_site()),
+        };
+
         // TODO: replace with blanket implementation when specialisation becomes stable.
         quote! {
             #[automatically_derived]
@@ -288,7 +322,7 @@ impl FunctionComponent {
                 fn create(ctx: &::yew::html::Context<Self>) -> Self {
                     Self {
                         _marker: ::std::marker::PhantomData,
-                        function_component: ::yew::functional::FunctionComponent::<Self>::new(ctx),
+                        function_component: ::yew::functional::FunctionComponent::<Self>::#new(ctx),
                     }
                 }
 
@@ -297,10 +331,7 @@ impl FunctionComponent {
                     true
                 }
 
-                #[inline]
-                fn changed(&mut self, _ctx: &::yew::html::Context<Self>, _old_props: &Self::Properties) -> ::std::primitive::bool {
-                    true
-                }
+                #changed
 
                 #[inline]
                 fn view(&self, ctx: &::yew::html::Context<Self>) -> ::yew::html::HtmlResult {
@@ -315,6 +346,11 @@ impl FunctionComponent {
                     ::yew::functional::FunctionComponent::<Self>::rendered(&self.function_component)
                 }
 
+                #[inline]
+                fn layout_rendered(&mut self, _ctx: &::yew::html::Context<Self>) {
+                    ::yew::functional::FunctionComponent::<Self>::layout_rendered(&self.function_component)
+                }
+
                 #[inline]
                 fn destroy(&mut self, _ctx: &::yew::html::Context<Self>) {
                     ::yew::functional::FunctionComponent::<Self>::destroy(&self.function_component)
@@ -392,22 +428,83 @@ impl FunctionComponent {
     }
 }
 
+/// How the properties of a memoized component are compared.
+#[derive(Clone)]
+enum Memo {
+    /// `memo`
+    PartialEq,
+    /// `memo(shallow)`, with the span of `shallow`
+    Shallow(Span),
+    /// `memo = eq`
+    With(Expr),
+}
+
+impl Memo {
+    fn peek(input: ParseStream) -> bool {
+        input
+            .fork()
+            .parse::<Ident>()
+            .is_ok_and(|ident| ident == "memo")
+    }
+}
+
+impl Parse for Memo {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident = input.parse::<Ident>()?;
+        if ident != "memo" {
+            return Err(syn::Error::new_spanned(ident, "expected `memo`"));
+        }
+
+        if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            return Ok(Self::With(input.parse()?));
+        }
+
+        if input.peek(Paren) {
+            let content;
+            parenthesized!(content in input);
+            let option = content.parse::<Ident>()?;
+            if option != "shallow" {
+                return Err(syn::Error::new_spanned(option, "expected `shallow`"));
+            }
+            return Ok(Self::Shallow(option.span()));
+        }
+
+        Ok(Self::PartialEq)
+    }
+}
+
 pub struct FunctionComponentName {
     component_name: Option<Ident>,
+    memo: Option<Memo>,
 }
 
 impl Parse for FunctionComponentName {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        if input.is_empty() {
-            return Ok(Self {
-                component_name: None,
-            });
+        let mut component_name = None;
+        if !input.is_empty() && !Memo::peek(input) {
+            component_name = Some(input.parse()?);
+
+            // Only options may follow the name, other tokens are reported as unexpected.
+            let fork = input.fork();
+            if fork.parse::<Comma>().is_err() || !Memo::peek(&fork) {
+                return Ok(Self {
+                    component_name,
+                    memo: None,
+                });
+            }
+            input.parse::<Comma>()?;
         }
 
-        let component_name = input.parse()?;
+        let memo = if input.is_empty() {
+            None
+        } else {
+            Some(input.parse()?)
+        };
 
         Ok(Self {
-            component_name: Some(component_name),
+            component_name,
+            memo,
         })
     }
 }
@@ -5,7 +5,7 @@ use syn::spanned::Spanned;
 use syn::visit_mut::VisitMut;
 use syn::{
     Expr, ExprCall, ExprClosure, ExprForLoop, ExprIf, ExprLoop, ExprMatch, ExprWhile, Ident, Item,
-    parse_quote_spanned, visit_mut,
+    LitStr, parse_quote, parse_quote_spanned, visit_mut,
 };
 
 #[derive(Debug)]
@@ -36,6 +36,23 @@ impl BodyRewriter {
     }
 }
 
+/// Renders the output of a hook as the value recorded with the `debug-hooks` feature.
+///
+/// The output type is only known where the hook is called, so the [`Debug`] implementation is
+/// detected there. This requires the output type to be known once the hook has been called.
+fn debug_value() -> ExprClosure {
+    if cfg!(feature = "debug-hooks") {
+        parse_quote! {
+            |output| {
+                use ::yew::functional::{HookOutputDebug as _, HookOutputOpaque as _, HookOutputState as _};
+                (&&&::yew::functional::HookOutput(output)).debug_value()
+            }
+        }
+    } else {
+        parse_quote! { |_| ::std::option::Option::None }
+    }
+}
+
 impl VisitMut for BodyRewriter {
     fn visit_expr_call_mut(&mut self, i: &mut ExprCall) {
         let ctx_ident = &self.ctx_ident;
@@ -52,7 +69,9 @@ impl VisitMut for BodyRewriter {
                             note = "see: https://yew.rs/docs/next/concepts/function-components/hooks"
                         );
                     } else {
-                        *i = parse_quote_spanned! { i.span() => ::yew::functional::Hook::run(#i, #ctx_ident) };
+                        let kind = LitStr::new(&m.to_string(), m.span());
+                        let debug_value = debug_value();
+                        *i = parse_quote_spanned! { i.span() => ::yew::functional::__run_hook(#i, #ctx_ident, #kind, #debug_value) };
                     }
 
                     return;
@@ -78,7 +97,9 @@ impl VisitMut for BodyRewriter {
                                 note = "see: https://yew.rs/docs/next/concepts/function-components/hooks"
                             );
                         } else {
-                            *i = parse_quote_spanned! { i.span() => ::yew::functional::Hook::run(#i, #ctx_ident) };
+                            let kind = LitStr::new(&ident.to_string(), ident.span());
+                            let debug_value = debug_value();
+                            *i = parse_quote_spanned! { i.span() => ::yew::functional::__run_hook(#i, #ctx_ident, #kind, #debug_value) };
                         }
                     } else {
                         visit_mut::visit_expr_macro_mut(self, m);
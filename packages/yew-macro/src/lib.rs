@@ -103,7 +103,10 @@ fn is_ide_completion() -> bool {
     }
 }
 
-#[proc_macro_derive(Properties, attributes(prop_or, prop_or_else, prop_or_default))]
+#[proc_macro_derive(
+    Properties,
+    attributes(prop_or, prop_or_else, prop_or_default, shallow_eq)
+)]
 pub fn derive_props(input: TokenStream) -> TokenStream {
     let mut input = parse_macro_input!(input as DerivePropsInput);
     input.normalise();
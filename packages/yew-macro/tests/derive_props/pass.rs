@@ -272,4 +272,40 @@ mod value_into_some_value_in_props {
     }
 }
 
+mod manual_partial_eq {
+    // Fields do not need to implement `PartialEq` unless `#[shallow_eq]` is used.
+    #[derive(::yew::Properties)]
+    struct Props {
+        id: u32,
+        callback: ::std::boxed::Box<dyn ::std::ops::Fn()>,
+    }
+
+    impl ::std::cmp::PartialEq for Props {
+        fn eq(&self, other: &Self) -> bool {
+            self.id == other.id
+        }
+    }
+
+    #[::yew::component(memo(shallow))]
+    fn Comp(_props: &Props) -> ::yew::html::Html {
+        ::yew::html! {}
+    }
+}
+
+mod shallow_eq {
+    #[derive(::yew::Properties, ::std::cmp::PartialEq)]
+    #[shallow_eq]
+    struct Props {
+        id: u32,
+        rows: ::std::rc::Rc<::std::vec::Vec<u32>>,
+        #[prop_or_default]
+        title: ::yew::virtual_dom::AttrValue,
+    }
+
+    #[::yew::component(memo(shallow))]
+    fn Comp(_props: &Props) -> ::yew::html::Html {
+        ::yew::html! {}
+    }
+}
+
 fn main() {}
@@ -0,0 +1,44 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+#[shallow_eq]
+struct Props {
+    a: usize,
+    b: Rc<String>,
+}
+
+fn same_a(lhs: &Props, rhs: &Props) -> bool {
+    lhs.a == rhs.a
+}
+
+#[component(memo)]
+fn Comp(props: &Props) -> Html {
+    html! {
+        <p>{ props.a }</p>
+    }
+}
+
+#[component(Named, memo(shallow))]
+fn comp_2(props: &Props) -> Html {
+    html! {
+        <p>{ props.a }</p>
+    }
+}
+
+#[component(memo = same_a)]
+fn Comp3(props: &Props) -> Html {
+    html! {
+        <p>{ props.a }</p>
+    }
+}
+
+#[component(memo = |lhs: &Props, rhs: &Props| lhs.b == rhs.b)]
+fn Comp4(props: &Props) -> Html {
+    html! {
+        <p>{ props.a }</p>
+    }
+}
+
+fn main() {}
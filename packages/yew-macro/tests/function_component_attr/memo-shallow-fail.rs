@@ -0,0 +1,15 @@
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+struct Props {
+    a: usize,
+}
+
+#[component(memo(shallow))]
+fn Comp(props: &Props) -> Html {
+    html! {
+        <p>{ props.a }</p>
+    }
+}
+
+fn main() {}
@@ -0,0 +1,120 @@
+//! Compares mounting a keyed list of all rows with mounting a `VirtualList` in the browser, and
+//! measures scrolling a `VirtualList`, which reconciles its keyed window of rows with
+//! `BList::apply_keyed`.
+//!
+//! Run with `cargo bench --features csr --target wasm32-unknown-unknown` in `packages/yew`. The
+//! server-side rendering of the same lists is measured by `tools/benchmark-core`.
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+use std::cell::Cell;
+
+use wasm_bindgen_test::{Criterion, wasm_bindgen_bench, wasm_bindgen_test_configure};
+use web_sys::{Element, Event};
+use yew::prelude::*;
+use yew::scheduler::flush_sync;
+use yew::virtual_list::{ItemHeight, VirtualList, VirtualListHandle};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const ROWS: usize = 10_000;
+const ROW_HEIGHT: f64 = 24.0;
+
+fn row(index: usize) -> Html {
+    html! {
+        <div class="row">
+            <span>{index}</span>
+            <strong>{"A row of a long list"}</strong>
+        </div>
+    }
+}
+
+#[component]
+fn KeyedList() -> Html {
+    html! {
+        <div>
+            { for (0..ROWS).map(|index| html! { <div key={index}>{row(index)}</div> }) }
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq, Default)]
+struct VirtualizedListProps {
+    #[prop_or_default]
+    handle: VirtualListHandle,
+}
+
+#[component]
+fn VirtualizedList(props: &VirtualizedListProps) -> Html {
+    html! {
+        <VirtualList
+            item_count={ROWS}
+            item={Callback::from(row)}
+            item_height={ItemHeight::Fixed(ROW_HEIGHT)}
+            height={600.0}
+            handle={props.handle.clone()}
+            class="list"
+        />
+    }
+}
+
+/// Returns a new element attached to the document to mount a list into.
+fn root() -> Element {
+    let document = gloo::utils::document();
+    let root = document.create_element("div").unwrap();
+    document.body().unwrap().append_child(&root).unwrap();
+    root
+}
+
+/// Mounts `COMP`, renders it and removes it again.
+fn mount<COMP>()
+where
+    COMP: BaseComponent,
+    COMP::Properties: Default,
+{
+    let root = root();
+    let app = flush_sync(|| yew::Renderer::<COMP>::with_root(root.clone()).render());
+    flush_sync(|| app.destroy());
+    root.remove();
+}
+
+#[wasm_bindgen_bench]
+fn keyed_list_mount(c: &mut Criterion) {
+    let c = &mut std::mem::take(c).sample_size(10);
+    c.bench_function("keyed_list_mount", |b| b.iter(mount::<KeyedList>));
+}
+
+#[wasm_bindgen_bench]
+fn virtual_list_mount(c: &mut Criterion) {
+    c.bench_function("virtual_list_mount", |b| b.iter(mount::<VirtualizedList>));
+}
+
+#[wasm_bindgen_bench]
+fn virtual_list_scroll(c: &mut Criterion) {
+    let root = root();
+    let handle = VirtualListHandle::default();
+    let props = VirtualizedListProps {
+        handle: handle.clone(),
+    };
+    let app = flush_sync(|| {
+        yew::Renderer::<VirtualizedList>::with_root_and_props(root.clone(), props).render()
+    });
+    let container = root.query_selector(".list").unwrap().unwrap();
+
+    // Scrolls by ten rows at a time, so that every render keeps some of the rendered rows.
+    let index = Cell::new(0);
+    c.bench_function("virtual_list_scroll", |b| {
+        b.iter(|| {
+            index.set((index.get() + 10) % ROWS);
+            flush_sync(|| {
+                handle.scroll_to_index(index.get());
+                // The browser dispatches the scroll event asynchronously.
+                container
+                    .dispatch_event(&Event::new("scroll").unwrap())
+                    .unwrap();
+            });
+        })
+    });
+
+    flush_sync(|| app.destroy());
+    root.remove();
+}
@@ -28,10 +28,14 @@ where
         name = "mount",
         skip(props),
     )]
-    pub(crate) fn mount_with_props(host: Element, props: Rc<COMP::Properties>) -> Self {
+    pub(crate) fn mount_with_props(
+        host: Element,
+        props: Rc<COMP::Properties>,
+        id_prefix: Option<Rc<str>>,
+    ) -> Self {
         clear_element(&host);
         let app = Self {
-            scope: Scope::new(None),
+            scope: Scope::new(None).with_id_prefix(id_prefix),
         };
         let hosting_root = BSubtree::create_root(&host);
         let _ = app
@@ -88,6 +92,7 @@ fn clear_element(host: &Element) {
 mod feat_hydration {
     use super::*;
     use crate::dom_bundle::Fragment;
+    use crate::hydration::MismatchPolicy;
 
     impl<COMP> AppHandle<COMP>
     where
@@ -98,13 +103,19 @@ mod feat_hydration {
             name = "hydrate",
             skip(props),
         )]
-        pub(crate) fn hydrate_with_props(host: Element, props: Rc<COMP::Properties>) -> Self {
+        pub(crate) fn hydrate_with_props(
+            host: Element,
+            props: Rc<COMP::Properties>,
+            id_prefix: Option<Rc<str>>,
+            mismatch_policy: MismatchPolicy,
+        ) -> Self {
             let app = Self {
-                scope: Scope::new(None),
+                scope: Scope::new(None).with_id_prefix(id_prefix),
             };
 
             let mut fragment = Fragment::collect_children(&host);
             let hosting_root = BSubtree::create_root(&host);
+            hosting_root.set_mismatch_policy(mismatch_policy);
 
             let mut previous_next_sibling = None;
             app.scope.hydrate_in_place(
@@ -152,14 +152,14 @@ mod feat_hydration {
     impl Hydratable for VRaw {
         fn hydrate(
             self,
-            _root: &BSubtree,
+            root: &BSubtree,
             _parent_scope: &AnyScope,
             parent: &Element,
             fragment: &mut Fragment,
             prev_next_sibling: &mut Option<DynamicDomSlot>,
         ) -> Self::Bundle {
             let collectable = Collectable::Raw;
-            let fallback_fragment = Fragment::collect_between(fragment, &collectable, parent);
+            let fallback_fragment = Fragment::collect_between(root, fragment, &collectable, parent);
             let first_child = fallback_fragment.iter().next().cloned();
 
             if let (Some(first_child), prev_next_sibling) = (&first_child, prev_next_sibling) {
@@ -7,6 +7,8 @@ use web_sys::Element;
 use super::Fragment;
 use super::{BNode, BSubtree, DomSlot, Reconcilable, ReconcileTarget};
 use crate::html::AnyScope;
+#[cfg(feature = "hydration")]
+use crate::hydration::HydrationTrigger;
 use crate::virtual_dom::{Key, VSuspense};
 
 #[derive(Debug)]
@@ -16,6 +18,11 @@ enum Fallback {
     /// Suspense Fallback with Hydration Fragment being rendered as placeholder.
     #[cfg(feature = "hydration")]
     Fragment(Fragment),
+    /// Server-side rendered content waiting for its hydration to be triggered.
+    ///
+    /// The children have not been hydrated yet and the content is left untouched.
+    #[cfg(feature = "hydration")]
+    Dehydrated(Fragment, HydrationTrigger),
 }
 
 /// The bundle implementation to [VSuspense]
@@ -45,7 +52,7 @@ impl ReconcileTarget for BSuspense {
                     }
 
                     #[cfg(feature = "hydration")]
-                    Fallback::Fragment(fragment) => {
+                    Fallback::Fragment(fragment) | Fallback::Dehydrated(fragment, _) => {
                         fragment.detach(root, parent, parent_to_detach);
                     }
                 }
@@ -63,7 +70,9 @@ impl ReconcileTarget for BSuspense {
         match self.fallback.as_ref() {
             Some(Fallback::Bundle(bundle)) => bundle.shift(next_parent, slot),
             #[cfg(feature = "hydration")]
-            Some(Fallback::Fragment(fragment)) => fragment.shift(next_parent, slot),
+            Some(Fallback::Fragment(fragment) | Fallback::Dehydrated(fragment, _)) => {
+                fragment.shift(next_parent, slot)
+            }
             None => self.children_bundle.shift(next_parent, slot),
         }
     }
@@ -84,6 +93,7 @@ impl Reconcilable for VSuspense {
             fallback,
             suspended,
             key,
+            ..
         } = self;
         let detached_parent = document()
             .create_element("div")
@@ -148,8 +158,25 @@ impl Reconcilable for VSuspense {
             fallback: vfallback,
             suspended,
             key: _,
+            #[cfg(feature = "hydration")]
+            lazy,
+            #[cfg(feature = "ssr")]
+                reveal: _,
         } = self;
 
+        #[cfg(feature = "hydration")]
+        if let Some(Fallback::Dehydrated(fragment, _)) = &suspense.fallback {
+            if lazy.is_some() {
+                // The server-side rendered content is kept until hydration is triggered.
+                return match fragment.front().cloned() {
+                    Some(m) => DomSlot::at(m),
+                    None => slot,
+                };
+            }
+
+            suspense.hydrate_children(root, parent_scope, &children, &slot);
+        }
+
         let children_bundle = &mut suspense.children_bundle;
         // no need to update key & detached_parent
 
@@ -171,10 +198,12 @@ impl Reconcilable for VSuspense {
                         vfallback.reconcile_node(root, parent_scope, parent, slot, bundle)
                     }
                     #[cfg(feature = "hydration")]
-                    Fallback::Fragment(fragment) => match fragment.front().cloned() {
-                        Some(m) => DomSlot::at(m),
-                        None => slot,
-                    },
+                    Fallback::Fragment(fragment) | Fallback::Dehydrated(fragment, _) => {
+                        match fragment.front().cloned() {
+                            Some(m) => DomSlot::at(m),
+                            None => slot,
+                        }
+                    }
                 }
             }
             // Not suspended, just reconcile the children into the DOM
@@ -206,7 +235,7 @@ impl Reconcilable for VSuspense {
                         bundle.detach(root, parent, false);
                     }
                     #[cfg(feature = "hydration")]
-                    Some(Fallback::Fragment(fragment)) => {
+                    Some(Fallback::Fragment(fragment) | Fallback::Dehydrated(fragment, _)) => {
                         fragment.detach(root, parent, false);
                     }
                     None => {
@@ -223,9 +252,51 @@ impl Reconcilable for VSuspense {
 
 #[cfg(feature = "hydration")]
 mod feat_hydration {
+    use web_sys::Node;
+
     use super::*;
-    use crate::dom_bundle::{DynamicDomSlot, Fragment, Hydratable};
-    use crate::virtual_dom::Collectable;
+    use crate::dom_bundle::{BList, DynamicDomSlot, Fragment, Hydratable};
+    use crate::virtual_dom::{Collectable, VNode};
+
+    impl BSuspense {
+        /// Hydrates the children of a boundary whose hydration has been deferred.
+        ///
+        /// Like a boundary hydrated on load, the children are hydrated against a copy of the
+        /// server-side rendered content, which stays in place until the boundary resumes.
+        pub(super) fn hydrate_children(
+            &mut self,
+            root: &BSubtree,
+            parent_scope: &AnyScope,
+            children: &VNode,
+            slot: &DomSlot,
+        ) {
+            let Some(Fallback::Dehydrated(fragment, trigger)) = self.fallback.take() else {
+                return;
+            };
+            drop(trigger);
+
+            let mut nodes = fragment.deep_clone();
+            for node in nodes.iter() {
+                self.detached_parent.append_child(node).unwrap();
+            }
+
+            let mut previous_next_sibling = None;
+            self.children_bundle = children.clone().hydrate(
+                root,
+                parent_scope,
+                &self.detached_parent,
+                &mut nodes,
+                &mut previous_next_sibling,
+            );
+            if let Some(previous_next_sibling) = previous_next_sibling {
+                previous_next_sibling.reassign(slot.clone());
+            }
+
+            nodes.finish(root, &self.detached_parent, "end of suspense");
+
+            self.fallback = Some(Fallback::Fragment(fragment));
+        }
+    }
 
     impl Hydratable for VSuspense {
         fn hydrate(
@@ -241,7 +312,59 @@ mod feat_hydration {
                 .expect("failed to create detached element");
 
             let collectable = Collectable::Suspense;
-            let fallback_fragment = Fragment::collect_between(fragment, &collectable, parent);
+
+            // The children of a boundary streamed out of order may not have been swapped in yet.
+            // Its opening tag carries the id of the boundary until then.
+            fragment.trim_start_text_nodes();
+            let pending = fragment.front().is_some_and(|m| {
+                m.node_type() == Node::COMMENT_NODE
+                    && m.text_content().is_some_and(|m| {
+                        m.strip_prefix(collectable.open_start_mark())
+                            .and_then(|m| m.strip_suffix(collectable.end_mark()))
+                            .is_some_and(|m| !m.is_empty())
+                    })
+            });
+
+            let fallback_fragment = Fragment::collect_between(root, fragment, &collectable, parent);
+
+            match self.lazy {
+                // Content that has not been streamed yet is rendered by the client right away.
+                Some((_, ref callback)) if pending => callback.emit(()),
+                Some((on, callback)) => {
+                    if let (Some(front), Some(previous_next_sibling)) =
+                        (fallback_fragment.front(), previous_next_sibling.take())
+                    {
+                        previous_next_sibling.reassign(DomSlot::at(front.clone()));
+                    }
+
+                    let trigger = HydrationTrigger::new(on, fallback_fragment.iter(), move || {
+                        callback.emit(())
+                    });
+
+                    return BSuspense {
+                        children_bundle: BNode::List(BList::new()),
+                        detached_parent,
+                        key: self.key,
+                        fallback: Some(Fallback::Dehydrated(fallback_fragment, trigger)),
+                    };
+                }
+                None => {}
+            }
+
+            if pending {
+                // The children are rendered by the client instead, the content streamed later is
+                // discarded as the boundary can no longer be found.
+                let (_, children_bundle) =
+                    self.children
+                        .attach(root, parent_scope, &detached_parent, DomSlot::at_end());
+
+                return BSuspense {
+                    children_bundle,
+                    detached_parent,
+                    key: self.key,
+                    fallback: Some(Fallback::Fragment(fallback_fragment)),
+                };
+            }
 
             let mut nodes = fallback_fragment.deep_clone();
 
@@ -259,10 +382,7 @@ mod feat_hydration {
                 previous_next_sibling,
             );
 
-            // We trim all leading text nodes before checking as it's likely these are whitespaces.
-            nodes.trim_start_text_nodes();
-
-            assert!(nodes.is_empty(), "expected end of suspense, found node.");
+            nodes.finish(root, &detached_parent, "end of suspense");
 
             BSuspense {
                 children_bundle,
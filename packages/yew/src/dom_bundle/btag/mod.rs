@@ -342,6 +342,52 @@ mod feat_hydration {
 
     use super::*;
     use crate::dom_bundle::{DynamicDomSlot, Fragment, Hydratable, node_type_str};
+    use crate::hydration::HydrationMismatch;
+
+    /// Reports attributes of a server-side rendered element that differ from the ones of the
+    /// virtual DOM node, they are updated when hydrating the element.
+    #[cfg(feature = "hydration_diagnostics")]
+    fn report_attribute_differences(root: &BSubtree, vtag: &VTag, el: &Element) {
+        let tag_name = vtag.tag();
+        let report = |message: String| {
+            root.report_repaired(
+                HydrationMismatch::new(message)
+                    .with_expected(vtag)
+                    .with_actual(Some(el)),
+            );
+        };
+
+        for (key, value) in vtag.attributes.iter() {
+            match el.get_attribute(key) {
+                Some(m) if m == value => {}
+                Some(m) => report(format!(
+                    "expected attribute {key}=\"{value}\" on {tag_name}, found \"{m}\"."
+                )),
+                None => report(format!(
+                    "expected attribute {key}=\"{value}\" on {tag_name}, found none."
+                )),
+            }
+        }
+
+        for name in el
+            .get_attribute_names()
+            .iter()
+            .filter_map(|m| m.as_string())
+        {
+            // The values of form fields are rendered as attributes by the server.
+            if matches!(name.as_str(), "value" | "checked") {
+                continue;
+            }
+
+            if !vtag
+                .attributes
+                .iter()
+                .any(|(k, _)| k.eq_ignore_ascii_case(&name))
+            {
+                report(format!("unexpected attribute {name} on {tag_name}."));
+            }
+        }
+    }
 
     impl Hydratable for VTag {
         fn hydrate(
@@ -354,6 +400,69 @@ mod feat_hydration {
         ) -> Self::Bundle {
             let tag_name = self.tag().to_owned();
 
+            // We trim all text nodes as it's likely these are whitespaces.
+            fragment.trim_start_text_nodes();
+
+            let mismatch = match fragment.front() {
+                None => Some(format!("expected element of type {tag_name}, found EOF.")),
+                Some(m) if m.node_type() != Node::ELEMENT_NODE => Some(format!(
+                    "expected element, found node type {}.",
+                    node_type_str(m),
+                )),
+                Some(m) => {
+                    let el_tag_name = m.unchecked_ref::<Element>().tag_name();
+                    let parent_namespace = _parent.namespace_uri();
+
+                    // In HTML namespace (or no namespace), createElement is case-insensitive
+                    // In other namespaces (SVG, MathML), createElementNS is case-sensitive
+                    let should_compare_case_insensitive = parent_namespace
+                        .as_deref()
+                        .is_none_or(|ns| ns == HTML_NAMESPACE);
+
+                    let matches = if should_compare_case_insensitive {
+                        // Case-insensitive comparison for HTML elements
+                        tag_name.eq_ignore_ascii_case(&el_tag_name)
+                    } else {
+                        // Case-sensitive comparison for namespaced elements (SVG, MathML)
+                        tag_name == el_tag_name
+                    };
+
+                    (!matches).then(|| {
+                        format!("expected element of kind {tag_name}, found {el_tag_name}.")
+                    })
+                }
+            };
+
+            if let Some(message) = mismatch {
+                root.report_mismatch(
+                    HydrationMismatch::new(message)
+                        .with_expected(&self)
+                        .with_actual(fragment.front()),
+                );
+
+                // The element is rendered in front of the mismatched node instead, which is
+                // removed together with the rest of the content that has not been hydrated.
+                let slot = DomSlot::create(
+                    fragment
+                        .front()
+                        .or_else(|| fragment.sibling_at_end())
+                        .cloned(),
+                );
+                let (_, btag) = self.attach(root, parent_scope, _parent, slot);
+
+                if let Some(prev_next_sibling) = prev_next_sibling {
+                    prev_next_sibling.reassign(DomSlot::at(btag.reference.clone().into()));
+                }
+                *prev_next_sibling = None;
+
+                return btag;
+            }
+
+            let el = fragment.pop_front().unwrap().unchecked_into::<Element>();
+
+            #[cfg(feature = "hydration_diagnostics")]
+            report_attribute_differences(root, &self, &el);
+
             let Self {
                 inner,
                 listeners,
@@ -362,45 +471,6 @@ mod feat_hydration {
                 key,
             } = self;
 
-            // We trim all text nodes as it's likely these are whitespaces.
-            fragment.trim_start_text_nodes();
-
-            let node = fragment
-                .pop_front()
-                .unwrap_or_else(|| panic!("expected element of type {tag_name}, found EOF."));
-
-            assert_eq!(
-                node.node_type(),
-                Node::ELEMENT_NODE,
-                "expected element, found node type {}.",
-                node_type_str(&node),
-            );
-            let el = node.dyn_into::<Element>().expect("expected an element.");
-
-            {
-                let el_tag_name = el.tag_name();
-                let parent_namespace = _parent.namespace_uri();
-
-                // In HTML namespace (or no namespace), createElement is case-insensitive
-                // In other namespaces (SVG, MathML), createElementNS is case-sensitive
-                let should_compare_case_insensitive = parent_namespace
-                    .as_deref()
-                    .is_none_or(|ns| ns == HTML_NAMESPACE);
-
-                if should_compare_case_insensitive {
-                    // Case-insensitive comparison for HTML elements
-                    assert!(
-                        tag_name.eq_ignore_ascii_case(&el_tag_name),
-                        "expected element of kind {tag_name}, found {el_tag_name}.",
-                    );
-                } else {
-                    // Case-sensitive comparison for namespaced elements (SVG, MathML)
-                    assert_eq!(
-                        el_tag_name, tag_name,
-                        "expected element of kind {tag_name}, found {el_tag_name}.",
-                    );
-                }
-            }
             // We simply register listeners and update all attributes.
             let attributes = attributes.apply(root, &el);
             let listeners = listeners.apply(root, &el);
@@ -425,9 +495,7 @@ mod feat_hydration {
                         prev_next_child.reassign(DomSlot::at_end());
                     }
 
-                    nodes.trim_start_text_nodes();
-
-                    assert!(nodes.is_empty(), "expected EOF, found node.");
+                    nodes.finish(root, &el, "EOF");
 
                     BTagInner::Other { child_bundle, tag }
                 }
@@ -95,16 +95,21 @@ mod feat_hydration {
 
     use super::*;
     use crate::dom_bundle::{DynamicDomSlot, Fragment, Hydratable};
+    #[cfg(feature = "hydration_diagnostics")]
+    use crate::hydration::HydrationMismatch;
 
     impl Hydratable for VText {
         fn hydrate(
             self,
-            _root: &BSubtree,
+            root: &BSubtree,
             _parent_scope: &AnyScope,
             parent: &Element,
             fragment: &mut Fragment,
             previous_next_sibling: &mut Option<DynamicDomSlot>,
         ) -> Self::Bundle {
+            #[cfg(not(feature = "hydration_diagnostics"))]
+            let _ = root;
+
             let create_at = |next_sibling: Option<Node>, text: AttrValue| {
                 // If there are multiple text nodes placed back-to-back in SSR, it may be parsed as
                 // a single text node by browser, hence we need to add extra text
@@ -122,12 +127,24 @@ mod feat_hydration {
                         // pop current node.
                         fragment.pop_front();
 
-                        // TODO: It may make sense to assert the text content in the text node
-                        // against the VText when #[cfg(debug_assertions)]
-                        // is true, but this may be complicated.
-                        // We always replace the text value for now.
+                        // Adjacent text nodes are merged by the browser, so the text node only has
+                        // to start with the text of this VText. We always replace the text value.
                         //
                         // Please see the next comment for a detailed explanation.
+                        #[cfg(feature = "hydration_diagnostics")]
+                        if !m
+                            .node_value()
+                            .is_some_and(|n| n.starts_with(self.text.as_ref()))
+                        {
+                            root.report_repaired(
+                                HydrationMismatch::new(format!(
+                                    "expected text {:?}.",
+                                    self.text.as_ref()
+                                ))
+                                .with_expected(&self)
+                                .with_actual(Some(&m)),
+                            );
+                        }
                         m.set_node_value(Some(self.text.as_ref()));
 
                         BText {
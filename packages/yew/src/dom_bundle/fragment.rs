@@ -4,7 +4,8 @@ use std::ops::{Deref, DerefMut};
 use wasm_bindgen::JsCast;
 use web_sys::{Element, Node};
 
-use super::{BSubtree, DomSlot};
+use super::{BSubtree, DomSlot, node_type_str};
+use crate::hydration::HydrationMismatch;
 use crate::virtual_dom::Collectable;
 
 /// A Hydration Fragment
@@ -43,7 +44,11 @@ impl Fragment {
     }
 
     /// Collects nodes for a Component Bundle or a BSuspense.
+    ///
+    /// If the opening tag cannot be found, the mismatch is reported and an empty fragment is
+    /// returned without collecting any nodes.
     pub fn collect_between(
+        root: &BSubtree,
         collect_from: &mut Fragment,
         collect_for: &Collectable,
         parent: &Element,
@@ -65,37 +70,47 @@ impl Fragment {
         // We trim all leading text nodes as it's likely these are whitespaces.
         collect_from.trim_start_text_nodes();
 
-        let first_node = collect_from
-            .pop_front()
-            .unwrap_or_else(|| panic!("expected {} opening tag, found EOF", collect_for.name()));
-
-        assert_eq!(
-            first_node.node_type(),
-            Node::COMMENT_NODE,
-            // TODO: improve error message with human readable node type name.
-            "expected {} start, found node type {}",
-            collect_for.name(),
-            first_node.node_type()
-        );
-
-        let mut nodes = VecDeque::new();
-
-        if !is_open_tag(&first_node) {
-            panic!(
+        let mismatch = match collect_from.front() {
+            None => Some(format!(
+                "expected {} opening tag, found EOF",
+                collect_for.name()
+            )),
+            Some(m) if m.node_type() != Node::COMMENT_NODE => Some(format!(
+                "expected {} start, found node type {}",
+                collect_for.name(),
+                node_type_str(m)
+            )),
+            Some(m) if !is_open_tag(m) => Some(format!(
                 "expected {} opening tag, found comment node",
                 collect_for.name()
-            );
+            )),
+            Some(_) => None,
+        };
+
+        if let Some(message) = mismatch {
+            root.report_mismatch(HydrationMismatch::new(message).with_actual(collect_from.front()));
+            return Self(VecDeque::new(), collect_from.front().cloned());
         }
 
+        let first_node = collect_from.pop_front().unwrap();
+        let mut nodes = VecDeque::new();
+
         // We remove the opening tag.
         parent.remove_child(&first_node).unwrap();
 
         let mut nested_layers = 1;
 
         loop {
-            let current_node = collect_from.pop_front().unwrap_or_else(|| {
-                panic!("expected {} closing tag, found EOF", collect_for.name())
-            });
+            let Some(current_node) = collect_from.pop_front() else {
+                root.report_mismatch(
+                    HydrationMismatch::new(format!(
+                        "expected {} closing tag, found EOF",
+                        collect_for.name()
+                    ))
+                    .with_actual(None),
+                );
+                break;
+            };
 
             if current_node.node_type() == Node::COMMENT_NODE {
                 if is_open_tag(&current_node) {
@@ -135,6 +150,25 @@ impl Fragment {
         }
     }
 
+    /// Removes all remaining nodes from the DOM, reporting them as a mismatch.
+    ///
+    /// `expected` describes the end of the content the nodes were collected for.
+    pub fn finish(&mut self, root: &BSubtree, parent: &Element, expected: &str) {
+        // We trim all leading text nodes before checking as it's likely these are whitespaces.
+        self.trim_start_text_nodes();
+
+        if let Some(m) = self.front() {
+            root.report_mismatch(
+                HydrationMismatch::new(format!("expected {expected}, found {}", node_type_str(m)))
+                    .with_actual(Some(m)),
+            );
+        }
+
+        for node in self.drain(..) {
+            let _ = parent.remove_child(&node);
+        }
+    }
+
     /// Deeply clones all nodes.
     pub fn deep_clone(&self) -> Self {
         let nodes = self
@@ -14,6 +14,9 @@ use web_sys::{
 };
 
 use super::{Registry, test_log};
+#[cfg(feature = "hydration")]
+use crate::hydration::{HydrationMismatch, MismatchPolicy};
+use crate::scheduler::{Priority, with_priority};
 use crate::virtual_dom::{Listener, ListenerKind};
 
 /// DOM-Types that capture (bubbling) events. This generally includes event targets,
@@ -106,6 +109,23 @@ pub struct EventDescriptor {
     passive: bool,
 }
 
+impl EventDescriptor {
+    /// Returns the priority of the updates sent by listeners of this event.
+    ///
+    /// Continuous events fire many times in a row, so updates sent for them do not need to take
+    /// precedence over other updates.
+    fn priority(&self) -> Priority {
+        match &*self.kind.type_name() {
+            "drag" | "dragenter" | "dragleave" | "dragover" | "mouseenter" | "mouseleave"
+            | "mousemove" | "mouseout" | "mouseover" | "pointerenter" | "pointerleave"
+            | "pointermove" | "pointerout" | "pointerover" | "scroll" | "touchmove" | "wheel" => {
+                Priority::Default
+            }
+            _ => Priority::Discrete,
+        }
+    }
+}
+
 impl From<&dyn Listener> for EventDescriptor {
     fn from(l: &dyn Listener) -> Self {
         Self {
@@ -251,6 +271,8 @@ impl Eq for WeakSubtree {}
 struct AppData {
     subtrees: HashSet<WeakSubtree>,
     listening: HashSet<EventDescriptor>,
+    #[cfg(feature = "hydration")]
+    mismatch_policy: MismatchPolicy,
 }
 
 impl AppData {
@@ -488,12 +510,14 @@ impl SubtreeData {
         };
         if let Some(bubbling_it) = self.start_bubbling_if_responsible(&event) {
             test_log!("Running handler on subtree {}", self.subtree_id);
-            for (subtree, el) in bubbling_it {
-                if event.cancel_bubble() {
-                    break;
+            with_priority(desc.priority(), || {
+                for (subtree, el) in bubbling_it {
+                    if event.cancel_bubble() {
+                        break;
+                    }
+                    run_handler(subtree, &el);
                 }
-                run_handler(subtree, &el);
-            }
+            });
         }
     }
 
@@ -551,4 +575,25 @@ impl BSubtree {
     pub fn brand_element(&self, el: &dyn EventGrating) {
         el.set_subtree_id(self.0.subtree_id);
     }
+
+    /// Sets how hydration mismatches are handled in this app.
+    #[cfg(feature = "hydration")]
+    pub(crate) fn set_mismatch_policy(&self, policy: MismatchPolicy) {
+        self.0.app_data.borrow_mut().mismatch_policy = policy;
+    }
+
+    /// Reports a mismatch found while hydrating this app.
+    #[cfg(feature = "hydration")]
+    pub(crate) fn report_mismatch(&self, mismatch: HydrationMismatch) {
+        let policy = self.0.app_data.borrow().mismatch_policy.clone();
+        policy.report(mismatch);
+    }
+
+    /// Reports a difference in attributes or text found while hydrating this app, which has been
+    /// repaired in place.
+    #[cfg(feature = "hydration_diagnostics")]
+    pub(crate) fn report_repaired(&self, mismatch: HydrationMismatch) {
+        let policy = self.0.app_data.borrow().mismatch_policy.clone();
+        policy.report_repaired(mismatch);
+    }
 }
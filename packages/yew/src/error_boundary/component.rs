@@ -0,0 +1,750 @@
+use crate::callback::Callback;
+use crate::html::{Html, Properties, RenderErrorInfo};
+
+/// Properties for [ErrorBoundary].
+#[derive(Properties, PartialEq, Debug, Clone)]
+pub struct ErrorBoundaryProps {
+    /// The Children of the current ErrorBoundary Component.
+    #[prop_or_default]
+    pub children: Html,
+
+    /// Renders the Fallback UI after a descendant component has failed.
+    pub fallback: Callback<RenderErrorInfo, Html>,
+}
+
+#[cfg(any(feature = "csr", feature = "ssr"))]
+mod feat_csr_ssr {
+    use std::any::TypeId;
+    use std::iter;
+    use std::rc::Rc;
+
+    use super::*;
+    #[cfg(feature = "ssr")]
+    use crate::html::RenderMode;
+    use crate::html::{AnyScope, ChildrenProps, Component, Context, Scope, ServerRenderError};
+    use crate::{component, html};
+
+    /// A failure of a component, reported to the closest [`BaseErrorBoundary`].
+    #[derive(Debug, Clone)]
+    pub(crate) struct RenderFailure {
+        pub error: Rc<dyn std::error::Error>,
+        pub component: &'static str,
+    }
+
+    impl RenderFailure {
+        /// Encodes the failure as the prepared state of a boundary.
+        ///
+        /// Neither the error nor the name of the component is exposed to the client side, the
+        /// state only marks that the boundary has failed.
+        #[cfg(feature = "ssr")]
+        fn encode(&self) -> String {
+            use base64ct::{Base64, Encoding};
+
+            let state = bincode::serde::encode_to_vec(true, bincode::config::standard())
+                .expect("failed to prepare state");
+
+            Base64::encode_string(&state)
+        }
+
+        /// Recreates a failure encoded on the server side, the original error is replaced by a
+        /// [`ServerRenderError`].
+        fn from_prepared_state(_state: &str) -> Self {
+            Self {
+                error: Rc::new(ServerRenderError::new()),
+                component: "",
+            }
+        }
+    }
+
+    #[derive(Debug)]
+    pub(crate) enum BaseErrorBoundaryMsg {
+        Fail(RenderFailure),
+        Reset,
+    }
+
+    #[derive(Debug)]
+    pub(crate) struct BaseErrorBoundary {
+        failure: Option<RenderFailure>,
+        /// Bumped on every reset, so the children are mounted from scratch.
+        generation: usize,
+    }
+
+    impl Component for BaseErrorBoundary {
+        type Message = BaseErrorBoundaryMsg;
+        type Properties = ErrorBoundaryProps;
+
+        fn create(ctx: &Context<Self>) -> Self {
+            // A boundary which has shown its fallback on the server side is hydrated with it.
+            let failure = ctx.prepared_state().map(RenderFailure::from_prepared_state);
+
+            #[cfg(feature = "ssr")]
+            if ctx.creation_mode() == RenderMode::Ssr {
+                ctx.set_ssr_content(feat_ssr::BoundaryContent {
+                    scope: ctx.link().clone(),
+                    fallback: ctx.props().fallback.clone(),
+                });
+            }
+
+            Self {
+                failure,
+                generation: 0,
+            }
+        }
+
+        fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+            match msg {
+                Self::Message::Fail(m) => {
+                    // Only the first failure is kept, subsequent failures are most likely caused
+                    // by it and the children are about to be replaced anyway.
+                    if self.failure.is_some() {
+                        return false;
+                    }
+
+                    self.failure = Some(m);
+                    true
+                }
+                Self::Message::Reset => {
+                    self.failure = None;
+                    self.generation = self.generation.wrapping_add(1);
+                    true
+                }
+            }
+        }
+
+        fn view(&self, ctx: &Context<Self>) -> Html {
+            if let Some(m) = self.fallback(ctx.link(), &ctx.props().fallback) {
+                return m;
+            }
+
+            let children = ctx.props().children.clone();
+
+            html! {
+                <ErrorBoundaryContent key={self.generation}>
+                    {children}
+                </ErrorBoundaryContent>
+            }
+        }
+
+        #[cfg(feature = "ssr")]
+        fn prepare_state(&self) -> Option<String> {
+            self.failure.as_ref().map(RenderFailure::encode)
+        }
+    }
+
+    impl BaseErrorBoundary {
+        pub(crate) fn fail(scope: &Scope<Self>, failure: RenderFailure) {
+            scope.send_message(BaseErrorBoundaryMsg::Fail(failure));
+        }
+
+        /// Renders the fallback if the boundary has caught a failure.
+        fn fallback(
+            &self,
+            scope: &Scope<Self>,
+            fallback: &Callback<RenderErrorInfo, Html>,
+        ) -> Option<Html> {
+            let m = self.failure.as_ref()?;
+
+            let reset = scope.callback(|_| BaseErrorBoundaryMsg::Reset);
+            let info = RenderErrorInfo::new(m.error.clone(), m.component, reset);
+            let fallback = fallback.emit(info);
+
+            Some(html! {
+                <ErrorBoundaryFallback>
+                    {fallback}
+                </ErrorBoundaryFallback>
+            })
+        }
+    }
+
+    #[cfg(feature = "ssr")]
+    mod feat_ssr {
+        use std::fmt::Write;
+        use std::rc::Rc;
+        use std::task::{Poll, ready};
+
+        use futures::future::{FutureExt, LocalBoxFuture};
+        use futures::stream::{self, StreamExt};
+
+        use super::*;
+        use crate::feat_ssr::{DeferredBoundaries, SwapMode, Swaps, VTagKind, swap};
+        use crate::html::{SsrAlive, SsrContent, write_prepared_state};
+        use crate::platform::fmt::{self, BufWriter};
+        use crate::suspense::RevealSlot;
+
+        /// Renders the content of a boundary on the server side.
+        ///
+        /// As a failing component cannot retract what has already been written, the content is
+        /// buffered until all children have finished rendering and replaced with the fallback if
+        /// any of them failed.
+        ///
+        /// During an out-of-order render, children deferred by a suspense boundary finish after
+        /// the content has been written. The content is then marked like a deferred part of the
+        /// document and the fallback is swapped in if any of the deferred children fail.
+        #[derive(Debug)]
+        pub(super) struct BoundaryContent {
+            pub scope: Scope<BaseErrorBoundary>,
+            pub fallback: Callback<RenderErrorInfo, Html>,
+        }
+
+        impl BoundaryContent {
+            /// Renders the fallback if the boundary has caught a failure.
+            fn fallback(&self) -> Option<Html> {
+                self.scope
+                    .get_component()
+                    .and_then(|m| m.fallback(&self.scope, &self.fallback))
+            }
+
+            /// Streams the swaps deferred by the content of the boundary, the content marked with
+            /// `id` is replaced with the fallback as soon as any of them fails.
+            ///
+            /// Inside of a [`SuspenseList`](crate::suspense::SuspenseList), the final swap of the
+            /// boundary is queued by the list instead, so it follows the order of the list.
+            fn watch(
+                &self,
+                id: usize,
+                deferred: Vec<Swaps>,
+                hydratable: bool,
+                parent_vtag_kind: VTagKind,
+                alive: Rc<SsrAlive>,
+            ) -> Swaps {
+                let scope = self.scope.clone();
+                let fallback = self.fallback.clone();
+
+                let mut deferred = Some(stream::select_all(deferred));
+                let mut failed: Option<LocalBoxFuture<'static, String>> = None;
+
+                let mut ticket =
+                    RevealSlot::find(&scope.clone().into()).map(|m| m.register(id, None));
+                let mut finish = move |swap: String| match ticket.take() {
+                    Some(m) => {
+                        m.resolve(swap, Vec::new());
+                        Poll::Ready(None)
+                    }
+                    None => Poll::Ready(Some(swap)),
+                };
+
+                stream::poll_fn(move |cx| {
+                    // The boundary has to outlive the deferred children to catch their failures.
+                    let _alive = &alive;
+
+                    loop {
+                        if let Some(ref mut m) = failed {
+                            let html = ready!(m.poll_unpin(cx));
+                            failed = None;
+                            return finish(swap(id, Some(&html), SwapMode::Unwrap));
+                        }
+
+                        let Some(ref mut swaps) = deferred else {
+                            return Poll::Ready(None);
+                        };
+                        let next = swaps.poll_next_unpin(cx);
+
+                        let Some(m) = scope
+                            .get_component()
+                            .and_then(|m| m.fallback(&scope, &fallback))
+                        else {
+                            return match next {
+                                Poll::Ready(Some(m)) => Poll::Ready(Some(m)),
+                                Poll::Ready(None) => {
+                                    deferred = None;
+                                    finish(swap(id, None, SwapMode::Unwrap))
+                                }
+                                Poll::Pending => Poll::Pending,
+                            };
+                        };
+
+                        // The remaining content is replaced by the fallback.
+                        deferred = None;
+
+                        let scope = scope.clone();
+                        failed = Some(Box::pin(async move {
+                            let (mut buf_w, buf_r) = fmt::buffer();
+                            m.render_into_stream(
+                                &mut buf_w,
+                                &scope.clone().into(),
+                                hydratable,
+                                parent_vtag_kind,
+                            )
+                            .await;
+
+                            // The state has to follow the fallback, as it is swapped in place of
+                            // the content.
+                            if let Some(state) =
+                                scope.get_component().and_then(|m| m.prepare_state())
+                            {
+                                write_prepared_state(&mut buf_w, &state);
+                            }
+                            drop(buf_w);
+
+                            buf_r.collect::<String>().await
+                        }));
+                    }
+                })
+                .boxed_local()
+            }
+        }
+
+        impl SsrContent for BoundaryContent {
+            fn render_into_stream<'a>(
+                &'a self,
+                w: &'a mut BufWriter,
+                html: Html,
+                hydratable: bool,
+                parent_vtag_kind: VTagKind,
+                alive: &'a Rc<SsrAlive>,
+            ) -> LocalBoxFuture<'a, ()> {
+                Box::pin(async move {
+                    let self_any_scope = AnyScope::from(self.scope.clone());
+                    let deferred = DeferredBoundaries::current();
+                    let nested = deferred.as_ref().map(|m| m.nested());
+
+                    let (mut buf_w, mut buf_r) = fmt::buffer();
+                    let render = html.render_into_stream(
+                        &mut buf_w,
+                        &self_any_scope,
+                        hydratable,
+                        parent_vtag_kind,
+                    );
+                    match nested {
+                        Some(ref m) => m.clone().run(render).await,
+                        None => render.await,
+                    }
+                    drop(buf_w);
+
+                    // Failures are delivered as messages, which have been processed by the
+                    // scheduler at this point.
+                    if let Some(m) = self.fallback() {
+                        m.render_into_stream(w, &self_any_scope, hydratable, parent_vtag_kind)
+                            .await;
+                        return;
+                    }
+
+                    let deferred = deferred.zip(nested).filter(|(_, m)| !m.is_empty());
+                    let id = deferred.as_ref().map(|(m, _)| m.next_id());
+
+                    if let Some(id) = id {
+                        let _ = write!(w, "<!--<?{id}>-->");
+                    }
+                    while let Some(m) = buf_r.next().await {
+                        let _ = w.write_str(m.as_str());
+                    }
+
+                    if let Some(((deferred, nested), id)) = deferred.zip(id) {
+                        let _ = w.write_str("<!--</?>-->");
+                        deferred.push(self.watch(
+                            id,
+                            nested.take(),
+                            hydratable,
+                            parent_vtag_kind,
+                            alive.clone(),
+                        ));
+                    }
+                })
+            }
+        }
+    }
+
+    /// Hosts the children of a boundary, keyed by the generation of the boundary.
+    #[component]
+    fn ErrorBoundaryContent(props: &ChildrenProps) -> Html {
+        props.children.clone()
+    }
+
+    /// Hosts the fallback of a boundary.
+    ///
+    /// Failures inside of it are handled by the next boundary up the tree.
+    #[component]
+    fn ErrorBoundaryFallback(props: &ChildrenProps) -> Html {
+        props.children.clone()
+    }
+
+    /// Finds the boundary that handles failures of the component at `scope`.
+    ///
+    /// Boundaries whose fallback contains the component are skipped, as they cannot recover from
+    /// a failing fallback.
+    pub(crate) fn find_error_boundary(scope: &AnyScope) -> Option<Scope<BaseErrorBoundary>> {
+        let mut in_fallback = false;
+
+        for scope in iter::successors(scope.get_parent(), |m| m.get_parent()) {
+            let type_id = *scope.get_type_id();
+
+            if type_id == TypeId::of::<ErrorBoundaryFallback>() {
+                in_fallback = true;
+            } else if type_id == TypeId::of::<BaseErrorBoundary>() {
+                if !in_fallback {
+                    return scope.try_downcast::<BaseErrorBoundary>();
+                }
+                in_fallback = false;
+            }
+        }
+
+        None
+    }
+
+    /// Catches failures of its children and shows a fallback UI instead.
+    ///
+    /// A component fails if one of its `view`, `update` or `rendered` methods panics, or if its
+    /// `view` returns [`RenderError::Failed`](crate::html::RenderError::Failed). The failure is
+    /// reported to the closest `ErrorBoundary`, which replaces all of its children with the
+    /// result of `fallback`. The fallback receives a [`RenderErrorInfo`] which can be used to
+    /// reset the boundary and mount the children again.
+    ///
+    /// # Panics on `wasm32-unknown-unknown`
+    ///
+    /// Panics can only be caught if the application is built with `panic=unwind`. The
+    /// `wasm32-unknown-unknown` target aborts on panics by default, so in the browser a panicking
+    /// component still takes down the whole application and only failures returned as
+    /// [`RenderError::Failed`](crate::html::RenderError::Failed) are caught. Unwinding on this
+    /// target requires a nightly toolchain, building the standard library with
+    /// `-Z build-std=std,panic_unwind` and compiling with
+    /// `-C panic=unwind -C target-feature=+exception-handling`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use yew::prelude::*;
+    /// use yew::error_boundary::RenderErrorInfo;
+    ///
+    /// #[component]
+    /// fn Widget() -> Html {
+    ///     panic!("oops");
+    /// }
+    ///
+    /// #[component]
+    /// fn App() -> Html {
+    ///     let fallback = Callback::from(|info: RenderErrorInfo| {
+    ///         html! {
+    ///             <div>
+    ///                 <p>{"Something went wrong: "}{info.error().to_string()}</p>
+    ///                 <button onclick={info.reset_callback()}>{"Try again"}</button>
+    ///             </div>
+    ///         }
+    ///     });
+    ///
+    ///     html! {
+    ///         <ErrorBoundary {fallback}>
+    ///             <Widget />
+    ///         </ErrorBoundary>
+    ///     }
+    /// }
+    /// ```
+    #[component]
+    pub fn ErrorBoundary(props: &ErrorBoundaryProps) -> Html {
+        let ErrorBoundaryProps { children, fallback } = props.clone();
+
+        html! {
+            <BaseErrorBoundary {fallback}>
+                {children}
+            </BaseErrorBoundary>
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::html::BaseComponent;
+
+        fn child_of<COMP: BaseComponent>(parent: &Scope<impl BaseComponent>) -> Scope<COMP> {
+            Scope::new(Some(parent.clone().into()))
+        }
+
+        #[test]
+        fn finds_closest_boundary() {
+            let outer = Scope::<BaseErrorBoundary>::new(None);
+            let inner = child_of::<BaseErrorBoundary>(&outer);
+            let content = child_of::<ErrorBoundaryContent>(&inner);
+            let comp = child_of::<ErrorBoundaryContent>(&content);
+
+            let found = find_error_boundary(&comp.into()).unwrap();
+            assert_eq!(found.id, inner.id);
+
+            assert!(find_error_boundary(&outer.into()).is_none());
+        }
+
+        #[test]
+        fn skips_boundary_of_failing_fallback() {
+            let outer = Scope::<BaseErrorBoundary>::new(None);
+            let inner = child_of::<BaseErrorBoundary>(&outer);
+            let fallback = child_of::<ErrorBoundaryFallback>(&inner);
+            let comp = child_of::<ErrorBoundaryContent>(&fallback);
+
+            let found = find_error_boundary(&comp.into()).unwrap();
+            assert_eq!(found.id, outer.id);
+
+            let fallback = child_of::<ErrorBoundaryFallback>(&outer);
+            let comp = child_of::<ErrorBoundaryContent>(&fallback);
+            assert!(find_error_boundary(&comp.into()).is_none());
+        }
+
+        #[cfg(feature = "ssr")]
+        #[test]
+        fn prepared_state_only_marks_failure() {
+            #[derive(Debug)]
+            struct Secret;
+
+            impl std::fmt::Display for Secret {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    write!(f, "password=hunter2")
+                }
+            }
+
+            impl std::error::Error for Secret {}
+
+            let failure = RenderFailure {
+                error: Rc::new(Secret),
+                component: "app::Widget",
+            };
+
+            let state = failure.encode();
+            assert!(!state.contains("hunter2"));
+            assert!(!state.contains("Widget"));
+
+            let other = RenderFailure {
+                error: Rc::new(ServerRenderError::new()),
+                component: "app::Other",
+            };
+            assert_eq!(other.encode(), state);
+
+            let decoded = RenderFailure::from_prepared_state(&state);
+            assert!(decoded.error.is::<ServerRenderError>());
+            assert_eq!(decoded.component, "");
+        }
+    }
+}
+
+#[cfg(any(feature = "csr", feature = "ssr"))]
+pub use feat_csr_ssr::ErrorBoundary;
+#[cfg(any(feature = "csr", feature = "ssr"))]
+pub(crate) use feat_csr_ssr::*;
+
+#[cfg(not(any(feature = "ssr", feature = "csr")))]
+mod feat_no_csr_ssr {
+    use super::*;
+    use crate::component;
+
+    /// Catches failures of its children and shows a fallback UI instead.
+    #[component]
+    pub fn ErrorBoundary(_props: &ErrorBoundaryProps) -> Html {
+        Html::default()
+    }
+}
+
+#[cfg(not(any(feature = "ssr", feature = "csr")))]
+pub use feat_no_csr_ssr::*;
+
+#[cfg(all(test, not(target_arch = "wasm32"), feature = "ssr"))]
+mod ssr_tests {
+    use std::fmt;
+    use std::time::Duration;
+
+    use tokio::test;
+
+    use crate::ServerRenderer;
+    use crate::error_boundary::RenderErrorInfo;
+    use crate::html::RenderError;
+    use crate::platform::time::sleep;
+    use crate::prelude::*;
+    use crate::suspense::use_future;
+
+    #[derive(Debug)]
+    struct FetchError;
+
+    impl fmt::Display for FetchError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "failed to fetch")
+        }
+    }
+
+    impl std::error::Error for FetchError {}
+
+    fn fetch(fail: bool) -> Result<&'static str, FetchError> {
+        if fail { Err(FetchError) } else { Ok("Jane") }
+    }
+
+    #[derive(PartialEq, Properties, Debug)]
+    struct ChildProps {
+        fail: bool,
+    }
+
+    #[component]
+    fn Child(props: &ChildProps) -> HtmlResult {
+        let name = fetch(props.fail).map_err(RenderError::failed)?;
+        Ok(html! { <div>{"Hello, "}{name}{"!"}</div> })
+    }
+
+    /// The prepared state of a boundary which has shown its fallback.
+    const FAILED: &str = r#"<script type="application/x-yew-comp-state">AQ==</script>"#;
+
+    fn fallback() -> Callback<RenderErrorInfo, Html> {
+        Callback::from(|info: RenderErrorInfo| html! { <p>{info.error().to_string()}</p> })
+    }
+
+    #[test]
+    async fn renders_children() {
+        #[component]
+        fn Comp() -> Html {
+            html! {
+                <ErrorBoundary fallback={fallback()}>
+                    <Child fail=false />
+                </ErrorBoundary>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(s, "<div>Hello, Jane!</div>");
+    }
+
+    #[test]
+    async fn streams_fallback_of_failed_render() {
+        #[component]
+        fn Comp() -> Html {
+            html! {
+                <div>
+                    <ErrorBoundary fallback={fallback()}>
+                        <Child fail=false />
+                        <Child fail=true />
+                    </ErrorBoundary>
+                    <Child fail=false />
+                </div>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(
+            s,
+            format!("<div><p>failed to fetch</p>{FAILED}<div>Hello, Jane!</div></div>")
+        );
+    }
+
+    #[test]
+    async fn streams_fallback_of_panic() {
+        #[component]
+        fn Panicking() -> Html {
+            panic!("oops");
+        }
+
+        #[component]
+        fn Comp() -> Html {
+            html! {
+                <ErrorBoundary fallback={fallback()}>
+                    <Panicking />
+                </ErrorBoundary>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(s, format!("<p>component panicked: oops</p>{FAILED}"));
+    }
+
+    #[test]
+    async fn failing_fallback_propagates_to_outer_boundary() {
+        #[component]
+        fn Comp() -> Html {
+            let failing = Callback::from(|_| html! { <Child fail=true /> });
+
+            html! {
+                <ErrorBoundary fallback={fallback()}>
+                    <ErrorBoundary fallback={failing}>
+                        <Child fail=true />
+                    </ErrorBoundary>
+                </ErrorBoundary>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(s, format!("<p>failed to fetch</p>{FAILED}"));
+    }
+
+    #[derive(PartialEq, Properties, Debug)]
+    struct DeferredProps {
+        fail: bool,
+    }
+
+    #[component]
+    fn Deferred(props: &DeferredProps) -> HtmlResult {
+        use_future(|| sleep(Duration::from_millis(10)))?;
+
+        Ok(html! { <Child fail={props.fail} /> })
+    }
+
+    #[component]
+    fn DeferredPage(props: &DeferredProps) -> Html {
+        html! {
+            <ErrorBoundary fallback={fallback()}>
+                <div>{"header"}</div>
+                <Suspense fallback={html! {"loading..."}}>
+                    <Deferred fail={props.fail} />
+                </Suspense>
+            </ErrorBoundary>
+            <div>{"footer"}</div>
+        }
+    }
+
+    /// Renders out of order, returns the shell and the swaps streamed after it.
+    async fn render_out_of_order(fail: bool) -> (String, String) {
+        let s = ServerRenderer::<DeferredPage>::with_props(move || DeferredProps { fail })
+            .hydratable(false)
+            .out_of_order(true)
+            .render()
+            .await;
+
+        let (shell, rest) = s.split_once("<script>function __yew_swap").unwrap();
+        let (_, swaps) = rest.split_once("</script>").unwrap();
+
+        (shell.to_string(), swaps.to_string())
+    }
+
+    #[test]
+    async fn streams_deferred_children_of_boundary() {
+        let (shell, swaps) = render_out_of_order(false).await;
+
+        assert_eq!(
+            shell,
+            "<!--<?1>--><div>header</div><!--<?0>-->loading...<!--</?>--><!--</?>--><div>footer</\
+             div>"
+        );
+        assert_eq!(
+            swaps,
+            concat!(
+                r#"<template id="yew-s-0"><div>Hello, Jane!</div></template>"#,
+                "<script>__yew_swap(0,0)</script>",
+                "<script>__yew_swap(1,0)</script>",
+            )
+        );
+    }
+
+    #[test]
+    async fn streams_fallback_of_deferred_failure() {
+        let (shell, swaps) = render_out_of_order(true).await;
+
+        assert_eq!(
+            shell,
+            "<!--<?1>--><div>header</div><!--<?0>-->loading...<!--</?>--><!--</?>--><div>footer</\
+             div>"
+        );
+        // The content of the boundary, including the pending suspense, is replaced by the
+        // fallback.
+        assert_eq!(
+            swaps,
+            format!(
+                r#"<template id="yew-s-1"><p>failed to fetch</p>{FAILED}</template><script>__yew_swap(1,0)</script>"#
+            )
+        );
+    }
+}
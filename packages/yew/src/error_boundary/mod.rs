@@ -0,0 +1,13 @@
+//! This module provides error boundary support.
+//!
+//! An [`ErrorBoundary`] catches failures of the components rendered below it and replaces its
+//! children with a fallback, so that a single broken widget does not take down the whole
+//! application.
+
+mod component;
+
+#[cfg(any(feature = "csr", feature = "ssr"))]
+pub(crate) use component::{BaseErrorBoundary, RenderFailure, find_error_boundary};
+pub use component::{ErrorBoundary, ErrorBoundaryProps};
+
+pub use crate::html::{PanicError, RenderErrorInfo, ServerRenderError};
@@ -0,0 +1,172 @@
+use std::mem;
+use std::panic::Location;
+
+/// Information about a hook called by a function component.
+///
+/// Hooks are recorded on every render when the `debug-hooks` feature is enabled and can be
+/// inspected with [`AnyScope::inspect_hooks`](crate::html::AnyScope::inspect_hooks).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HookInfo {
+    /// The name of the hook, such as `use_state`.
+    pub kind: &'static str,
+    /// Where the hook is called.
+    pub location: &'static Location<'static>,
+    /// A [`Debug`](std::fmt::Debug) rendering of the value of the hook.
+    ///
+    /// This is the value given to [`use_debug_value`](crate::functional::use_debug_value) by the
+    /// hook. Otherwise, it is the value returned by the hook if it implements `Debug`, or the
+    /// current state for the handles of state hooks such as
+    /// [`use_state`](crate::functional::use_state).
+    pub value: Option<String>,
+    /// The hooks called by this hook.
+    pub hooks: Vec<HookInfo>,
+}
+
+struct Recording {
+    info: HookInfo,
+    /// Whether the value is forwarded to the hook calling this hook.
+    forward_value: bool,
+}
+
+/// Records the hooks called during a render.
+#[derive(Default)]
+pub(crate) struct HookRecorder {
+    stack: Vec<Recording>,
+    hooks: Vec<HookInfo>,
+}
+
+impl HookRecorder {
+    pub fn enter(&mut self, kind: &'static str, location: &'static Location<'static>) {
+        self.stack.push(Recording {
+            info: HookInfo {
+                kind,
+                location,
+                value: None,
+                hooks: Vec::new(),
+            },
+            forward_value: false,
+        });
+    }
+
+    pub fn exit(&mut self) {
+        let Some(Recording {
+            info,
+            forward_value,
+        }) = self.stack.pop()
+        else {
+            return;
+        };
+
+        match self.stack.last_mut() {
+            Some(parent) if forward_value => parent.info.value = info.value,
+            Some(parent) => parent.info.hooks.push(info),
+            None => self.hooks.push(info),
+        }
+    }
+
+    /// Sets the value of the current hook, unless the hook has set one itself.
+    pub fn set_default_value(&mut self, value: impl FnOnce() -> Option<String>) {
+        if let Some(m) = self.stack.last_mut() {
+            if m.info.value.is_none() {
+                m.info.value = value();
+            }
+        }
+    }
+
+    /// Sets the value of the hook calling the current hook.
+    pub fn set_value(&mut self, value: String) {
+        if let Some(m) = self.stack.last_mut() {
+            m.info.value = Some(value);
+            m.forward_value = true;
+        }
+    }
+
+    /// Returns the hooks recorded since the last call and starts a new recording.
+    pub fn take(&mut self) -> Vec<HookInfo> {
+        // Hooks that did not exit, because the render panicked, are discarded.
+        self.stack.clear();
+        mem::take(&mut self.hooks)
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32"), feature = "ssr"))]
+mod ssr_tests {
+    use tokio::test;
+
+    use crate::ServerRenderer;
+    use crate::html::AnyScope;
+    use crate::prelude::*;
+
+    #[hook]
+    fn use_counter() -> UseStateHandle<u32> {
+        let counter = use_state(|| 2);
+        use_debug_value(*counter);
+
+        counter
+    }
+
+    struct Inspector;
+
+    impl Component for Inspector {
+        type Message = ();
+        type Properties = ();
+
+        fn create(_ctx: &Context<Self>) -> Self {
+            Self
+        }
+
+        fn view(&self, ctx: &Context<Self>) -> Html {
+            let scope = AnyScope::from(ctx.link().clone());
+            let hooks = scope.get_parent().unwrap().inspect_hooks();
+
+            let hooks = hooks
+                .iter()
+                .map(|m| {
+                    let inner = m.hooks.iter().map(|m| m.kind).collect::<Vec<_>>();
+                    format!(
+                        "{}:{}:{:?}:{}",
+                        m.kind,
+                        m.location.line(),
+                        m.value,
+                        inner.join("+")
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            html! { <p>{hooks.join(" ")}</p> }
+        }
+    }
+
+    const FIRST_LINE: u32 = line!() + 4;
+
+    #[component]
+    fn App() -> Html {
+        let _counter = use_counter();
+        let _name = use_memo((), |_| "yew");
+        let _enabled = use_state(|| true);
+
+        html! { <Inspector /> }
+    }
+
+    #[test]
+    async fn records_hooks() {
+        let s = ServerRenderer::<App>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        // Hooks without a value of their own are recorded with the value they return, or their
+        // state for state hooks.
+        assert_eq!(
+            s,
+            format!(
+                "<p>use_counter:{}:Some(\"2\"):use_state \
+                 use_memo:{}:Some(\"\\\"yew\\\"\"):use_memo_base \
+                 use_state:{}:Some(\"true\"):use_reducer</p>",
+                FIRST_LINE,
+                FIRST_LINE + 1,
+                FIRST_LINE + 2,
+            )
+        );
+    }
+}
@@ -0,0 +1,30 @@
+use futures::future::{AbortHandle, Abortable};
+
+use crate::platform::spawn_local;
+
+/// A handle to a future created by [`abortable`] or [`spawn_abortable`], which aborts the future
+/// when dropped.
+pub(crate) struct AbortOnDrop(AbortHandle);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+/// Wraps `f` into a future which is aborted when the returned handle is dropped.
+pub(crate) fn abortable<F: Future>(f: F) -> (Abortable<F>, AbortOnDrop) {
+    let (handle, registration) = AbortHandle::new_pair();
+
+    (Abortable::new(f, registration), AbortOnDrop(handle))
+}
+
+/// Spawns `f`, which is aborted when the returned handle is dropped.
+pub(crate) fn spawn_abortable(f: impl Future<Output = ()> + 'static) -> AbortOnDrop {
+    let (task, handle) = abortable(f);
+    spawn_local(async move {
+        let _ = task.await;
+    });
+
+    handle
+}
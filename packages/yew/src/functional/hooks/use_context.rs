@@ -119,3 +119,116 @@ pub fn use_context<T: Clone + PartialEq + 'static>() -> impl Hook<Output = Optio
         _marker: PhantomData,
     }
 }
+
+/// Hook for consuming a slice of a context value in function components.
+///
+/// `selector` maps the context of the type passed as `T` to the slice the component reads. If
+/// there is no such context in scope, `None` is returned. Unlike [`use_context`], a component
+/// which calls `use_context_selector` only re-renders when the selected slice changes.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::{Html, component, html, use_context_selector};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct AppState {
+///     user_name: String,
+///     counter: u32,
+/// }
+///
+/// /// Does not re-render when the counter changes.
+/// #[component]
+/// pub fn UserName() -> Html {
+///     let user_name = use_context_selector::<AppState, _>(|m| m.user_name.clone());
+///
+///     html! { <span>{user_name.unwrap_or_default()}</span> }
+/// }
+/// ```
+pub fn use_context_selector<T, U>(
+    selector: impl Fn(&T) -> U + 'static,
+) -> impl Hook<Output = Option<U>>
+where
+    T: Clone + PartialEq + 'static,
+    U: Clone + PartialEq + 'static,
+{
+    struct HookProvider<T, U, F>
+    where
+        F: Fn(&T) -> U + 'static,
+    {
+        selector: F,
+        _marker: PhantomData<(T, U)>,
+    }
+
+    type Selector<T, U> = Rc<dyn Fn(&T) -> U>;
+
+    struct Selected<T, U> {
+        context: Option<T>,
+        value: Option<U>,
+        selector: Option<Selector<T, U>>,
+    }
+
+    struct UseContextSelector<T: Clone + PartialEq + 'static, U> {
+        _handle: Option<ContextHandle<T>>,
+        selected: Rc<RefCell<Selected<T, U>>>,
+    }
+
+    impl<T, U, F> Hook for HookProvider<T, U, F>
+    where
+        T: Clone + PartialEq + 'static,
+        U: Clone + PartialEq + 'static,
+        F: Fn(&T) -> U + 'static,
+    {
+        type Output = Option<U>;
+
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            let scope = ctx.scope.clone();
+
+            let state = ctx.next_state(move |re_render| -> UseContextSelector<T, U> {
+                let selected = Rc::new(RefCell::new(Selected {
+                    context: None,
+                    value: None,
+                    selector: None,
+                }));
+
+                let (context, handle) = {
+                    let selected = selected.clone();
+
+                    scope.context(Callback::from(move |m: T| {
+                        let mut selected = selected.borrow_mut();
+                        let next = selected.selector.as_ref().map(|f| f(&m));
+                        selected.context = Some(m);
+
+                        if next != selected.value {
+                            selected.value = next;
+                            re_render()
+                        }
+                    }))
+                }
+                .map(|(context, handle)| (Some(context), Some(handle)))
+                .unwrap_or((None, None));
+
+                selected.borrow_mut().context = context;
+
+                UseContextSelector {
+                    _handle: handle,
+                    selected,
+                }
+            });
+
+            // The selector may depend on the properties, so it is applied on every render.
+            let selector: Selector<T, U> = Rc::new(self.selector);
+            let mut selected = state.selected.borrow_mut();
+            let value = selected.context.as_ref().map(|m| selector(m));
+            selected.value = value.clone();
+            selected.selector = Some(selector);
+
+            value
+        }
+    }
+
+    HookProvider {
+        selector,
+        _marker: PhantomData,
+    }
+}
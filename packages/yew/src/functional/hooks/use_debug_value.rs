@@ -0,0 +1,49 @@
+use std::fmt;
+
+use crate::functional::{Hook, HookContext};
+
+/// This hook is used to label the value of a custom hook for debugging.
+///
+/// With the `debug-hooks` feature, the [`Debug`](fmt::Debug) rendering of `value` is recorded as
+/// the value of the hook calling `use_debug_value`, and can be inspected with
+/// [`AnyScope::inspect_hooks`](crate::html::AnyScope::inspect_hooks). Without the feature, this
+/// hook does nothing.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+///
+/// #[hook]
+/// fn use_online() -> UseStateHandle<bool> {
+///     let online = use_state(|| true);
+///     use_debug_value(if *online { "online" } else { "offline" });
+///
+///     online
+/// }
+/// ```
+pub fn use_debug_value<T>(value: T) -> impl Hook<Output = ()>
+where
+    T: fmt::Debug,
+{
+    struct UseDebugValue<T> {
+        value: T,
+    }
+
+    impl<T> Hook for UseDebugValue<T>
+    where
+        T: fmt::Debug,
+    {
+        type Output = ();
+
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            #[cfg(feature = "debug-hooks")]
+            ctx.set_debug_value(format!("{:?}", self.value));
+
+            #[cfg(not(feature = "debug-hooks"))]
+            let _ = (self.value, ctx);
+        }
+    }
+
+    UseDebugValue { value }
+}
@@ -1,6 +1,8 @@
 use std::cell::RefCell;
+use std::rc::Rc;
 
-use crate::functional::{Effect, Hook, HookContext, hook};
+use crate::functional::{Effect, Hook, HookContext, hook, spawn_abortable};
+use crate::platform::spawn_local;
 
 /// Trait describing the destructor of [`use_effect`] hook.
 pub trait TearDown: Sized + 'static {
@@ -18,6 +20,26 @@ impl<F: FnOnce() + 'static> TearDown for F {
     }
 }
 
+/// Trait describing the destructor of [`use_async_effect_with`] hook.
+pub trait AsyncTearDown: Sized + 'static {
+    /// The future that is spawned when destructor is called
+    fn tear_down(self) -> impl Future<Output = ()> + 'static;
+}
+
+impl AsyncTearDown for () {
+    async fn tear_down(self) {}
+}
+
+impl<F, Fut> AsyncTearDown for F
+where
+    F: FnOnce() -> Fut + 'static,
+    Fut: Future<Output = ()> + 'static,
+{
+    fn tear_down(self) -> impl Future<Output = ()> + 'static {
+        self()
+    }
+}
+
 struct UseEffectBase<T, F, D>
 where
     F: FnOnce(&T) -> D + 'static,
@@ -73,6 +95,7 @@ fn use_effect_base<T, D>(
     runner: impl FnOnce(&T) -> D + 'static,
     deps: T,
     effect_changed_fn: fn(Option<&T>, Option<&T>) -> bool,
+    layout: bool,
 ) -> impl Hook<Output = ()>
 where
     T: 'static,
@@ -87,6 +110,7 @@ where
         runner: F,
         deps: T,
         effect_changed_fn: fn(Option<&T>, Option<&T>) -> bool,
+        layout: bool,
     }
 
     impl<T, F, D> Hook for HookProvider<T, F, D>
@@ -102,16 +126,22 @@ where
                 runner,
                 deps,
                 effect_changed_fn,
+                layout,
             } = self;
 
-            let state = ctx.next_effect(|_| -> RefCell<UseEffectBase<T, F, D>> {
+            let initializer = |_| -> RefCell<UseEffectBase<T, F, D>> {
                 RefCell::new(UseEffectBase {
                     runner_with_deps: None,
                     destructor: None,
                     deps: None,
                     effect_changed_fn,
                 })
-            });
+            };
+            let state = if layout {
+                ctx.next_layout_effect(initializer)
+            } else {
+                ctx.next_effect(initializer)
+            };
 
             state.borrow_mut().runner_with_deps = Some((deps, runner));
         }
@@ -121,6 +151,7 @@ where
         runner,
         deps,
         effect_changed_fn,
+        layout,
     }
 }
 
@@ -172,7 +203,7 @@ where
     F: FnOnce() -> D + 'static,
     D: TearDown,
 {
-    use_effect_base(|_| f(), (), |_, _| true);
+    use_effect_base(|_| f(), (), |_, _| true, false);
 }
 
 /// This hook is similar to [`use_effect`] but it accepts dependencies.
@@ -261,5 +292,143 @@ where
     F: FnOnce(&T) -> D + 'static,
     D: TearDown,
 {
-    use_effect_base(f, deps, |lhs, rhs| lhs != rhs)
+    use_effect_base(f, deps, |lhs, rhs| lhs != rhs, false)
+}
+
+/// `use_layout_effect` is used to read and adjust the layout of a component before the browser
+/// paints it.
+///
+/// It is identical to [`use_effect`], except that the callback is called right after the DOM of
+/// the component has been updated, in the same task and before the browser paints. Layout effects
+/// of children are called before the layout effects of their parents and before any
+/// [`use_effect`] callback. State updated in the callback is rendered before the browser paints
+/// as well.
+///
+/// This is useful to measure elements, for example to position a tooltip. Prefer [`use_effect`]
+/// whenever possible, as layout effects delay the paint.
+///
+/// Layout effects are not called when rendered on the server.
+///
+/// # Example
+///
+/// ```rust
+/// use web_sys::HtmlElement;
+/// use yew::prelude::*;
+///
+/// #[component]
+/// fn Tooltip() -> Html {
+///     let node = use_node_ref();
+///     let height = use_state_eq(|| 0);
+///
+///     {
+///         let node = node.clone();
+///         let height = height.clone();
+///         use_layout_effect(move || {
+///             if let Some(element) = node.cast::<HtmlElement>() {
+///                 height.set(element.offset_height());
+///             }
+///         });
+///     }
+///
+///     html! {
+///         <div ref={node} style={format!("top: -{}px", *height)}>{"Tooltip"}</div>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_layout_effect<F, D>(f: F)
+where
+    F: FnOnce() -> D + 'static,
+    D: TearDown,
+{
+    use_effect_base(|_| f(), (), |_, _| true, true);
+}
+
+/// This hook is similar to [`use_layout_effect`] but it accepts dependencies.
+///
+/// The callback is only called when the dependencies change, see [`use_effect_with`].
+pub fn use_layout_effect_with<T, F, D>(deps: T, f: F) -> impl Hook<Output = ()>
+where
+    T: PartialEq + 'static,
+    F: FnOnce(&T) -> D + 'static,
+    D: TearDown,
+{
+    use_effect_base(f, deps, |lhs, rhs| lhs != rhs, true)
+}
+
+/// This hook is used for running an asynchronous side effect when the dependencies change.
+///
+/// The future returned by `f` is spawned after the component is rendered with new dependencies.
+/// When the dependencies change again or the component is destroyed before it completes, the
+/// future is dropped, so a slow future never updates the component with outdated results.
+///
+/// The future can resolve to a destructor, either `()` or a function returning a future. The
+/// destructor is spawned when the dependencies change or the component is destroyed after the
+/// future has completed.
+///
+/// Async effects are not run when rendered on the server.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::platform::time::sleep;
+/// use yew::prelude::*;
+/// # use std::time::Duration;
+/// # async fn search(query: &str) -> Vec<String> { vec![query.to_owned()] }
+///
+/// #[derive(Properties, PartialEq)]
+/// struct Props {
+///     query: String,
+/// }
+///
+/// #[component]
+/// fn SearchResults(props: &Props) -> Html {
+///     let results = use_state(Vec::new);
+///
+///     {
+///         let results = results.setter();
+///         use_async_effect_with(props.query.clone(), move |query| {
+///             let query = query.clone();
+///             async move {
+///                 // Debounces the search, the search is not started if the query changes
+///                 // within 300ms.
+///                 sleep(Duration::from_millis(300)).await;
+///                 results.set(search(&query).await);
+///             }
+///         });
+///     }
+///
+///     html! {
+///         <ul>{ for results.iter().map(|m| html! { <li>{m}</li> }) }</ul>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_async_effect_with<T, F, Fut, D>(deps: T, f: F)
+where
+    T: PartialEq + 'static,
+    F: FnOnce(&T) -> Fut + 'static,
+    Fut: Future<Output = D> + 'static,
+    D: AsyncTearDown,
+{
+    use_effect_with(deps, move |deps| {
+        let destructor: Rc<RefCell<Option<D>>> = Rc::default();
+
+        let handle = {
+            let task = f(deps);
+            let destructor = destructor.clone();
+            spawn_abortable(async move {
+                *destructor.borrow_mut() = Some(task.await);
+            })
+        };
+
+        move || {
+            drop(handle);
+
+            let destructor = destructor.borrow_mut().take();
+            if let Some(m) = destructor {
+                spawn_local(m.tear_down());
+            }
+        }
+    });
 }
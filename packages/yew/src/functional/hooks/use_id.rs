@@ -0,0 +1,103 @@
+use super::{Hook, HookContext};
+use crate::virtual_dom::AttrValue;
+
+/// This hook is used to create an id which is unique within the application and identical when
+/// rendered on the server and hydrated on the client.
+///
+/// The id is derived from the position of the component in the component tree, which makes it
+/// suitable to link elements with attributes such as `for` or `aria-describedby`. It does not
+/// change between renders.
+///
+/// Ids start with the prefix set by [`Renderer::id_prefix`](crate::Renderer::id_prefix) or
+/// [`ServerRenderer::id_prefix`](crate::ServerRenderer::id_prefix), `yew-` by default.
+/// Applications sharing a page should be given distinct prefixes.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+///
+/// #[component]
+/// fn Checkbox() -> Html {
+///     let id = use_id();
+///
+///     html! {
+///         <>
+///             <input type="checkbox" id={id.clone()} />
+///             <label for={id}>{"Subscribe"}</label>
+///         </>
+///     }
+/// }
+/// ```
+pub fn use_id() -> impl Hook<Output = AttrValue> {
+    struct UseId;
+
+    impl Hook for UseId {
+        type Output = AttrValue;
+
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            let hook_pos = ctx.counter;
+            let scope = ctx.scope.clone();
+
+            let id = ctx.next_state(move |_| -> AttrValue {
+                format!("{}_{hook_pos}", scope.position_id()).into()
+            });
+
+            (*id).clone()
+        }
+    }
+
+    UseId
+}
+
+#[cfg(all(test, not(target_arch = "wasm32"), feature = "ssr"))]
+mod ssr_tests {
+    use tokio::test;
+
+    use crate::ServerRenderer;
+    use crate::prelude::*;
+
+    #[component]
+    fn Field() -> Html {
+        let id = use_id();
+
+        html! { <input id={id} /> }
+    }
+
+    #[component]
+    fn Form() -> Html {
+        html! {
+            <form>
+                <Field />
+                <Field />
+            </form>
+        }
+    }
+
+    fn ids(s: &str) -> Vec<&str> {
+        s.split(r#"id=""#)
+            .skip(1)
+            .filter_map(|m| m.split('"').next())
+            .collect()
+    }
+
+    #[test]
+    async fn ids_are_unique_and_stable() {
+        let first = ServerRenderer::<Form>::new().render().await;
+        let second = ServerRenderer::<Form>::new().render().await;
+
+        let ids = ids(&first);
+        assert_eq!(ids, ["yew-0-0_0", "yew-0-1_0"], "{first}");
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    async fn ids_use_prefix() {
+        let s = ServerRenderer::<Form>::new()
+            .id_prefix("app-")
+            .render()
+            .await;
+
+        assert_eq!(ids(&s), ["app-0-0_0", "app-0-1_0"], "{s}");
+    }
+}
@@ -17,7 +17,7 @@ use crate::suspense::{Suspension, SuspensionResult};
     not(target_os = "wasi"),
     not(feature = "not_browser_env")
 ))]
-async fn decode_base64(s: &str) -> Result<Vec<u8>, JsValue> {
+pub(crate) async fn decode_base64(s: &str) -> Result<Vec<u8>, JsValue> {
     use gloo::utils::window;
     use js_sys::Uint8Array;
     use wasm_bindgen::JsCast;
@@ -43,7 +43,7 @@ async fn decode_base64(s: &str) -> Result<Vec<u8>, JsValue> {
     target_os = "wasi",
     feature = "not_browser_env"
 ))]
-async fn decode_base64(_s: &str) -> Result<Vec<u8>, JsValue> {
+pub(crate) async fn decode_base64(_s: &str) -> Result<Vec<u8>, JsValue> {
     unreachable!("this function is not callable under non-wasm targets!");
 }
 
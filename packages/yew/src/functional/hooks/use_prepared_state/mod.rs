@@ -7,6 +7,8 @@ pub(super) mod feat_none;
 #[cfg(feature = "ssr")]
 mod feat_ssr;
 
+#[cfg(feature = "hydration")]
+pub(crate) use feat_hydration::decode_base64;
 #[cfg(all(feature = "hydration", not(feature = "ssr")))]
 pub use feat_hydration::*;
 #[cfg(all(feature = "ssr", feature = "hydration"))]
@@ -1,17 +1,28 @@
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::fmt;
 use std::marker::PhantomData;
 use std::ops::Deref;
-use std::rc::Rc;
+use std::rc::{Rc, Weak};
 
+use futures::future::LocalBoxFuture;
 use implicit_clone::ImplicitClone;
+use slab::Slab;
 
 use crate::Callback;
-use crate::functional::{Hook, HookContext, hook};
+use crate::functional::{AbortOnDrop, Hook, HookContext, abortable, hook};
 use crate::html::IntoPropValue;
+use crate::platform::spawn_local;
 
 type DispatchFn<T> = Rc<dyn Fn(<T as Reducible>::Action)>;
 
+/// An asynchronous effect started by an action of an [`AsyncReducible`].
+///
+/// The action the effect resolves to is dispatched to the same reducer.
+pub type ReducerEffect<A> = LocalBoxFuture<'static, A>;
+
+type EffectFn<T> =
+    fn(&Rc<T>, &<T as Reducible>::Action) -> Option<ReducerEffect<<T as Reducible>::Action>>;
+
 /// A trait that implements a reducer function of a type.
 pub trait Reducible {
     /// The action type of the reducer.
@@ -21,6 +32,66 @@ pub trait Reducible {
     fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self>;
 }
 
+/// A [`Reducible`] whose actions can start asynchronous effects.
+///
+/// Used with [`use_async_reducer`].
+pub trait AsyncReducible: Reducible {
+    /// Returns the effect started by an action, if any.
+    ///
+    /// This is called with the state before the action is reduced. The action the effect resolves
+    /// to is dispatched to the reducer once it has completed.
+    fn effect(self: &Rc<Self>, action: &Self::Action) -> Option<ReducerEffect<Self::Action>>;
+}
+
+type WeakDispatchFn<A> = Weak<dyn Fn(A)>;
+
+/// The effects of a reducer which have not completed yet.
+struct PendingEffects<A> {
+    handles: RefCell<Slab<AbortOnDrop>>,
+    destroyed: Cell<bool>,
+    dispatch: RefCell<Option<WeakDispatchFn<A>>>,
+}
+
+impl<A> PendingEffects<A> {
+    fn new() -> Self {
+        Self {
+            handles: RefCell::default(),
+            destroyed: Cell::new(false),
+            dispatch: RefCell::default(),
+        }
+    }
+
+    fn cancel(&self) {
+        self.destroyed.set(true);
+        self.handles.borrow_mut().clear();
+    }
+}
+
+impl<A: 'static> PendingEffects<A> {
+    /// Runs an effect, dispatching its result unless it has been cancelled.
+    fn spawn(self: &Rc<Self>, effect: ReducerEffect<A>) {
+        if self.destroyed.get() {
+            return;
+        }
+
+        let (effect, handle) = abortable(effect);
+        let key = self.handles.borrow_mut().insert(handle);
+
+        let this = self.clone();
+        spawn_local(async move {
+            // Cancelled effects have already been removed.
+            if let Ok(action) = effect.await {
+                this.handles.borrow_mut().remove(key);
+
+                let dispatch = this.dispatch.borrow().as_ref().and_then(Weak::upgrade);
+                if let Some(dispatch) = dispatch {
+                    dispatch(action);
+                }
+            }
+        });
+    }
+}
+
 struct UseReducer<T>
 where
     T: Reducible,
@@ -28,6 +99,16 @@ where
     current_state: Rc<RefCell<Rc<T>>>,
 
     dispatch: DispatchFn<T>,
+    effects: Rc<PendingEffects<T::Action>>,
+}
+
+impl<T> Drop for UseReducer<T>
+where
+    T: Reducible,
+{
+    fn drop(&mut self) {
+        self.effects.cancel();
+    }
 }
 
 /// State handle for [`use_reducer`] and [`use_reducer_eq`] hook
@@ -254,10 +335,11 @@ where
     }
 }
 
-/// The base function of [`use_reducer`] and [`use_reducer_eq`]
+/// The base function of [`use_reducer`], [`use_reducer_eq`] and [`use_async_reducer`]
 fn use_reducer_base<'hook, T>(
     init_fn: impl 'hook + FnOnce() -> T,
     should_render_fn: fn(&T, &T) -> bool,
+    effect_fn: Option<EffectFn<T>>,
 ) -> impl 'hook + Hook<Output = UseReducerHandle<T>>
 where
     T: Reducible + 'static,
@@ -271,6 +353,7 @@ where
 
         init_fn: F,
         should_render_fn: fn(&T, &T) -> bool,
+        effect_fn: Option<EffectFn<T>>,
     }
 
     impl<'hook, T, F> Hook for HookProvider<'hook, T, F>
@@ -284,16 +367,22 @@ where
             let Self {
                 init_fn,
                 should_render_fn,
+                effect_fn,
                 ..
             } = self;
 
             let state = ctx.next_state(move |re_render| {
                 let val = Rc::new(RefCell::new(Rc::new(init_fn())));
                 let should_render_fn = Rc::new(should_render_fn);
+                let effects = Rc::new(PendingEffects::new());
+
+                let dispatch: DispatchFn<T> = {
+                    let val = val.clone();
+                    let effects = effects.clone();
+
+                    Rc::new(move |action: T::Action| {
+                        let effect = effect_fn.and_then(|f| f(&val.borrow(), &action));
 
-                UseReducer {
-                    current_state: val.clone(),
-                    dispatch: Rc::new(move |action: T::Action| {
                         let should_render = {
                             let should_render_fn = should_render_fn.clone();
                             let mut val = val.borrow_mut();
@@ -309,7 +398,18 @@ where
                         if should_render {
                             re_render()
                         }
-                    }),
+
+                        if let Some(effect) = effect {
+                            effects.spawn(effect);
+                        }
+                    })
+                };
+                *effects.dispatch.borrow_mut() = Some(Rc::downgrade(&dispatch));
+
+                UseReducer {
+                    current_state: val,
+                    dispatch,
+                    effects,
                 }
             });
 
@@ -329,6 +429,7 @@ where
         _marker: PhantomData,
         init_fn,
         should_render_fn,
+        effect_fn,
     }
 }
 
@@ -432,7 +533,7 @@ where
     T: Reducible + 'static,
     F: FnOnce() -> T,
 {
-    use_reducer_base(init_fn, |a, b| !address_eq(a, b))
+    use_reducer_base(init_fn, |a, b| !address_eq(a, b), None)
 }
 
 /// [`use_reducer`] but only re-renders when `prev_state != next_state`.
@@ -445,7 +546,87 @@ where
     T: Reducible + PartialEq + 'static,
     F: FnOnce() -> T,
 {
-    use_reducer_base(init_fn, |a, b| !address_eq(a, b) && a != b)
+    use_reducer_base(init_fn, |a, b| !address_eq(a, b) && a != b, None)
+}
+
+/// [`use_reducer`] for states whose actions start asynchronous effects.
+///
+/// When an action is dispatched, the effect returned by [`AsyncReducible::effect`] is started
+/// after the action has been reduced. The action the effect resolves to is dispatched through the
+/// same [`UseReducerDispatcher`]. Effects which have not completed when the component is destroyed
+/// are cancelled.
+///
+/// # Example
+/// ```rust
+/// # use yew::prelude::*;
+/// # use std::rc::Rc;
+/// # async fn fetch_user(id: u32) -> String { format!("user {id}") }
+/// #
+/// enum UserAction {
+///     Load(u32),
+///     Loaded(String),
+/// }
+///
+/// #[derive(Default)]
+/// struct UserState {
+///     loading: bool,
+///     name: Option<String>,
+/// }
+///
+/// impl Reducible for UserState {
+///     type Action = UserAction;
+///
+///     fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+///         match action {
+///             UserAction::Load(_) => Self {
+///                 loading: true,
+///                 name: None,
+///             },
+///             UserAction::Loaded(name) => Self {
+///                 loading: false,
+///                 name: Some(name),
+///             },
+///         }
+///         .into()
+///     }
+/// }
+///
+/// impl AsyncReducible for UserState {
+///     fn effect(self: &Rc<Self>, action: &Self::Action) -> Option<ReducerEffect<Self::Action>> {
+///         match *action {
+///             UserAction::Load(id) => {
+///                 Some(Box::pin(
+///                     async move { UserAction::Loaded(fetch_user(id).await) },
+///                 ))
+///             }
+///             UserAction::Loaded(_) => None,
+///         }
+///     }
+/// }
+///
+/// #[component]
+/// fn User() -> Html {
+///     let user = use_async_reducer(UserState::default);
+///     let onclick = {
+///         let user = user.clone();
+///         Callback::from(move |_| user.dispatch(UserAction::Load(1)))
+///     };
+///
+///     html! {
+///         <>
+///             <button {onclick} disabled={user.loading}>{ "Load" }</button>
+///             <p>{ user.name.clone().unwrap_or_default() }</p>
+///         </>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_async_reducer<T, F>(init_fn: F) -> UseReducerHandle<T>
+where
+    T: AsyncReducible + 'static,
+    F: FnOnce() -> T,
+{
+    use_reducer_base(init_fn, |a, b| !address_eq(a, b), Some(T::effect))
 }
 
 /// Check if two references point to the same address.
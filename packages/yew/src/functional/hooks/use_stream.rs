@@ -0,0 +1,208 @@
+use std::rc::Rc;
+
+use futures::{Stream, StreamExt};
+
+use crate::functional::{AbortOnDrop, hook, spawn_abortable, use_mut_ref, use_state};
+
+/// A stream consumed for the dependencies of a hook, aborted when dropped.
+struct StreamTask<D, T> {
+    deps: Rc<D>,
+    id: u32,
+    init: Rc<T>,
+    _handle: AbortOnDrop,
+}
+
+/// Consumes the stream created by `f` for the latest `deps` and folds its items into a value.
+///
+/// The stream is aborted when the dependencies change or the component is destroyed.
+#[hook]
+fn use_stream_base<D, F, S, I, G, T>(deps: D, f: F, init: I, fold: G) -> Rc<T>
+where
+    D: PartialEq + 'static,
+    F: FnOnce(Rc<D>) -> S,
+    S: Stream + 'static,
+    I: FnOnce() -> T,
+    G: FnMut(&T, S::Item) -> T + 'static,
+    T: 'static,
+{
+    let output = use_state(|| -> Option<(u32, Rc<T>)> { None });
+    let task = use_mut_ref(|| -> Option<StreamTask<D, T>> { None });
+
+    let mut task = task.borrow_mut();
+    if task.as_ref().is_none_or(|m| *m.deps != deps) {
+        let id = task.as_ref().map_or(0, |m| m.id.wrapping_add(1));
+        *task = None;
+
+        let deps = Rc::new(deps);
+        let init = Rc::new(init());
+        let stream = f(deps.clone());
+
+        let handle = {
+            let output = output.clone();
+            let mut acc = init.clone();
+            let mut fold = fold;
+
+            spawn_abortable(async move {
+                futures::pin_mut!(stream);
+                while let Some(item) = stream.next().await {
+                    acc = Rc::new(fold(&acc, item));
+                    output.set(Some((id, acc.clone())));
+                }
+            })
+        };
+
+        *task = Some(StreamTask {
+            deps,
+            id,
+            init,
+            _handle: handle,
+        });
+    }
+
+    let task = task.as_ref().expect("stream has been spawned");
+    match *output {
+        // Values folded from a previous stream are not used.
+        Some((output_id, ref m)) if output_id == task.id => m.clone(),
+        _ => task.init.clone(),
+    }
+}
+
+/// This hook is used to consume a [`Stream`] in a function component.
+///
+/// The stream returned from `f` for the latest `deps` is consumed until it ends, re-rendering the
+/// component for every item. The latest item is returned, or `None` if the stream has not
+/// produced an item yet.
+///
+/// When the dependencies change, the current stream is aborted and `f` is called again. The
+/// stream is also aborted when the component is destroyed.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use futures::StreamExt;
+/// use yew::platform::time::interval;
+/// use yew::prelude::*;
+///
+/// #[derive(Properties, PartialEq)]
+/// struct Props {
+///     period_millis: u64,
+/// }
+///
+/// #[component]
+/// fn Ticks(props: &Props) -> Html {
+///     let tick = use_stream(props.period_millis, |period_millis| {
+///         interval(Duration::from_millis(*period_millis))
+///             .enumerate()
+///             .map(|(i, _)| i)
+///     });
+///
+///     html! { <p>{"Tick: "}{tick.map(|m| *m).unwrap_or_default()}</p> }
+/// }
+/// ```
+#[hook]
+pub fn use_stream<D, F, S>(deps: D, f: F) -> Option<Rc<S::Item>>
+where
+    D: PartialEq + 'static,
+    F: FnOnce(Rc<D>) -> S,
+    S: Stream + 'static,
+{
+    let latest = use_stream_base(deps, f, || None, |_, item| Some(Rc::new(item)));
+
+    (*latest).clone()
+}
+
+/// This hook is used to fold the items of a [`Stream`] into a value in a function component.
+///
+/// The value is initialised with `init` and `fold` is called with the current value and each
+/// item of the stream returned from `f` for the latest `deps`, re-rendering the component with
+/// the new value.
+///
+/// When the dependencies change, the current stream is aborted, the value is initialised again
+/// and `f` is called again. The stream is also aborted when the component is destroyed.
+/// `init` and `fold` are only used when a stream is created.
+///
+/// # Example
+///
+/// ```rust
+/// use futures::stream::Stream;
+/// use yew::prelude::*;
+///
+/// fn messages() -> impl Stream<Item = String> {
+///     // ...
+///     # futures::stream::empty()
+/// }
+///
+/// #[component]
+/// fn Chat() -> Html {
+///     let messages = use_stream_fold(
+///         (),
+///         |_| messages(),
+///         Vec::new,
+///         |messages: &Vec<String>, message| {
+///             let mut messages = messages.clone();
+///             messages.push(message);
+///             messages
+///         },
+///     );
+///
+///     html! {
+///         <ul>{ for messages.iter().map(|m| html! { <li>{m}</li> }) }</ul>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_stream_fold<D, F, S, I, G, T>(deps: D, f: F, init: I, fold: G) -> Rc<T>
+where
+    D: PartialEq + 'static,
+    F: FnOnce(Rc<D>) -> S,
+    S: Stream + 'static,
+    I: FnOnce() -> T,
+    G: FnMut(&T, S::Item) -> T + 'static,
+    T: 'static,
+{
+    use_stream_base(deps, f, init, fold)
+}
+
+#[cfg(all(test, not(target_arch = "wasm32"), feature = "ssr"))]
+mod ssr_tests {
+    use std::time::Duration;
+
+    use futures::stream;
+    use tokio::test;
+
+    use crate::ServerRenderer;
+    use crate::platform::time::sleep;
+    use crate::prelude::*;
+    use crate::suspense::use_future;
+
+    #[component]
+    fn Content() -> HtmlResult {
+        let latest = use_stream((), |_| stream::iter([1, 2, 3]));
+        let sum = use_stream_fold((), |_| stream::iter([1, 2, 3]), || 0, |m, n| m + n);
+        // Suspends until the streams have been consumed.
+        use_future(|| sleep(Duration::from_millis(10)))?;
+
+        Ok(html! { <p>{latest.map(|m| *m)}{"/"}{*sum}</p> })
+    }
+
+    #[component]
+    fn App() -> Html {
+        html! {
+            <Suspense fallback={Html::default()}>
+                <Content />
+            </Suspense>
+        }
+    }
+
+    #[test]
+    async fn consumes_streams() {
+        let s = ServerRenderer::<App>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(s, "<p>3/6</p>");
+    }
+}
@@ -0,0 +1,263 @@
+use std::cell::{Cell, RefCell};
+use std::rc::{Rc, Weak};
+
+use super::TearDown;
+use crate::callback::Callback;
+use crate::functional::{Effect, Hook, HookContext, ReRender};
+#[cfg(any(feature = "hydration", feature = "ssr"))]
+use crate::html::RenderMode;
+
+/// The snapshot last rendered by the component and how to obtain the current one.
+struct Snapshot<T> {
+    rendered: RefCell<Option<T>>,
+    get_snapshot: RefCell<Option<Rc<dyn Fn() -> T>>>,
+    re_render: ReRender,
+}
+
+impl<T> Snapshot<T>
+where
+    T: PartialEq,
+{
+    /// Re-renders the component if the snapshot differs from the rendered one.
+    fn check(&self) {
+        let get_snapshot = match self.get_snapshot.borrow().clone() {
+            Some(m) => m,
+            None => return,
+        };
+
+        if self.rendered.borrow().as_ref() != Some(&get_snapshot()) {
+            (self.re_render)();
+        }
+    }
+}
+
+enum Subscription<D> {
+    Pending(Box<dyn FnOnce(Callback<()>) -> D>),
+    Active(D),
+    None,
+}
+
+struct UseSyncExternalStore<T, D>
+where
+    D: TearDown,
+{
+    snapshot: Rc<Snapshot<T>>,
+    subscription: RefCell<Subscription<D>>,
+    /// Whether the server snapshot is rendered, until the component has been hydrated.
+    server_snapshot: Cell<bool>,
+}
+
+impl<T, D> Effect for UseSyncExternalStore<T, D>
+where
+    T: 'static + PartialEq,
+    D: TearDown,
+{
+    fn rendered(&self) {
+        let hydrated = self.server_snapshot.replace(false);
+
+        let subscription =
+            std::mem::replace(&mut *self.subscription.borrow_mut(), Subscription::None);
+        let subscription = match subscription {
+            Subscription::Pending(subscribe) => {
+                let snapshot = Rc::downgrade(&self.snapshot);
+                let on_change = Callback::from(move |_| {
+                    if let Some(m) = Weak::upgrade(&snapshot) {
+                        m.check();
+                    }
+                });
+
+                let destructor = subscribe(on_change);
+                // The store may have changed between rendering and subscribing.
+                self.snapshot.check();
+
+                Subscription::Active(destructor)
+            }
+            m => {
+                // The server snapshot has been rendered while hydrating.
+                if hydrated {
+                    self.snapshot.check();
+                }
+
+                m
+            }
+        };
+
+        *self.subscription.borrow_mut() = subscription;
+    }
+}
+
+impl<T, D> Drop for UseSyncExternalStore<T, D>
+where
+    D: TearDown,
+{
+    fn drop(&mut self) {
+        let subscription = std::mem::replace(self.subscription.get_mut(), Subscription::None);
+        if let Subscription::Active(destructor) = subscription {
+            destructor.tear_down();
+        }
+    }
+}
+
+/// Returns whether the server snapshot should be rendered, which is the case during server-side
+/// rendering and hydration.
+fn renders_server_snapshot(ctx: &HookContext) -> bool {
+    #[cfg(any(feature = "hydration", feature = "ssr"))]
+    {
+        #[allow(unreachable_patterns)]
+        match ctx.creation_mode {
+            #[cfg(feature = "ssr")]
+            RenderMode::Ssr => true,
+            #[cfg(feature = "hydration")]
+            RenderMode::Hydration => true,
+            _ => false,
+        }
+    }
+
+    #[cfg(not(any(feature = "hydration", feature = "ssr")))]
+    {
+        let _ = ctx;
+        false
+    }
+}
+
+/// This hook is used to subscribe to a state that is managed outside of Yew, such as a global
+/// store or a JavaScript library.
+///
+/// `subscribe` is called once the component has been rendered for the first time with a
+/// callback to notify when the store changes. It returns a destructor that unsubscribes from the
+/// store when the component is destroyed. It is only called once, later `subscribe` functions are
+/// ignored.
+///
+/// `get_snapshot` returns the current state of the store. It is called during each render, so
+/// that a render always reflects the store as it is, and whenever the store notifies a change.
+/// The component is only re-rendered when the snapshot is different from the rendered one.
+///
+/// `get_server_snapshot` returns the state used during server-side rendering and hydration. It
+/// must return the same value on the server and on the client, the component is re-rendered with
+/// the snapshot returned by `get_snapshot` once it has been hydrated.
+///
+/// # Example
+///
+/// ```rust
+/// use std::cell::{Cell, RefCell};
+///
+/// use yew::prelude::*;
+///
+/// thread_local! {
+///     static COUNT: Cell<u32> = const { Cell::new(0) };
+///     static LISTENERS: RefCell<Vec<Callback<()>>> = const { RefCell::new(Vec::new()) };
+/// }
+///
+/// fn increment() {
+///     COUNT.with(|m| m.set(m.get() + 1));
+///     LISTENERS.with(|m| m.borrow().iter().for_each(|m| m.emit(())));
+/// }
+///
+/// #[component]
+/// fn Counter() -> Html {
+///     let count = use_sync_external_store(
+///         |on_change| {
+///             LISTENERS.with(|m| m.borrow_mut().push(on_change.clone()));
+///             move || LISTENERS.with(|m| m.borrow_mut().retain(|m| m != &on_change))
+///         },
+///         || COUNT.with(|m| m.get()),
+///         || 0,
+///     );
+///
+///     html! { <button onclick={Callback::from(|_| increment())}>{count}</button> }
+/// }
+/// ```
+pub fn use_sync_external_store<T, S, D, G, GS>(
+    subscribe: S,
+    get_snapshot: G,
+    get_server_snapshot: GS,
+) -> impl Hook<Output = T>
+where
+    T: 'static + Clone + PartialEq,
+    S: 'static + FnOnce(Callback<()>) -> D,
+    D: TearDown,
+    G: 'static + Fn() -> T,
+    GS: FnOnce() -> T,
+{
+    struct HookProvider<S, G, GS> {
+        subscribe: S,
+        get_snapshot: G,
+        get_server_snapshot: GS,
+    }
+
+    impl<T, S, D, G, GS> Hook for HookProvider<S, G, GS>
+    where
+        T: 'static + Clone + PartialEq,
+        S: 'static + FnOnce(Callback<()>) -> D,
+        D: TearDown,
+        G: 'static + Fn() -> T,
+        GS: FnOnce() -> T,
+    {
+        type Output = T;
+
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            let Self {
+                subscribe,
+                get_snapshot,
+                get_server_snapshot,
+            } = self;
+
+            let renders_server_snapshot = renders_server_snapshot(ctx);
+            let state = ctx.next_effect(|re_render| UseSyncExternalStore::<T, D> {
+                snapshot: Rc::new(Snapshot {
+                    rendered: RefCell::default(),
+                    get_snapshot: RefCell::default(),
+                    re_render,
+                }),
+                subscription: RefCell::new(Subscription::Pending(Box::new(subscribe))),
+                server_snapshot: Cell::new(renders_server_snapshot),
+            });
+
+            let value = if state.server_snapshot.get() {
+                get_server_snapshot()
+            } else {
+                get_snapshot()
+            };
+
+            *state.snapshot.rendered.borrow_mut() = Some(value.clone());
+            *state.snapshot.get_snapshot.borrow_mut() = Some(Rc::new(get_snapshot));
+
+            value
+        }
+    }
+
+    HookProvider {
+        subscribe,
+        get_snapshot,
+        get_server_snapshot,
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32"), feature = "ssr"))]
+mod ssr_tests {
+    use tokio::test;
+
+    use crate::ServerRenderer;
+    use crate::prelude::*;
+
+    #[component]
+    fn Comp() -> Html {
+        let value = use_sync_external_store(
+            |_| -> () { panic!("subscribed during server-side rendering") },
+            || "client",
+            || "server",
+        );
+
+        html! { <div>{value}</div> }
+    }
+
+    #[test]
+    async fn renders_server_snapshot() {
+        let s = ServerRenderer::<Comp>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert_eq!(s, "<div>server</div>");
+    }
+}
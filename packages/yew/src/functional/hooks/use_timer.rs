@@ -0,0 +1,278 @@
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::rc::Rc;
+use std::time::Duration;
+
+use crate::functional::{
+    AbortOnDrop, hook, spawn_abortable, use_effect_with, use_mut_ref, use_ref, use_state_eq,
+};
+use crate::platform::time::sleep;
+
+fn duration(millis: u32) -> Duration {
+    Duration::from_millis(millis.into())
+}
+
+/// This hook is used to call a function repeatedly, every `millis` milliseconds.
+///
+/// The interval is restarted when `millis` changes and cleared when the component is destroyed.
+/// The callback passed on the latest render is called. Nothing is scheduled when rendered on the
+/// server.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+///
+/// #[component]
+/// fn Clock() -> Html {
+///     let seconds = use_state(|| 0);
+///     {
+///         let seconds = seconds.clone();
+///         use_interval(1000, move || seconds.set(*seconds + 1));
+///     }
+///
+///     html! { <p>{"Seconds: "}{*seconds}</p> }
+/// }
+/// ```
+#[hook]
+pub fn use_interval<F>(millis: u32, callback: F)
+where
+    F: Fn() + 'static,
+{
+    let latest = use_mut_ref(|| -> Option<Rc<dyn Fn()>> { None });
+    *latest.borrow_mut() = Some(Rc::new(callback));
+
+    use_effect_with(millis, move |millis| {
+        let period = duration(*millis);
+        let handle = spawn_abortable(async move {
+            loop {
+                sleep(period).await;
+
+                let callback = latest.borrow().clone();
+                if let Some(callback) = callback {
+                    callback();
+                }
+            }
+        });
+
+        move || drop(handle)
+    });
+}
+
+struct Timeout {
+    millis: Cell<u32>,
+    callback: RefCell<Option<Rc<dyn Fn()>>>,
+    mounted: Cell<bool>,
+    handle: RefCell<Option<AbortOnDrop>>,
+}
+
+impl Timeout {
+    fn start(self: &Rc<Self>) {
+        self.cancel();
+        if !self.mounted.get() {
+            return;
+        }
+
+        let this = self.clone();
+        let handle = spawn_abortable(async move {
+            sleep(duration(this.millis.get())).await;
+
+            // Dropping the handle does not abort the future, as it does not await anymore.
+            this.handle.borrow_mut().take();
+            let callback = this.callback.borrow().clone();
+            if let Some(callback) = callback {
+                callback();
+            }
+        });
+        *self.handle.borrow_mut() = Some(handle);
+    }
+
+    fn cancel(&self) {
+        self.handle.borrow_mut().take();
+    }
+}
+
+/// Handle for the [`use_timeout`] hook.
+#[derive(Clone)]
+pub struct UseTimeoutHandle {
+    inner: Rc<Timeout>,
+}
+
+impl UseTimeoutHandle {
+    /// Cancels the timeout if it is pending.
+    pub fn cancel(&self) {
+        self.inner.cancel();
+    }
+
+    /// Starts the timeout again, cancelling the pending timeout if any.
+    ///
+    /// This can also be used to call the function again after the timeout has elapsed.
+    pub fn reset(&self) {
+        self.inner.start();
+    }
+
+    /// Returns `true` if the timeout is pending.
+    pub fn is_pending(&self) -> bool {
+        self.inner.handle.borrow().is_some()
+    }
+}
+
+impl PartialEq for UseTimeoutHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl fmt::Debug for UseTimeoutHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UseTimeoutHandle")
+            .field("millis", &self.inner.millis.get())
+            .field("pending", &self.is_pending())
+            .finish()
+    }
+}
+
+/// This hook is used to call a function once, after `millis` milliseconds.
+///
+/// The timeout is started when the component is rendered and restarted when `millis` changes. It
+/// is cancelled when the component is destroyed. The callback passed on the latest render is
+/// called. Nothing is scheduled when rendered on the server.
+///
+/// The returned [`UseTimeoutHandle`] can cancel or reset the timeout.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+///
+/// #[component]
+/// fn Notification() -> Html {
+///     let visible = use_state(|| true);
+///     let timeout = {
+///         let visible = visible.clone();
+///         use_timeout(3000, move || visible.set(false))
+///     };
+///     // Keeps the notification visible while hovered.
+///     let onmouseenter = {
+///         let timeout = timeout.clone();
+///         move |_| timeout.cancel()
+///     };
+///     let onmouseleave = move |_| timeout.reset();
+///
+///     html! {
+///         if *visible {
+///             <div {onmouseenter} {onmouseleave}>{"Saved!"}</div>
+///         }
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_timeout<F>(millis: u32, callback: F) -> UseTimeoutHandle
+where
+    F: Fn() + 'static,
+{
+    let inner = use_ref(|| Timeout {
+        millis: Cell::new(millis),
+        callback: RefCell::default(),
+        mounted: Cell::new(false),
+        handle: RefCell::default(),
+    });
+    *inner.callback.borrow_mut() = Some(Rc::new(callback));
+
+    {
+        let inner = inner.clone();
+        use_effect_with(millis, move |millis| {
+            inner.millis.set(*millis);
+            inner.mounted.set(true);
+            inner.start();
+
+            move || {
+                inner.mounted.set(false);
+                inner.cancel();
+            }
+        });
+    }
+
+    UseTimeoutHandle { inner }
+}
+
+/// This hook is used to debounce a value.
+///
+/// Returns the value once it has not changed for `millis` milliseconds, or its initial value until
+/// then. This is useful to delay expensive work, such as searching, until the user has stopped
+/// typing. The value is never updated when rendered on the server.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+///
+/// #[derive(Properties, PartialEq)]
+/// struct Props {
+///     query: String,
+/// }
+///
+/// #[component]
+/// fn SearchResults(props: &Props) -> Html {
+///     let query = use_debounced_value(props.query.clone(), 300);
+///
+///     html! { <p>{"Searching for "}{query}</p> }
+/// }
+/// ```
+#[hook]
+pub fn use_debounced_value<T>(value: T, millis: u32) -> T
+where
+    T: Clone + PartialEq + 'static,
+{
+    let debounced = use_state_eq(|| value.clone());
+
+    {
+        let debounced = debounced.setter();
+        use_effect_with((value, millis), move |(value, millis)| {
+            let value = value.clone();
+            let delay = duration(*millis);
+            let handle = spawn_abortable(async move {
+                sleep(delay).await;
+                debounced.set(value);
+            });
+
+            move || drop(handle)
+        });
+    }
+
+    (*debounced).clone()
+}
+
+#[cfg(all(test, not(target_arch = "wasm32"), feature = "ssr"))]
+mod ssr_tests {
+    use tokio::test;
+
+    use crate::ServerRenderer;
+    use crate::prelude::*;
+
+    #[derive(Properties, PartialEq)]
+    struct Props {
+        query: String,
+    }
+
+    #[component]
+    fn Search(props: &Props) -> Html {
+        let query = use_debounced_value(props.query.clone(), 0);
+        use_interval(0, || panic!("interval called during SSR"));
+        use_timeout(0, || panic!("timeout called during SSR"));
+
+        html! { <p>{query}</p> }
+    }
+
+    #[test]
+    async fn timers_do_nothing_during_ssr() {
+        let s = ServerRenderer::<Search>::with_props(|| Props {
+            query: "yew".to_owned(),
+        })
+        .hydratable(false)
+        .render()
+        .await;
+
+        assert_eq!(s, "<p>yew</p>");
+    }
+}
@@ -28,11 +28,15 @@ use std::rc::Rc;
 use wasm_bindgen::prelude::*;
 
 use crate::Properties;
-#[cfg(all(feature = "hydration", feature = "ssr"))]
+#[cfg(any(feature = "hydration", feature = "ssr"))]
 use crate::html::RenderMode;
 use crate::html::{AnyScope, BaseComponent, Context, HtmlResult};
 
+#[cfg(feature = "debug-hooks")]
+mod debug_hooks;
 mod hooks;
+#[cfg(feature = "debug-hooks")]
+pub use debug_hooks::HookInfo;
 pub use hooks::*;
 /// This attribute creates a function component from a normal Rust function.
 ///
@@ -60,6 +64,38 @@ pub use hooks::*;
 ///     }
 /// }
 /// ```
+///
+/// # Memoization
+///
+/// By default, a function component is rendered again when it receives properties which are
+/// not equal to the previous ones according to [`PartialEq`]. The comparison can be customised
+/// with the `memo` option:
+///
+/// - `#[component(memo)]` compares the properties with [`PartialEq`].
+/// - `#[component(memo(shallow))]` compares them with
+///   [`ShallowEq::shallow_eq`](crate::html::ShallowEq::shallow_eq), which compares
+///   [`Rc`](std::rc::Rc) fields by pointer instead of comparing their content. The properties
+///   must be derived with `#[shallow_eq]`.
+/// - `#[component(memo = eq)]` compares them with `eq`, a function or closure of type
+///   `fn(&Props, &Props) -> bool` which returns `true` if the properties are equal.
+///
+/// ```rust
+/// # use yew::prelude::*;
+/// #
+/// # #[derive(Properties, PartialEq)]
+/// # pub struct RowProps {
+/// #     id: u32,
+/// #     text: String,
+/// # }
+/// #
+/// // Rows are never updated once rendered, only the id is compared.
+/// #[component(memo = |a: &RowProps, b: &RowProps| a.id == b.id)]
+/// pub fn Row(props: &RowProps) -> Html {
+///     html! {
+///         <li>{ &props.text }</li>
+///     }
+/// }
+/// ```
 pub use yew_macro::function_component as component;
 /// A re-export of [`component`](yew_macro::function_component) with the older name.
 #[deprecated(since = "0.22.0", note = "renamed to `#[component]")]
@@ -84,12 +120,13 @@ pub(crate) trait Effect {
 /// A hook context to be passed to hooks.
 pub struct HookContext {
     pub(crate) scope: AnyScope,
-    #[cfg(all(feature = "hydration", feature = "ssr"))]
+    #[cfg(any(feature = "hydration", feature = "ssr"))]
     creation_mode: RenderMode,
     re_render: ReRender,
 
     states: Vec<Rc<dyn Any>>,
     effects: Vec<Rc<dyn Effect>>,
+    layout_effects: Vec<Rc<dyn Effect>>,
 
     #[cfg(any(feature = "hydration", feature = "ssr"))]
     prepared_states: Vec<Rc<dyn PreparedState>>,
@@ -102,20 +139,23 @@ pub struct HookContext {
     counter: usize,
     #[cfg(debug_assertions)]
     total_hook_counter: Option<usize>,
+
+    #[cfg(feature = "debug-hooks")]
+    recorder: debug_hooks::HookRecorder,
 }
 
 impl HookContext {
     fn new(
         scope: AnyScope,
         re_render: ReRender,
-        #[cfg(all(feature = "hydration", feature = "ssr"))] creation_mode: RenderMode,
+        #[cfg(any(feature = "hydration", feature = "ssr"))] creation_mode: RenderMode,
         #[cfg(feature = "hydration")] prepared_state: Option<&str>,
     ) -> RefCell<Self> {
         RefCell::new(HookContext {
             scope,
             re_render,
 
-            #[cfg(all(feature = "hydration", feature = "ssr"))]
+            #[cfg(any(feature = "hydration", feature = "ssr"))]
             creation_mode,
 
             states: Vec::new(),
@@ -123,6 +163,7 @@ impl HookContext {
             #[cfg(any(feature = "hydration", feature = "ssr"))]
             prepared_states: Vec::new(),
             effects: Vec::new(),
+            layout_effects: Vec::new(),
 
             #[cfg(feature = "hydration")]
             prepared_states_data: {
@@ -137,6 +178,9 @@ impl HookContext {
             counter: 0,
             #[cfg(debug_assertions)]
             total_hook_counter: None,
+
+            #[cfg(feature = "debug-hooks")]
+            recorder: debug_hooks::HookRecorder::default(),
         })
     }
 
@@ -176,6 +220,21 @@ impl HookContext {
         t
     }
 
+    pub(crate) fn next_layout_effect<T>(&mut self, initializer: impl FnOnce(ReRender) -> T) -> Rc<T>
+    where
+        T: 'static + Effect,
+    {
+        let prev_state_len = self.states.len();
+        let t = self.next_state(initializer);
+
+        // This is a new layout effect, we add it to layout effects.
+        if self.states.len() != prev_state_len {
+            self.layout_effects.push(t.clone());
+        }
+
+        t
+    }
+
     #[cfg(any(feature = "hydration", feature = "ssr"))]
     pub(crate) fn next_prepared_state<T>(
         &mut self,
@@ -206,6 +265,21 @@ impl HookContext {
         t
     }
 
+    /// Sets the [`Debug`](fmt::Debug) rendering of the value of the hook calling the current
+    /// hook.
+    #[cfg(feature = "debug-hooks")]
+    pub(crate) fn set_debug_value(&mut self, value: String) {
+        self.recorder.set_value(value);
+    }
+
+    /// Makes the hooks recorded during the last render available to
+    /// [`AnyScope::inspect_hooks`].
+    #[cfg(feature = "debug-hooks")]
+    fn publish_hooks(&mut self) {
+        let hooks = self.recorder.take();
+        self.scope.set_hooks(hooks);
+    }
+
     #[inline(always)]
     fn prepare_run(&mut self) {
         #[cfg(feature = "hydration")]
@@ -255,9 +329,16 @@ impl HookContext {
         }
     }
 
+    fn run_layout_effects(&self) {
+        for effect in self.layout_effects.iter() {
+            effect.rendered();
+        }
+    }
+
     fn drain_states(&mut self) {
         // We clear the effects as these are also references to states.
         self.effects.clear();
+        self.layout_effects.clear();
 
         for state in self.states.drain(..) {
             drop(state);
@@ -294,6 +375,97 @@ impl fmt::Debug for HookContext {
     }
 }
 
+/// Runs a hook called by a function component or another hook.
+///
+/// Calls to hooks are rewritten to this function by the
+/// [`#[component]`](crate::functional::component) and [`#[hook]`](crate::functional::hook)
+/// macros. With the `debug-hooks` feature, the hook is recorded with its name and call site, and
+/// with the value rendered by `debug_value` if the hook does not set one itself.
+#[doc(hidden)]
+#[inline]
+#[cfg_attr(feature = "debug-hooks", track_caller)]
+pub fn __run_hook<H, F>(
+    hook: H,
+    ctx: &mut HookContext,
+    kind: &'static str,
+    debug_value: F,
+) -> H::Output
+where
+    H: Hook,
+    F: FnOnce(&H::Output) -> Option<String>,
+{
+    #[cfg(not(feature = "debug-hooks"))]
+    let _ = (kind, debug_value);
+
+    #[cfg(feature = "debug-hooks")]
+    ctx.recorder.enter(kind, std::panic::Location::caller());
+
+    #[allow(clippy::let_and_return)]
+    let output = hook.run(ctx);
+
+    #[cfg(feature = "debug-hooks")]
+    {
+        ctx.recorder.set_default_value(|| debug_value(&output));
+        ctx.recorder.exit();
+    }
+
+    output
+}
+
+#[doc(hidden)]
+mod __macro {
+    use std::fmt;
+
+    use super::{Reducible, UseReducerHandle, UseStateHandle};
+
+    /// The output of a hook, rendered as the value of the hook with the `debug-hooks` feature.
+    ///
+    /// `(&&&HookOutput(output)).debug_value()` resolves to [`HookOutputState`] for the handles of
+    /// state hooks, to [`HookOutputDebug`] for all other outputs implementing [`Debug`] and to
+    /// [`HookOutputOpaque`] otherwise, each of which requires an additional auto-deref.
+    #[derive(Debug)]
+    pub struct HookOutput<'a, T>(pub &'a T);
+
+    pub trait HookOutputState {
+        fn debug_value(&self) -> Option<String>;
+    }
+
+    impl<T: fmt::Debug> HookOutputState for &&HookOutput<'_, UseStateHandle<T>> {
+        fn debug_value(&self) -> Option<String> {
+            Some(format!("{:?}", **self.0))
+        }
+    }
+
+    impl<T: Reducible + fmt::Debug> HookOutputState for &&HookOutput<'_, UseReducerHandle<T>> {
+        fn debug_value(&self) -> Option<String> {
+            Some(format!("{:?}", **self.0))
+        }
+    }
+
+    pub trait HookOutputDebug {
+        fn debug_value(&self) -> Option<String>;
+    }
+
+    impl<T: fmt::Debug> HookOutputDebug for &HookOutput<'_, T> {
+        fn debug_value(&self) -> Option<String> {
+            Some(format!("{:?}", self.0))
+        }
+    }
+
+    pub trait HookOutputOpaque {
+        fn debug_value(&self) -> Option<String>;
+    }
+
+    impl<T> HookOutputOpaque for HookOutput<'_, T> {
+        fn debug_value(&self) -> Option<String> {
+            None
+        }
+    }
+}
+
+#[doc(hidden)]
+pub use __macro::{HookOutput, HookOutputDebug, HookOutputOpaque, HookOutputState};
+
 /// Trait that allows a struct to act as Function Component.
 pub trait FunctionProvider {
     /// Properties for the Function Component.
@@ -344,7 +516,7 @@ where
             hook_ctx: HookContext::new(
                 scope,
                 re_render,
-                #[cfg(all(feature = "hydration", feature = "ssr"))]
+                #[cfg(any(feature = "hydration", feature = "ssr"))]
                 ctx.creation_mode(),
                 #[cfg(feature = "hydration")]
                 ctx.prepared_state(),
@@ -352,6 +524,19 @@ where
         }
     }
 
+    /// Creates a new function component whose `changed` compares the properties itself.
+    ///
+    /// This is used by components declared with `#[component(memo)]`.
+    pub fn new_memo(ctx: &Context<T>) -> Self
+    where
+        T: BaseComponent<Message = ()> + FunctionProvider + 'static,
+    {
+        #[cfg(feature = "csr")]
+        ctx.set_changed_compares_props();
+
+        Self::new(ctx)
+    }
+
     /// Renders a function component.
     pub fn render(&self, props: &T::Properties) -> HtmlResult {
         let mut hook_ctx = self.hook_ctx.borrow_mut();
@@ -364,6 +549,9 @@ where
         #[cfg(debug_assertions)]
         hook_ctx.assert_hook_context(result.is_ok());
 
+        #[cfg(feature = "debug-hooks")]
+        hook_ctx.publish_hooks();
+
         result
     }
 
@@ -373,6 +561,12 @@ where
         hook_ctx.run_effects();
     }
 
+    /// Run Layout Effects of a function component.
+    pub fn layout_rendered(&self) {
+        let hook_ctx = self.hook_ctx.borrow();
+        hook_ctx.run_layout_effects();
+    }
+
     /// Destroys the function component.
     pub fn destroy(&self) {
         let mut hook_ctx = self.hook_ctx.borrow_mut();
@@ -1,21 +1,31 @@
 //! Component lifecycle module
 
 use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
 use std::rc::Rc;
 
 #[cfg(feature = "csr")]
 use web_sys::Element;
 
 use super::BaseComponent;
+#[cfg(feature = "ssr")]
+use super::scope::SsrContent;
 use super::scope::{AnyScope, Scope};
 #[cfg(feature = "hydration")]
 use crate::dom_bundle::Fragment;
 #[cfg(feature = "csr")]
 use crate::dom_bundle::{BSubtree, Bundle, DomSlot, DynamicDomSlot};
-#[cfg(feature = "hydration")]
+use crate::error_boundary::{BaseErrorBoundary, RenderFailure, find_error_boundary};
+#[cfg(any(feature = "hydration", feature = "ssr"))]
 use crate::html::RenderMode;
-use crate::html::{Html, RenderError};
+use crate::html::{Html, PanicError, RenderError};
+#[cfg(feature = "hydration")]
+use crate::hydration::track_mismatches;
+#[cfg(feature = "profiling")]
+use crate::profiler::{self, SpanKind};
 use crate::scheduler::{self, Runnable, Shared};
+#[cfg(feature = "csr")]
+use crate::suspense::Transition;
 use crate::suspense::{BaseSuspense, Suspension};
 use crate::{Callback, Context, HtmlResult};
 
@@ -151,6 +161,8 @@ pub(crate) trait Stateful {
     fn view(&self) -> HtmlResult;
     #[cfg(feature = "csr")]
     fn rendered(&mut self, first_render: bool);
+    #[cfg(feature = "csr")]
+    fn layout_rendered(&mut self);
     fn destroy(&mut self);
 
     fn any_scope(&self) -> AnyScope;
@@ -161,8 +173,13 @@ pub(crate) trait Stateful {
 
     fn as_any(&self) -> &dyn Any;
 
+    fn type_name(&self) -> &'static str;
+
     #[cfg(feature = "hydration")]
     fn creation_mode(&self) -> RenderMode;
+
+    #[cfg(feature = "ssr")]
+    fn ssr_content(&self) -> Option<Rc<dyn SsrContent>>;
 }
 
 impl<COMP> Stateful for CompStateInner<COMP>
@@ -178,6 +195,11 @@ where
         self.component.rendered(&self.context, first_render)
     }
 
+    #[cfg(feature = "csr")]
+    fn layout_rendered(&mut self) {
+        self.component.layout_rendered(&self.context)
+    }
+
     fn destroy(&mut self) {
         self.component.destroy(&self.context);
     }
@@ -208,9 +230,30 @@ where
             _ => return false,
         };
 
-        if self.context.props != props {
+        let compares_props = self.context.changed_compares_props();
+        let changed = if compares_props {
+            !Rc::ptr_eq(&self.context.props, &props)
+        } else {
+            self.context.props != props
+        };
+        if changed {
+            // Only updates with new properties are recorded, not the ones which merely compare
+            // them.
+            #[cfg(feature = "profiling")]
+            let span = profiler::enter(
+                SpanKind::Update,
+                self.context.scope.id,
+                std::any::type_name::<COMP>(),
+            );
             let old_props = std::mem::replace(&mut self.context.props, props);
-            self.component.changed(&self.context, &old_props)
+            let changed = self.component.changed(&self.context, &old_props);
+
+            #[cfg(feature = "profiling")]
+            if compares_props && !changed {
+                span.discard();
+            }
+
+            changed
         } else {
             false
         }
@@ -219,6 +262,15 @@ where
     fn as_any(&self) -> &dyn Any {
         self
     }
+
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<COMP>()
+    }
+
+    #[cfg(feature = "ssr")]
+    fn ssr_content(&self) -> Option<Rc<dyn SsrContent>> {
+        self.context.ssr_content.get().cloned()
+    }
 }
 
 pub(crate) struct ComponentState {
@@ -235,6 +287,9 @@ pub(crate) struct ComponentState {
     pending_props: Option<Rc<dyn Any>>,
 
     suspension: Option<Suspension>,
+    /// The transition the next render of this component belongs to.
+    #[cfg(feature = "csr")]
+    transition: Option<Transition>,
 
     pub(crate) comp_id: usize,
 }
@@ -253,10 +308,12 @@ impl ComponentState {
         #[cfg(feature = "hydration")] prepared_state: Option<String>,
     ) -> Self {
         let comp_id = scope.id;
-        #[cfg(feature = "hydration")]
+        #[cfg(any(feature = "hydration", feature = "ssr"))]
         let creation_mode = {
             match initial_render_state {
+                #[cfg(feature = "csr")]
                 ComponentRenderState::Render { .. } => RenderMode::Render,
+                #[cfg(feature = "hydration")]
                 ComponentRenderState::Hydration { .. } => RenderMode::Hydration,
                 #[cfg(feature = "ssr")]
                 ComponentRenderState::Ssr { .. } => RenderMode::Ssr,
@@ -266,10 +323,14 @@ impl ComponentState {
         let context = Context {
             scope,
             props,
-            #[cfg(feature = "hydration")]
+            #[cfg(any(feature = "hydration", feature = "ssr"))]
             creation_mode,
             #[cfg(feature = "hydration")]
             prepared_state,
+            #[cfg(feature = "csr")]
+            changed_compares_props: Default::default(),
+            #[cfg(feature = "ssr")]
+            ssr_content: Default::default(),
         };
 
         let inner = Box::new(CompStateInner {
@@ -281,6 +342,8 @@ impl ComponentState {
             inner,
             render_state: initial_render_state,
             suspension: None,
+            #[cfg(feature = "csr")]
+            transition: None,
 
             #[cfg(feature = "csr")]
             has_rendered: false,
@@ -309,6 +372,68 @@ impl ComponentState {
             BaseSuspense::resume(&suspense_scope, m);
         }
     }
+
+    /// Forwards a panic raised by a lifecycle method to the closest error boundary.
+    ///
+    /// The panic is resumed if there is no boundary to handle it.
+    fn handle_panic(&mut self, payload: Box<dyn Any + Send>) {
+        let Some(boundary_scope) = find_error_boundary(&self.inner.any_scope()) else {
+            panic::resume_unwind(payload);
+        };
+
+        self.fail(
+            &boundary_scope,
+            Rc::new(PanicError::from_payload(&*payload)),
+        );
+    }
+
+    /// Forwards an error returned by `view` to the closest error boundary.
+    fn handle_error(&mut self, error: Rc<dyn std::error::Error>) {
+        let Some(boundary_scope) = find_error_boundary(&self.inner.any_scope()) else {
+            panic!(
+                "{} failed to render: {error}. To handle errors, an <ErrorBoundary /> component \
+                 is required.",
+                self.inner.type_name()
+            );
+        };
+
+        self.fail(&boundary_scope, error);
+    }
+
+    fn fail(
+        &mut self,
+        boundary_scope: &Scope<BaseErrorBoundary>,
+        error: Rc<dyn std::error::Error>,
+    ) {
+        tracing::debug!(
+            component.id = self.comp_id,
+            "forwarding failure to error boundary"
+        );
+
+        // The output of a server-side rendered boundary is buffered until all of its children have
+        // finished rendering, the content of this component is discarded in favour of the
+        // fallback.
+        match self.render_state {
+            #[cfg(feature = "csr")]
+            ComponentRenderState::Render { .. } => {}
+            #[cfg(feature = "hydration")]
+            ComponentRenderState::Hydration { .. } => {}
+            #[cfg(feature = "ssr")]
+            ComponentRenderState::Ssr { ref mut sender } => {
+                if let Some(tx) = sender.take() {
+                    let _ = tx.send(Html::default());
+                }
+            }
+        }
+
+        BaseErrorBoundary::fail(
+            boundary_scope,
+            RenderFailure {
+                error,
+                component: self.inner.type_name(),
+            },
+        );
+    }
 }
 
 pub(crate) struct CreateRunner<COMP: BaseComponent> {
@@ -323,6 +448,12 @@ impl<COMP: BaseComponent> Runnable for CreateRunner<COMP> {
     fn run(self: Box<Self>) {
         let mut current_state = self.scope.state.borrow_mut();
         if current_state.is_none() {
+            #[cfg(feature = "profiling")]
+            let _span = profiler::enter(
+                SpanKind::Create,
+                self.scope.id,
+                std::any::type_name::<COMP>(),
+            );
             *current_state = Some(ComponentState::new(
                 self.initial_render_state,
                 self.scope.clone(),
@@ -336,6 +467,8 @@ impl<COMP: BaseComponent> Runnable for CreateRunner<COMP> {
 
 pub(crate) struct UpdateRunner {
     pub state: Shared<Option<ComponentState>>,
+    #[cfg(feature = "csr")]
+    pub transition: Option<Transition>,
 }
 
 impl ComponentState {
@@ -345,7 +478,14 @@ impl ComponentState {
         fields(component.id = self.comp_id)
     )]
     fn update(&mut self) -> bool {
-        let schedule_render = self.inner.flush_messages();
+        let schedule_render =
+            match panic::catch_unwind(AssertUnwindSafe(|| self.inner.flush_messages())) {
+                Ok(m) => m,
+                Err(payload) => {
+                    self.handle_panic(payload);
+                    false
+                }
+            };
         tracing::trace!(schedule_render);
         schedule_render
     }
@@ -354,9 +494,17 @@ impl ComponentState {
 impl Runnable for UpdateRunner {
     fn run(self: Box<Self>) {
         if let Some(state) = self.state.borrow_mut().as_mut() {
-            let schedule_render = state.update();
+            let schedule_render = {
+                #[cfg(feature = "profiling")]
+                let _span =
+                    profiler::enter(SpanKind::Update, state.comp_id, state.inner.type_name());
+                state.update()
+            };
 
             if schedule_render {
+                #[cfg(feature = "csr")]
+                state.join_transition(self.transition);
+
                 scheduler::push_component_render(
                     state.comp_id,
                     Box::new(RenderRunner {
@@ -416,6 +564,8 @@ impl ComponentState {
 impl Runnable for DestroyRunner {
     fn run(self: Box<Self>) {
         if let Some(state) = self.state.borrow_mut().take() {
+            #[cfg(feature = "profiling")]
+            let _span = profiler::enter(SpanKind::Destroy, state.comp_id, state.inner.type_name());
             state.destroy(self.parent_to_detach);
         }
     }
@@ -432,19 +582,52 @@ impl ComponentState {
         fields(component.id = self.comp_id)
     )]
     fn render(&mut self, shared_state: &Shared<Option<ComponentState>>) {
-        let view = self.inner.view();
+        // Work scheduled while rendering belongs to the same transition.
+        #[cfg(feature = "csr")]
+        let transition = self.transition.take();
+        #[cfg(feature = "csr")]
+        let _guard = transition.as_ref().map(Transition::enter);
+
+        let view = {
+            #[cfg(feature = "profiling")]
+            let _span = profiler::enter(SpanKind::View, self.comp_id, self.inner.type_name());
+            panic::catch_unwind(AssertUnwindSafe(|| {
+                scheduler::rendering(|| self.inner.view())
+            }))
+        };
+        let view = match view {
+            Ok(m) => m,
+            // Nothing is committed, the previous render stays in place until the boundary
+            // replaces it.
+            Err(payload) => return self.handle_panic(payload),
+        };
         tracing::trace!(?view, "render result");
         match view {
             Ok(vnode) => self.commit_render(shared_state, vnode),
-            Err(RenderError::Suspended(susp)) => self.suspend(shared_state, susp),
+            Err(RenderError::Suspended(susp)) => self.suspend(
+                shared_state,
+                susp,
+                #[cfg(feature = "csr")]
+                transition,
+            ),
+            // Same as a panic, the previous render stays in place.
+            Err(RenderError::Failed(e)) => self.handle_error(e),
         };
     }
 
-    fn suspend(&mut self, shared_state: &Shared<Option<ComponentState>>, suspension: Suspension) {
+    fn suspend(
+        &mut self,
+        shared_state: &Shared<Option<ComponentState>>,
+        suspension: Suspension,
+        #[cfg(feature = "csr")] transition: Option<Transition>,
+    ) {
         // Currently suspended, we re-use previous root node and send
         // suspension to parent element.
 
         if suspension.resumed() {
+            #[cfg(feature = "csr")]
+            self.join_transition(transition);
+
             // schedule a render immediately if suspension is resumed.
             scheduler::push_component_render(
                 self.comp_id,
@@ -460,9 +643,25 @@ impl ComponentState {
                 .find_parent_scope::<BaseSuspense>()
                 .expect("To suspend rendering, a <Suspense /> component is required.");
 
+            // Only components that have been rendered before have content to keep showing.
+            #[cfg(feature = "csr")]
+            let in_transition = transition.is_some() && self.has_rendered;
+            // The transition is pending until the render after the suspension has been committed.
+            #[cfg(feature = "csr")]
+            let transition = std::cell::Cell::new(transition);
+
             let comp_id = self.comp_id;
             let shared_state = shared_state.clone();
             suspension.listen(Callback::from(move |_| {
+                #[cfg(feature = "csr")]
+                if let Some(m) = transition.take() {
+                    if let Ok(mut state) = shared_state.try_borrow_mut() {
+                        if let Some(state) = state.as_mut() {
+                            state.join_transition(Some(m));
+                        }
+                    }
+                }
+
                 scheduler::push_component_render(
                     comp_id,
                     Box::new(RenderRunner {
@@ -480,6 +679,12 @@ impl ComponentState {
             }
             self.suspension = Some(suspension.clone());
 
+            #[cfg(feature = "csr")]
+            if in_transition {
+                BaseSuspense::suspend_in_transition(&suspense_scope, suspension);
+                return;
+            }
+
             BaseSuspense::suspend(&suspense_scope, suspension);
         }
     }
@@ -503,8 +708,12 @@ impl ComponentState {
             } => {
                 let scope = self.inner.any_scope();
 
-                let new_node_ref =
-                    bundle.reconcile(root, &scope, parent, sibling_slot.to_position(), new_vdom);
+                let new_node_ref = {
+                    #[cfg(feature = "profiling")]
+                    let _span =
+                        profiler::enter(SpanKind::Reconcile, self.comp_id, self.inner.type_name());
+                    bundle.reconcile(root, &scope, parent, sibling_slot.to_position(), new_vdom)
+                };
                 own_slot.reassign(new_node_ref);
 
                 let first_render = !self.has_rendered;
@@ -523,6 +732,12 @@ impl ComponentState {
                         .expect("a resuming component must have a Suspense ancestor");
                     BaseSuspense::defer_rendered(&suspense_scope, self.comp_id, pending);
                 } else {
+                    scheduler::push_component_layout(
+                        self.comp_id,
+                        Box::new(LayoutRunner {
+                            state: shared_state.clone(),
+                        }),
+                    );
                     scheduler::push_component_rendered(
                         self.comp_id,
                         Box::new(RenderedRunner {
@@ -553,18 +768,38 @@ impl ComponentState {
                 );
 
                 let scope = self.inner.any_scope();
-                let bundle = Bundle::hydrate(
-                    root,
-                    &scope,
-                    parent,
-                    fragment,
-                    new_vdom,
-                    &mut Some(own_slot.clone()),
-                );
-
-                // We trim all text nodes before checking as it's likely these are whitespaces.
-                fragment.trim_start_text_nodes();
-                assert!(fragment.is_empty(), "expected end of component, found node");
+                #[cfg(feature = "profiling")]
+                let _span =
+                    profiler::enter(SpanKind::Reconcile, self.comp_id, self.inner.type_name());
+                let (mut bundle, mismatched) = track_mismatches(&scope, || {
+                    let bundle = Bundle::hydrate(
+                        root,
+                        &scope,
+                        parent,
+                        fragment,
+                        new_vdom.clone(),
+                        &mut Some(own_slot.clone()),
+                    );
+                    fragment.finish(root, parent, "end of component");
+
+                    bundle
+                });
+
+                // The server-side rendered content does not match, so it is discarded and the
+                // component is rendered on the client side instead.
+                if mismatched {
+                    bundle.detach(root, parent, false);
+
+                    bundle = Bundle::new();
+                    let new_node_ref = bundle.reconcile(
+                        root,
+                        &scope,
+                        parent,
+                        sibling_slot.to_position(),
+                        new_vdom,
+                    );
+                    own_slot.reassign(new_node_ref);
+                }
 
                 self.render_state = ComponentRenderState::Render {
                     root: root.clone(),
@@ -606,6 +841,18 @@ mod feat_csr {
         pub state: Shared<Option<ComponentState>>,
         pub props: Option<Rc<dyn Any>>,
         pub next_sibling_slot: Option<DomSlot>,
+        pub transition: Option<Transition>,
+    }
+
+    impl ComponentState {
+        /// Marks the next render of this component as part of `transition`.
+        ///
+        /// A transition which is already attached is kept if `transition` is `None`.
+        pub(super) fn join_transition(&mut self, transition: Option<Transition>) {
+            if transition.is_some() {
+                self.transition = transition;
+            }
+        }
     }
 
     impl ComponentState {
@@ -694,12 +941,15 @@ mod feat_csr {
                 next_sibling_slot,
                 props,
                 state: shared_state,
+                transition,
             } = *self;
 
             if let Some(state) = shared_state.borrow_mut().as_mut() {
                 let schedule_render = state.changed(props, next_sibling_slot);
 
                 if schedule_render {
+                    state.join_transition(transition);
+
                     scheduler::push_component_render(
                         state.comp_id,
                         Box::new(RenderRunner {
@@ -717,6 +967,10 @@ mod feat_csr {
         pub first_render: bool,
     }
 
+    pub(crate) struct LayoutRunner {
+        pub state: Shared<Option<ComponentState>>,
+    }
+
     /// A `rendered` lifecycle deferred by the ancestor `<Suspense>` so it fires
     /// only after Suspense un-suspends and children's DOM has been shifted into
     /// the live tree. See `BaseSuspense::defer_rendered`.
@@ -741,12 +995,18 @@ mod feat_csr {
             self.first_render |= later.first_render;
         }
 
-        /// Push this onto the scheduler's `rendered` queue.
+        /// Push this onto the scheduler's `layout` and `rendered` queues.
         pub(crate) fn schedule(self, comp_id: usize) {
             let PendingRendered {
                 state,
                 first_render,
             } = self;
+            scheduler::push_component_layout(
+                comp_id,
+                Box::new(LayoutRunner {
+                    state: state.clone(),
+                }),
+            );
             scheduler::push_component_rendered(
                 comp_id,
                 Box::new(RenderedRunner {
@@ -766,7 +1026,11 @@ mod feat_csr {
         )]
         fn rendered(&mut self, first_render: bool) -> bool {
             if self.suspension.is_none() {
-                self.inner.rendered(first_render);
+                let result =
+                    panic::catch_unwind(AssertUnwindSafe(|| self.inner.rendered(first_render)));
+                if let Err(payload) = result {
+                    self.handle_panic(payload);
+                }
             }
 
             #[cfg(feature = "hydration")]
@@ -780,16 +1044,46 @@ mod feat_csr {
         }
     }
 
+    impl ComponentState {
+        #[tracing::instrument(
+            level = tracing::Level::DEBUG,
+            skip(self),
+            fields(component.id = self.comp_id)
+        )]
+        fn layout_rendered(&mut self) {
+            if self.suspension.is_none() {
+                let result = panic::catch_unwind(AssertUnwindSafe(|| self.inner.layout_rendered()));
+                if let Err(payload) = result {
+                    self.handle_panic(payload);
+                }
+            }
+        }
+    }
+
+    impl Runnable for LayoutRunner {
+        fn run(self: Box<Self>) {
+            if let Some(state) = self.state.borrow_mut().as_mut() {
+                state.layout_rendered();
+            }
+        }
+    }
+
     impl Runnable for RenderedRunner {
         fn run(self: Box<Self>) {
             if let Some(state) = self.state.borrow_mut().as_mut() {
-                let has_pending_props = state.rendered(self.first_render);
+                let has_pending_props = {
+                    #[cfg(feature = "profiling")]
+                    let _span =
+                        profiler::enter(SpanKind::Rendered, state.comp_id, state.inner.type_name());
+                    state.rendered(self.first_render)
+                };
 
                 if has_pending_props {
                     scheduler::push_component_props_update(Box::new(PropsUpdateRunner {
                         state: self.state.clone(),
                         props: None,
                         next_sibling_slot: None,
+                        transition: None,
                     }));
                 }
             }
@@ -7,6 +7,10 @@ mod marker;
 mod properties;
 mod scope;
 
+#[cfg(feature = "csr")]
+use std::cell::Cell;
+#[cfg(feature = "ssr")]
+use std::cell::OnceCell;
 use std::rc::Rc;
 
 pub use children::*;
@@ -17,13 +21,17 @@ pub use properties::*;
 #[cfg(feature = "csr")]
 pub(crate) use scope::Scoped;
 pub use scope::{AnyScope, Scope, SendAsMessage};
+#[cfg(feature = "ssr")]
+pub(crate) use scope::{SsrAlive, SsrContent, write_prepared_state};
 
 use super::{Html, HtmlResult, IntoHtmlResult};
 
-#[cfg(feature = "hydration")]
+#[cfg(any(feature = "hydration", feature = "ssr"))]
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub(crate) enum RenderMode {
+    #[cfg(feature = "hydration")]
     Hydration,
+    #[cfg(feature = "csr")]
     Render,
     #[cfg(feature = "ssr")]
     Ssr,
@@ -35,11 +43,17 @@ pub(crate) enum RenderMode {
 pub struct Context<COMP: BaseComponent> {
     scope: Scope<COMP>,
     props: Rc<COMP::Properties>,
-    #[cfg(feature = "hydration")]
+    #[cfg(any(feature = "hydration", feature = "ssr"))]
     creation_mode: RenderMode,
 
     #[cfg(feature = "hydration")]
     prepared_state: Option<String>,
+
+    #[cfg(feature = "csr")]
+    changed_compares_props: Cell<bool>,
+
+    #[cfg(feature = "ssr")]
+    ssr_content: OnceCell<Rc<dyn SsrContent>>,
 }
 
 impl<COMP: BaseComponent> Context<COMP> {
@@ -55,11 +69,32 @@ impl<COMP: BaseComponent> Context<COMP> {
         &self.props
     }
 
-    #[cfg(feature = "hydration")]
+    #[cfg(any(feature = "hydration", feature = "ssr"))]
     pub(crate) fn creation_mode(&self) -> RenderMode {
         self.creation_mode
     }
 
+    /// Makes [`BaseComponent::changed`] compare the new properties with the old ones itself.
+    ///
+    /// By default, `changed` is only called if the new properties are not equal to the old ones
+    /// according to [`PartialEq`]. Afterwards, it is called whenever the component receives new
+    /// properties.
+    #[cfg(feature = "csr")]
+    pub(crate) fn set_changed_compares_props(&self) {
+        self.changed_compares_props.set(true);
+    }
+
+    #[cfg(feature = "csr")]
+    pub(crate) fn changed_compares_props(&self) -> bool {
+        self.changed_compares_props.get()
+    }
+
+    /// Renders the content of the component with `content` during server-side rendering.
+    #[cfg(feature = "ssr")]
+    pub(crate) fn set_ssr_content(&self, content: impl SsrContent + 'static) {
+        let _ = self.ssr_content.set(Rc::new(content));
+    }
+
     /// The component's prepared state
     pub fn prepared_state(&self) -> Option<&str> {
         #[cfg(not(feature = "hydration"))]
@@ -110,6 +145,13 @@ pub trait BaseComponent: Sized + 'static {
     /// Notified after a layout is rendered.
     fn rendered(&mut self, ctx: &Context<Self>, first_render: bool);
 
+    /// Notified right after a layout is rendered, before [`rendered`](Self::rendered) and
+    /// before the browser paints.
+    ///
+    /// This is used by [`use_layout_effect`](crate::functional::use_layout_effect) and does
+    /// nothing by default.
+    fn layout_rendered(&mut self, _ctx: &Context<Self>) {}
+
     /// Notified before a component is destroyed.
     fn destroy(&mut self, ctx: &Context<Self>);
 
@@ -257,8 +299,14 @@ mod tests {
             props: Rc::new(()),
             #[cfg(feature = "hydration")]
             creation_mode: crate::html::RenderMode::Hydration,
+            #[cfg(all(feature = "ssr", not(feature = "hydration")))]
+            creation_mode: crate::html::RenderMode::Ssr,
             #[cfg(feature = "hydration")]
             prepared_state: None,
+            #[cfg(feature = "csr")]
+            changed_compares_props: Default::default(),
+            #[cfg(feature = "ssr")]
+            ssr_content: Default::default(),
         };
         assert!(Component::update(&mut comp, &ctx, ()));
         assert!(Component::changed(&mut comp, &ctx, &Rc::new(())));
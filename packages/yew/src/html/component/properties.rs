@@ -11,8 +11,37 @@ pub trait Properties: PartialEq {
     fn builder() -> Self::Builder;
 }
 
+/// Properties which can be compared shallowly.
+///
+/// This is derived by [`Properties`](derive@Properties) with the `#[shallow_eq]` attribute, which
+/// requires every field which is not an [`Rc`](std::rc::Rc) to implement [`PartialEq`], and is
+/// required by function components declared with `#[component(memo(shallow))]`:
+///
+/// ```
+/// # use std::rc::Rc;
+/// # use yew::prelude::*;
+/// #[derive(Properties, PartialEq)]
+/// #[shallow_eq]
+/// struct TableProps {
+///     title: AttrValue,
+///     rows: Rc<Vec<String>>,
+/// }
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be compared shallowly",
+    label = "`{Self}` does not implement `ShallowEq`",
+    note = "add `#[shallow_eq]` to the `#[derive(Properties)]` of `{Self}`"
+)]
+pub trait ShallowEq: Properties {
+    /// Compares the properties like [`PartialEq`], except for [`Rc`](std::rc::Rc) fields which
+    /// are compared by pointer.
+    fn shallow_eq(&self, other: &Self) -> bool;
+}
+
 #[doc(hidden)]
 mod __macro {
+    use std::rc::Rc;
+
     /// A marker trait to ensure that the builder has received a specific required prop.
     /// For each required impl in a property, we generate:
     /// - a struct with the name of the prop, which takes the place of `P`.
@@ -125,7 +154,61 @@ mod __macro {
     }
 
     impl<T> HasAllProps<(), T> for T {}
+
+    /// A field of two properties compared by [`ShallowEq::shallow_eq`](super::ShallowEq).
+    ///
+    /// `(&ShallowEqField(a, b)).shallow_eq()` resolves to [`ShallowEqPtr`] for [`Rc`] fields and to
+    /// [`ShallowEqValue`] for all other fields, which require an additional auto-ref.
+    #[derive(Debug)]
+    pub struct ShallowEqField<'a, T>(pub &'a T, pub &'a T);
+
+    pub trait ShallowEqPtr {
+        fn shallow_eq(&self) -> bool;
+    }
+
+    impl<T: ?Sized> ShallowEqPtr for ShallowEqField<'_, Rc<T>> {
+        fn shallow_eq(&self) -> bool {
+            Rc::ptr_eq(self.0, self.1)
+        }
+    }
+
+    pub trait ShallowEqValue {
+        fn shallow_eq(&self) -> bool;
+    }
+
+    impl<T: PartialEq> ShallowEqValue for &ShallowEqField<'_, T> {
+        fn shallow_eq(&self) -> bool {
+            self.0 == self.1
+        }
+    }
 }
 
 #[doc(hidden)]
-pub use __macro::{AllPropsFor, AssertAllProps, Buildable, HasAllProps, HasProp};
+pub use __macro::{
+    AllPropsFor, AssertAllProps, Buildable, HasAllProps, HasProp, ShallowEqField, ShallowEqPtr,
+    ShallowEqValue,
+};
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::html::{Properties, ShallowEq};
+
+    #[derive(Properties, PartialEq)]
+    #[shallow_eq]
+    struct Props {
+        id: u32,
+        rows: Rc<Vec<u32>>,
+    }
+
+    #[test]
+    fn shallow_eq_compares_rc_fields_by_pointer() {
+        let rows = Rc::new(vec![1, 2]);
+        let props = |id, rows| Props { id, rows };
+
+        assert!(props(1, rows.clone()).shallow_eq(&props(1, rows.clone())));
+        assert!(!props(1, rows.clone()).shallow_eq(&props(2, rows.clone())));
+        assert!(!props(1, rows).shallow_eq(&props(1, Rc::new(vec![1, 2]))));
+    }
+}
@@ -1,6 +1,9 @@
 //! Component scope module
 
 use std::any::{Any, TypeId};
+#[cfg(any(feature = "csr", feature = "ssr"))]
+use std::cell::Cell;
+use std::cell::RefCell;
 use std::marker::PhantomData;
 use std::ops::Deref;
 use std::rc::Rc;
@@ -13,6 +16,8 @@ use super::BaseComponent;
 use super::lifecycle::ComponentState;
 use crate::callback::Callback;
 use crate::context::{ContextHandle, ContextProvider};
+#[cfg(feature = "debug-hooks")]
+use crate::functional::HookInfo;
 use crate::platform::spawn_local;
 #[cfg(any(feature = "csr", feature = "ssr"))]
 use crate::scheduler::Shared;
@@ -21,10 +26,43 @@ use crate::scheduler::Shared;
 #[derive(Clone)]
 pub struct AnyScope {
     type_id: TypeId,
+    #[cfg(feature = "hydration_diagnostics")]
+    type_name: &'static str,
     parent: Option<Rc<AnyScope>>,
+    #[cfg(any(feature = "csr", feature = "ssr"))]
+    position: Rc<ScopePosition>,
+    #[cfg(feature = "debug-hooks")]
+    hooks: Rc<RefCell<Vec<HookInfo>>>,
     typed_scope: Rc<dyn Any>,
 }
 
+/// The position of a component in the component tree.
+///
+/// Children are numbered in the order they are created, which is the order they appear in the
+/// document for both server-side rendering and hydration.
+#[cfg(any(feature = "csr", feature = "ssr"))]
+#[derive(Debug, Default)]
+pub(crate) struct ScopePosition {
+    index: usize,
+    children: Cell<usize>,
+    /// Prefix of the ids created by [`use_id`](crate::functional::use_id), only set for roots.
+    id_prefix: Option<Rc<str>>,
+}
+
+#[cfg(any(feature = "csr", feature = "ssr"))]
+impl ScopePosition {
+    fn child_of(parent: Option<&AnyScope>) -> Self {
+        let index = parent
+            .map(|m| m.position.children.replace(m.position.children.get() + 1))
+            .unwrap_or_default();
+
+        Self {
+            index,
+            ..Default::default()
+        }
+    }
+}
+
 impl fmt::Debug for AnyScope {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("AnyScope").finish_non_exhaustive()
@@ -35,7 +73,13 @@ impl<COMP: BaseComponent> From<Scope<COMP>> for AnyScope {
     fn from(scope: Scope<COMP>) -> Self {
         AnyScope {
             type_id: TypeId::of::<COMP>(),
+            #[cfg(feature = "hydration_diagnostics")]
+            type_name: std::any::type_name::<COMP>(),
             parent: scope.parent.clone(),
+            #[cfg(any(feature = "csr", feature = "ssr"))]
+            position: scope.position.clone(),
+            #[cfg(feature = "debug-hooks")]
+            hooks: scope.hooks.clone(),
             typed_scope: Rc::new(scope),
         }
     }
@@ -52,6 +96,42 @@ impl AnyScope {
         &self.type_id
     }
 
+    /// Returns the type name of the linked component
+    #[cfg(feature = "hydration_diagnostics")]
+    pub(crate) fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Returns an id derived from the position of the linked component in the component tree.
+    ///
+    /// The id is unique within the application and identical on the server and the client.
+    #[cfg(any(feature = "csr", feature = "ssr"))]
+    pub(crate) fn position_id(&self) -> String {
+        let mut path = iter::successors(Some(self), |m| m.get_parent()).collect::<Vec<_>>();
+        path.reverse();
+
+        let prefix = path[0].position.id_prefix.as_deref().unwrap_or("yew-");
+        let indices = path
+            .iter()
+            .map(|m| m.position.index.to_string())
+            .collect::<Vec<_>>();
+
+        format!("{prefix}{}", indices.join("-"))
+    }
+
+    /// Returns the hooks called by the linked component during its last render.
+    ///
+    /// Only function components call hooks, the hooks of other components are empty.
+    #[cfg(feature = "debug-hooks")]
+    pub fn inspect_hooks(&self) -> Vec<HookInfo> {
+        self.hooks.borrow().clone()
+    }
+
+    #[cfg(feature = "debug-hooks")]
+    pub(crate) fn set_hooks(&self, hooks: Vec<HookInfo>) {
+        *self.hooks.borrow_mut() = hooks;
+    }
+
     /// Attempts to downcast into a typed scope
     ///
     /// # Panics
@@ -87,12 +167,52 @@ impl AnyScope {
         let component = scope.get_component()?;
         Some(component.subscribe_consumer(callback, scope_clone))
     }
+
+    /// Accesses a slice of a value provided by a parent `ContextProvider` component of the
+    /// same type.
+    ///
+    /// The callback is only notified when the slice returned by `selector` changes.
+    pub fn context_selector<T, U, F>(
+        &self,
+        selector: F,
+        callback: Callback<U>,
+    ) -> Option<(U, ContextHandle<T>)>
+    where
+        T: Clone + PartialEq + 'static,
+        U: Clone + PartialEq + 'static,
+        F: Fn(&T) -> U + 'static,
+    {
+        let selector = Rc::new(selector);
+        let selected: Rc<RefCell<Option<U>>> = Rc::default();
+
+        let (value, handle) = {
+            let selector = selector.clone();
+            let selected = selected.clone();
+
+            self.context(Callback::from(move |m: T| {
+                let next = selector(&m);
+                if selected.borrow().as_ref() != Some(&next) {
+                    *selected.borrow_mut() = Some(next.clone());
+                    callback.emit(next);
+                }
+            }))
+        }?;
+
+        let value = selector(&value);
+        *selected.borrow_mut() = Some(value.clone());
+
+        Some((value, handle))
+    }
 }
 
 /// A context which allows sending messages to a component.
 pub struct Scope<COMP: BaseComponent> {
     _marker: PhantomData<COMP>,
     parent: Option<Rc<AnyScope>>,
+    #[cfg(any(feature = "csr", feature = "ssr"))]
+    position: Rc<ScopePosition>,
+    #[cfg(feature = "debug-hooks")]
+    hooks: Rc<RefCell<Vec<HookInfo>>>,
 
     #[cfg(any(feature = "csr", feature = "ssr"))]
     pub(crate) pending_messages: MsgQueue<COMP::Message>,
@@ -117,6 +237,10 @@ impl<COMP: BaseComponent> Clone for Scope<COMP> {
             #[cfg(any(feature = "csr", feature = "ssr"))]
             pending_messages: self.pending_messages.clone(),
             parent: self.parent.clone(),
+            #[cfg(any(feature = "csr", feature = "ssr"))]
+            position: self.position.clone(),
+            #[cfg(feature = "debug-hooks")]
+            hooks: self.hooks.clone(),
 
             #[cfg(any(feature = "csr", feature = "ssr"))]
             state: self.state.clone(),
@@ -183,6 +307,23 @@ impl<COMP: BaseComponent> Scope<COMP> {
         AnyScope::from(self.clone()).context(callback)
     }
 
+    /// Accesses a slice of a value provided by a parent `ContextProvider` component of the
+    /// same type.
+    ///
+    /// The callback is only notified when the slice returned by `selector` changes.
+    pub fn context_selector<T, U, F>(
+        &self,
+        selector: F,
+        callback: Callback<U>,
+    ) -> Option<(U, ContextHandle<T>)>
+    where
+        T: Clone + PartialEq + 'static,
+        U: Clone + PartialEq + 'static,
+        F: Fn(&T) -> U + 'static,
+    {
+        AnyScope::from(self.clone()).context_selector(selector, callback)
+    }
+
     /// This method asynchronously awaits a [Future] that returns a message and sends it
     /// to the linked component.
     ///
@@ -291,8 +432,11 @@ impl<COMP: BaseComponent> Scope<COMP> {
 mod feat_ssr {
     use std::fmt::Write;
 
+    use futures::future::LocalBoxFuture;
+
     use super::*;
     use crate::feat_ssr::VTagKind;
+    use crate::html::Html;
     use crate::html::component::lifecycle::{
         ComponentRenderState, CreateRunner, DestroyRunner, RenderRunner,
     };
@@ -301,6 +445,47 @@ mod feat_ssr {
     use crate::scheduler;
     use crate::virtual_dom::Collectable;
 
+    /// Renders the content of a component during server-side rendering, in place of streaming it
+    /// as is.
+    ///
+    /// Components register it in `create` with
+    /// [`Context::set_ssr_content`](crate::html::Context::set_ssr_content).
+    pub(crate) trait SsrContent: std::fmt::Debug {
+        /// Renders `html`, the output of the first render of the component, into `w`.
+        ///
+        /// The component is destroyed once the last clone of `alive` is dropped, content streamed
+        /// after the shell of an out-of-order render can hold on to it.
+        fn render_into_stream<'a>(
+            &'a self,
+            w: &'a mut BufWriter,
+            html: Html,
+            hydratable: bool,
+            parent_vtag_kind: VTagKind,
+            alive: &'a Rc<SsrAlive>,
+        ) -> LocalBoxFuture<'a, ()>;
+    }
+
+    /// Keeps a component rendered on the server side alive, the component is destroyed when it
+    /// is dropped.
+    pub(crate) struct SsrAlive(Shared<Option<ComponentState>>);
+
+    impl Drop for SsrAlive {
+        fn drop(&mut self) {
+            scheduler::push_component_destroy(Box::new(DestroyRunner {
+                state: self.0.clone(),
+                parent_to_detach: false,
+            }));
+            scheduler::start();
+        }
+    }
+
+    /// Writes the prepared state of a component rendered on the server side.
+    pub(crate) fn write_prepared_state(w: &mut BufWriter, prepared_state: &str) {
+        let _ = w.write_str(r#"<script type="application/x-yew-comp-state">"#);
+        let _ = w.write_str(prepared_state);
+        let _ = w.write_str(r#"</script>"#);
+    }
+
     impl<COMP: BaseComponent> Scope<COMP> {
         pub(crate) async fn render_into_stream(
             &self,
@@ -320,7 +505,7 @@ mod feat_ssr {
                 self.id,
                 Box::new(CreateRunner {
                     initial_render_state: state,
-                    props,
+                    props: props.clone(),
                     scope: self.clone(),
                     #[cfg(feature = "hydration")]
                     prepared_state: None,
@@ -338,30 +523,40 @@ mod feat_ssr {
             }
 
             let html = rx.await.unwrap();
-
-            let self_any_scope = AnyScope::from(self.clone());
-            html.render_into_stream(w, &self_any_scope, hydratable, parent_vtag_kind)
-                .await;
+            let alive = Rc::new(SsrAlive(self.state.clone()));
+
+            let content = self
+                .state
+                .borrow()
+                .as_ref()
+                .and_then(|state| state.inner.ssr_content());
+
+            match content {
+                Some(m) => {
+                    m.render_into_stream(w, html, hydratable, parent_vtag_kind, &alive)
+                        .await;
+                }
+                None => {
+                    let self_any_scope = AnyScope::from(self.clone());
+                    html.render_into_stream(w, &self_any_scope, hydratable, parent_vtag_kind)
+                        .await;
+                }
+            }
 
             if let Some(prepared_state) = self.get_component().unwrap().prepare_state() {
-                let _ = w.write_str(r#"<script type="application/x-yew-comp-state">"#);
-                let _ = w.write_str(&prepared_state);
-                let _ = w.write_str(r#"</script>"#);
+                write_prepared_state(w, &prepared_state);
             }
 
             if hydratable {
                 collectable.write_close_tag(w);
             }
-
-            scheduler::push_component_destroy(Box::new(DestroyRunner {
-                state: self.state.clone(),
-                parent_to_detach: false,
-            }));
-            scheduler::start();
         }
     }
 }
 
+#[cfg(feature = "ssr")]
+pub(crate) use feat_ssr::{SsrAlive, SsrContent, write_prepared_state};
+
 #[cfg(not(any(feature = "ssr", feature = "csr")))]
 mod feat_no_csr_ssr {
     use super::*;
@@ -380,6 +575,12 @@ mod feat_no_csr_ssr {
 
         pub(super) fn arch_send_message_batch(&self, _messages: Vec<COMP::Message>) {}
     }
+
+    impl AnyScope {
+        pub(crate) fn position_id(&self) -> String {
+            String::new()
+        }
+    }
 }
 
 #[cfg(any(feature = "ssr", feature = "csr"))]
@@ -390,6 +591,8 @@ mod feat_csr_ssr {
     use super::*;
     use crate::html::component::lifecycle::UpdateRunner;
     use crate::scheduler::{self, Shared};
+    #[cfg(feature = "csr")]
+    use crate::suspense::Transition;
 
     #[derive(Debug)]
     pub(crate) struct MsgQueue<Msg>(Shared<Vec<Msg>>);
@@ -434,6 +637,7 @@ mod feat_csr_ssr {
     impl<COMP: BaseComponent> Scope<COMP> {
         /// Crate a scope with an optional parent scope
         pub(crate) fn new(parent: Option<AnyScope>) -> Self {
+            let position = Rc::new(ScopePosition::child_of(parent.as_ref()));
             let parent = parent.map(Rc::new);
 
             let state = Rc::new(RefCell::new(None));
@@ -447,11 +651,26 @@ mod feat_csr_ssr {
 
                 state,
                 parent,
+                position,
+                #[cfg(feature = "debug-hooks")]
+                hooks: Rc::default(),
 
                 id: COMP_ID_COUNTER.fetch_add(1, Ordering::SeqCst),
             }
         }
 
+        /// Sets the prefix of the ids created by [`use_id`](crate::functional::use_id) for the
+        /// application rooted at this scope.
+        pub(crate) fn with_id_prefix(mut self, id_prefix: Option<Rc<str>>) -> Self {
+            debug_assert!(self.parent.is_none(), "only roots can have an id prefix");
+
+            self.position = Rc::new(ScopePosition {
+                id_prefix,
+                ..Default::default()
+            });
+            self
+        }
+
         #[inline]
         pub(super) fn arch_get_component(&self) -> Option<impl Deref<Target = COMP> + '_> {
             self.state.try_borrow().ok().and_then(|state_ref| {
@@ -466,6 +685,8 @@ mod feat_csr_ssr {
         fn schedule_update(&self) {
             scheduler::push_component_update(Box::new(UpdateRunner {
                 state: self.state.clone(),
+                #[cfg(feature = "csr")]
+                transition: Transition::current(),
             }));
             // Not guaranteed to already have the scheduler started
             scheduler::start();
@@ -509,13 +730,19 @@ mod feat_csr {
         ComponentRenderState, CreateRunner, DestroyRunner, PropsUpdateRunner, RenderRunner,
     };
     use crate::scheduler;
+    use crate::suspense::Transition;
 
     impl AnyScope {
         #[cfg(any(test, feature = "test"))]
         pub(crate) fn test() -> Self {
             Self {
                 type_id: TypeId::of::<()>(),
+                #[cfg(feature = "hydration_diagnostics")]
+                type_name: std::any::type_name::<()>(),
                 parent: None,
+                position: Rc::default(),
+                #[cfg(feature = "debug-hooks")]
+                hooks: Rc::default(),
                 typed_scope: Rc::new(()),
             }
         }
@@ -530,6 +757,7 @@ mod feat_csr {
             state,
             next_sibling_slot: Some(next_sibling_slot),
             props: Some(props),
+            transition: Transition::current(),
         }));
         // Not guaranteed to already have the scheduler started
         scheduler::start();
@@ -690,7 +918,7 @@ mod feat_hydration {
 
             let collectable = Collectable::for_component::<COMP>();
 
-            let mut fragment = Fragment::collect_between(fragment, &collectable, &parent);
+            let mut fragment = Fragment::collect_between(&root, fragment, &collectable, &parent);
 
             let prepared_state = match fragment
                 .back()
@@ -1,14 +1,255 @@
+use std::fmt;
+use std::rc::Rc;
+
 use thiserror::Error;
 
+use crate::Callback;
 use crate::suspense::Suspension;
 
 /// Render Error.
-#[derive(Error, Debug, Clone, PartialEq)]
+///
+/// [`Suspension`]s are converted into a [`RenderError`] by the `?` operator. Application errors
+/// can be converted with [`RenderError::failed`] in components returning a
+/// [`HtmlResult`](crate::HtmlResult):
+///
+/// ```
+/// # use yew::prelude::*;
+/// use yew::html::RenderError;
+/// # #[derive(Debug)]
+/// # struct User;
+///
+/// #[derive(Debug)]
+/// struct FetchError;
+///
+/// impl std::fmt::Display for FetchError {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "failed to fetch user")
+///     }
+/// }
+///
+/// impl std::error::Error for FetchError {}
+///
+/// fn fetch_user() -> Result<User, FetchError> {
+///     Err(FetchError)
+/// }
+///
+/// #[component]
+/// fn Profile() -> HtmlResult {
+///     let user = fetch_user().map_err(RenderError::failed)?;
+///
+///     Ok(html! { <p>{format!("{user:?}")}</p> })
+/// }
+/// ```
+///
+/// Boxed errors are converted by the `?` operator as well. Other error types need
+/// [`RenderError::failed`]: a blanket `From` implementation for every error type would overlap
+/// with the reflexive `From<RenderError>` implementation, as [`RenderError`] is an error itself.
+///
+/// Failed renders are forwarded to the closest
+/// [`ErrorBoundary`](crate::error_boundary::ErrorBoundary).
+#[derive(Error, Debug, Clone)]
 pub enum RenderError {
     /// Component Rendering Suspended
     #[error("component rendering is suspended.")]
     Suspended(#[from] Suspension),
+    /// Component Rendering Failed
+    #[error("component rendering failed: {0}")]
+    Failed(Rc<dyn std::error::Error>),
+}
+
+impl RenderError {
+    /// Creates a [`RenderError::Failed`] from an error.
+    pub fn failed<E>(e: E) -> Self
+    where
+        E: std::error::Error + 'static,
+    {
+        Self::Failed(Rc::new(e))
+    }
+}
+
+impl From<Box<dyn std::error::Error>> for RenderError {
+    fn from(e: Box<dyn std::error::Error>) -> Self {
+        Self::Failed(e.into())
+    }
+}
+
+impl From<Box<dyn std::error::Error + Send + Sync>> for RenderError {
+    fn from(e: Box<dyn std::error::Error + Send + Sync>) -> Self {
+        Self::Failed(Rc::<dyn std::error::Error + Send + Sync>::from(e))
+    }
+}
+
+impl PartialEq for RenderError {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Suspended(l), Self::Suspended(r)) => l == r,
+            (Self::Failed(l), Self::Failed(r)) => Rc::ptr_eq(l, r),
+            _ => false,
+        }
+    }
 }
 
 /// Render Result.
 pub type RenderResult<T> = std::result::Result<T, RenderError>;
+
+/// An error created from a panic raised by a component lifecycle method.
+///
+/// Panics in `view`, `update` and `rendered` are caught and forwarded to the closest
+/// [`ErrorBoundary`](crate::error_boundary::ErrorBoundary) as this error.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("component panicked: {message}")]
+pub struct PanicError {
+    message: String,
+}
+
+impl PanicError {
+    /// Creates a [`PanicError`] from the payload of a caught panic.
+    #[cfg(any(feature = "csr", feature = "ssr"))]
+    pub(crate) fn from_payload(payload: &(dyn std::any::Any + Send)) -> Self {
+        let message = if let Some(m) = payload.downcast_ref::<&'static str>() {
+            (*m).to_string()
+        } else if let Some(m) = payload.downcast_ref::<String>() {
+            m.clone()
+        } else {
+            "Box<dyn Any>".to_string()
+        };
+
+        Self { message }
+    }
+
+    /// Returns the message the component panicked with.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+/// An error which failed a component during server-side rendering.
+///
+/// When an [`ErrorBoundary`](crate::error_boundary::ErrorBoundary) is hydrated after it has shown
+/// its fallback on the server side, the fallback receives this error in place of the original
+/// one. Only the fact that a failure happened is sent to the client side, the original error and
+/// the name of the failed component are not.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("a component failed during server-side rendering")]
+pub struct ServerRenderError {
+    _private: (),
+}
+
+impl ServerRenderError {
+    #[cfg(any(feature = "csr", feature = "ssr"))]
+    pub(crate) fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+/// Information about an error caught by an
+/// [`ErrorBoundary`](crate::error_boundary::ErrorBoundary).
+///
+/// This is passed to the fallback of the boundary.
+#[derive(Clone)]
+pub struct RenderErrorInfo {
+    error: Rc<dyn std::error::Error>,
+    component: &'static str,
+    reset: Callback<()>,
+}
+
+impl RenderErrorInfo {
+    #[cfg(any(feature = "csr", feature = "ssr"))]
+    pub(crate) fn new(
+        error: Rc<dyn std::error::Error>,
+        component: &'static str,
+        reset: Callback<()>,
+    ) -> Self {
+        Self {
+            error,
+            component,
+            reset,
+        }
+    }
+
+    /// Returns the error that caused the boundary to render its fallback.
+    ///
+    /// Panics are reported as [`PanicError`] and can be recovered with
+    /// [`downcast_ref`](trait@std::error::Error#method.downcast_ref).
+    pub fn error(&self) -> &(dyn std::error::Error + 'static) {
+        &*self.error
+    }
+
+    /// Returns the type name of the component that failed.
+    ///
+    /// This is empty for a [`ServerRenderError`], as the name is not sent to the client side.
+    pub fn component_name(&self) -> &'static str {
+        self.component
+    }
+
+    /// Clears the error and remounts the children of the boundary.
+    pub fn reset(&self) {
+        self.reset.emit(());
+    }
+
+    /// Creates a [`Callback`] which resets the boundary when emitted.
+    ///
+    /// This is convenient to attach to a "Try again" button in the fallback.
+    pub fn reset_callback<IN>(&self) -> Callback<IN> {
+        self.reset.reform(|_| ())
+    }
+}
+
+impl fmt::Debug for RenderErrorInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RenderErrorInfo")
+            .field("error", &self.error)
+            .field("component", &self.component)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for RenderErrorInfo {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.error, &other.error)
+            && self.component == other.component
+            && self.reset == other.reset
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_errors() {
+        let (s, _handle) = Suspension::new();
+        assert_eq!(RenderError::from(s.clone()), RenderError::Suspended(s));
+
+        let e = RenderError::failed(PanicError {
+            message: "oops".to_string(),
+        });
+        assert!(matches!(e, RenderError::Failed(_)));
+        assert_eq!(
+            e.to_string(),
+            "component rendering failed: component panicked: oops"
+        );
+    }
+
+    #[test]
+    fn converts_boxed_errors_with_question_mark() {
+        fn parse(s: &str) -> RenderResult<u32> {
+            let n: Result<u32, Box<dyn std::error::Error>> = s.parse().map_err(Into::into);
+            let n = n?;
+
+            let m: Result<u32, Box<dyn std::error::Error + Send + Sync>> =
+                s.parse().map_err(Into::into);
+
+            Ok(n + m?)
+        }
+
+        assert_eq!(parse("1"), Ok(2));
+
+        let e = parse("one").unwrap_err();
+        assert!(matches!(e, RenderError::Failed(_)));
+        assert_eq!(
+            e.to_string(),
+            "component rendering failed: invalid digit found in string"
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use super::HydrateOn;
+use crate::html::{Html, Properties};
+
+/// Properties for [Hydrate].
+#[derive(Properties, PartialEq, Debug, Clone)]
+pub struct HydrateProps {
+    /// The Children of the current Hydrate Component.
+    #[prop_or_default]
+    pub children: Html,
+
+    /// When the children are hydrated.
+    #[prop_or_default]
+    pub on: HydrateOn,
+
+    /// The Fallback UI shown while the children are suspended.
+    #[prop_or_default]
+    pub fallback: Html,
+}
+
+#[cfg(any(feature = "csr", feature = "ssr"))]
+mod feat_csr_ssr {
+    use super::*;
+    use crate::suspense::BaseSuspense;
+    use crate::{component, html};
+
+    /// Defers the hydration of its children.
+    ///
+    /// The server-side rendered content of the children stays in place and is not interactive
+    /// until the condition set by [`on`](HydrateProps::on) has been met. The children are then
+    /// hydrated in place. When the application is rendered on the client side, the children are
+    /// rendered immediately.
+    ///
+    /// Like [`Suspense`](crate::suspense::Suspense), this component shows its fallback while any
+    /// of its children are suspended.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use yew::hydration::{Hydrate, HydrateOn};
+    /// use yew::prelude::*;
+    ///
+    /// #[component]
+    /// fn Comments() -> Html {
+    ///     html! { <ul><li>{"First!"}</li></ul> }
+    /// }
+    ///
+    /// #[component]
+    /// fn Article() -> Html {
+    ///     html! {
+    ///         <article>{"..."}</article>
+    ///         <Hydrate on={HydrateOn::Visible}>
+    ///             <Comments />
+    ///         </Hydrate>
+    ///     }
+    /// }
+    /// ```
+    #[component]
+    pub fn Hydrate(props: &HydrateProps) -> Html {
+        let HydrateProps {
+            children,
+            on,
+            fallback,
+        } = props.clone();
+
+        let fallback = html! {
+            <BaseSuspense>
+                {fallback}
+            </BaseSuspense>
+        };
+
+        html! {
+            <BaseSuspense {fallback} hydrate_on={on}>
+                {children}
+            </BaseSuspense>
+        }
+    }
+}
+
+#[cfg(any(feature = "csr", feature = "ssr"))]
+pub use feat_csr_ssr::*;
+
+#[cfg(not(any(feature = "ssr", feature = "csr")))]
+mod feat_no_csr_ssr {
+    use super::*;
+    use crate::component;
+
+    /// Defers the hydration of its children.
+    #[component]
+    pub fn Hydrate(_props: &HydrateProps) -> Html {
+        Html::default()
+    }
+}
+
+#[cfg(not(any(feature = "ssr", feature = "csr")))]
+pub use feat_no_csr_ssr::*;
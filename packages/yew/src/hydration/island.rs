@@ -0,0 +1,338 @@
+use std::marker::PhantomData;
+
+use super::HydrateOn;
+use crate::html::{BaseComponent, Properties};
+
+/// Properties for [Island].
+#[derive(Properties, PartialEq, Debug, Clone)]
+pub struct IslandProps<P>
+where
+    P: Properties,
+{
+    /// The properties of the component rendered by the island.
+    pub props: P,
+
+    /// When the island is hydrated by [`Islands`].
+    #[prop_or_default]
+    pub on: HydrateOn,
+}
+
+/// A component which can be rendered as an [`Island`].
+///
+/// The name identifies the component on the client side. It has to be unique among the registered
+/// islands, must not change between the builds of the server and the client and may only contain
+/// ASCII letters, digits, `-` and `_`.
+pub trait IslandComponent: BaseComponent {
+    /// The name the island is rendered and registered with.
+    const NAME: &'static str;
+}
+
+/// Marks a component which is hydrated by [`Islands`].
+///
+/// The properties of the component are sent to the client alongside the server-side rendered
+/// content, so that the island can be hydrated without hydrating the rest of the application.
+/// When the whole application is hydrated or rendered on the client side, the component is
+/// rendered as usual.
+///
+/// # Example
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use yew::hydration::{Island, IslandComponent};
+/// use yew::prelude::*;
+///
+/// #[derive(Properties, PartialEq, Clone, Serialize, Deserialize)]
+/// struct CounterProps {
+///     start: u32,
+/// }
+///
+/// #[component]
+/// fn Counter(props: &CounterProps) -> Html {
+///     let count = use_state(|| props.start);
+///     let onclick = {
+///         let count = count.clone();
+///         move |_| count.set(*count + 1)
+///     };
+///
+///     html! { <button {onclick}>{*count}</button> }
+/// }
+///
+/// impl IslandComponent for Counter {
+///     const NAME: &'static str = "counter";
+/// }
+///
+/// #[component]
+/// fn Page() -> Html {
+///     html! {
+///         <main>
+///             <p>{"A lot of static content."}</p>
+///             <Island<Counter> props={CounterProps { start: 3 }} />
+///         </main>
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Island<COMP>
+where
+    COMP: BaseComponent,
+{
+    #[cfg(feature = "ssr")]
+    props: COMP::Properties,
+    _marker: PhantomData<COMP>,
+}
+
+mod feat_component {
+    use serde::Serialize;
+    use serde::de::DeserializeOwned;
+
+    use super::*;
+    use crate::html;
+    use crate::html::{Component, Context, Html};
+    use crate::virtual_dom::VChild;
+
+    impl<COMP> Component for Island<COMP>
+    where
+        COMP: IslandComponent,
+        COMP::Properties: Clone + Serialize + DeserializeOwned,
+    {
+        type Message = ();
+        type Properties = IslandProps<COMP::Properties>;
+
+        fn create(ctx: &Context<Self>) -> Self {
+            #[cfg(not(feature = "ssr"))]
+            let _ = ctx;
+
+            Self {
+                #[cfg(feature = "ssr")]
+                props: ctx.props().props.clone(),
+                _marker: PhantomData,
+            }
+        }
+
+        fn view(&self, ctx: &Context<Self>) -> Html {
+            let IslandProps { props, on } = ctx.props().clone();
+
+            html! {
+                <yew-island
+                    style="display: contents"
+                    data-component={COMP::NAME}
+                    data-hydrate-on={on.name()}
+                >
+                    {VChild::<COMP>::new(props, None)}
+                </yew-island>
+            }
+        }
+
+        #[cfg(feature = "ssr")]
+        fn prepare_state(&self) -> Option<String> {
+            use base64ct::{Base64, Encoding};
+
+            let props = bincode::serde::encode_to_vec(&self.props, bincode::config::standard())
+                .expect("failed to serialize island properties");
+
+            Some(Base64::encode_string(&props))
+        }
+    }
+}
+
+#[cfg(feature = "hydration")]
+mod feat_hydration {
+    use std::fmt;
+    use std::rc::Rc;
+
+    use serde::de::DeserializeOwned;
+    use wasm_bindgen::JsCast;
+    use web_sys::{Element, HtmlScriptElement, Node};
+
+    use super::*;
+    use crate::Renderer;
+    use crate::functional::decode_base64;
+    use crate::hydration::HydrationTrigger;
+    use crate::platform::spawn_local;
+
+    type HydrateIsland = Rc<dyn Fn(Element, HydrateOn, String)>;
+
+    /// Hydrates the [`Island`]s of a server-side rendered page.
+    ///
+    /// Only the islands are hydrated, the rest of the page stays static. Each island is hydrated
+    /// as a separate application once the condition set by [`on`](IslandProps::on) has been met.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use serde::{Deserialize, Serialize};
+    /// # use yew::prelude::*;
+    /// # #[derive(Properties, PartialEq, Clone, Serialize, Deserialize)]
+    /// # struct CounterProps {}
+    /// # #[component]
+    /// # fn Counter(props: &CounterProps) -> Html { Html::default() }
+    /// # impl yew::hydration::IslandComponent for Counter {
+    /// #     const NAME: &'static str = "counter";
+    /// # }
+    /// use yew::hydration::Islands;
+    ///
+    /// Islands::new().register::<Counter>().hydrate();
+    /// ```
+    #[derive(Default)]
+    pub struct Islands {
+        components: Vec<(&'static str, HydrateIsland)>,
+    }
+
+    impl fmt::Debug for Islands {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("Islands").finish_non_exhaustive()
+        }
+    }
+
+    impl Islands {
+        /// Creates an island renderer without any registered components.
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Registers a component rendered by [`Island`]s.
+        pub fn register<COMP>(mut self) -> Self
+        where
+            COMP: IslandComponent,
+            COMP::Properties: DeserializeOwned,
+        {
+            let hydrate: HydrateIsland = Rc::new(|root, on, props| {
+                spawn_local(async move {
+                    let props = format!("data:application/octet-binary;base64,{props}");
+                    let props = decode_base64(&props)
+                        .await
+                        .expect("failed to deserialize island properties");
+                    let (props, _) = bincode::serde::decode_from_slice::<COMP::Properties, _>(
+                        &props,
+                        bincode::config::standard(),
+                    )
+                    .expect("failed to deserialize island properties");
+
+                    let node = Node::from(root.clone());
+                    HydrationTrigger::spawn(on, [&node], move || {
+                        Renderer::<COMP>::with_root_and_props(root, props).hydrate();
+                    });
+                });
+            });
+
+            self.components.push((COMP::NAME, hydrate));
+
+            self
+        }
+
+        /// Hydrates all islands of registered components in the document.
+        pub fn hydrate(self) {
+            let document = gloo::utils::document();
+
+            for (component, hydrate) in self.components.iter() {
+                let selector = format!(r#"yew-island[data-component="{component}"]"#);
+                let roots = document
+                    .query_selector_all(&selector)
+                    .expect("failed to query islands");
+
+                for root in (0..roots.length()).filter_map(|i| roots.item(i)) {
+                    let root = root.unchecked_into::<Element>();
+
+                    // Islands nested in other islands are hydrated with their parent.
+                    let nested = root
+                        .parent_element()
+                        .and_then(|m| m.closest("yew-island").ok().flatten())
+                        .is_some();
+                    if nested {
+                        continue;
+                    }
+
+                    let on = root
+                        .get_attribute("data-hydrate-on")
+                        .and_then(|m| HydrateOn::from_name(&m))
+                        .unwrap_or_default();
+                    // The properties are rendered after the island by `Island::prepare_state`.
+                    let props = root
+                        .next_element_sibling()
+                        .and_then(|m| m.dyn_into::<HtmlScriptElement>().ok())
+                        .filter(|m| m.type_() == "application/x-yew-comp-state")
+                        .expect("failed to find island properties");
+
+                    hydrate(root, on, props.text().unwrap());
+                    props.remove();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "hydration")]
+pub use feat_hydration::*;
+
+#[cfg(all(test, not(target_arch = "wasm32"), feature = "ssr"))]
+mod ssr_tests {
+    use serde::{Deserialize, Serialize};
+    use tokio::test;
+
+    use super::*;
+    use crate::ServerRenderer;
+    use crate::prelude::*;
+
+    #[derive(Properties, PartialEq, Clone, Debug, Serialize, Deserialize)]
+    struct CounterProps {
+        start: u32,
+    }
+
+    #[component]
+    fn Counter(props: &CounterProps) -> Html {
+        html! { <button>{props.start}</button> }
+    }
+
+    impl IslandComponent for Counter {
+        const NAME: &'static str = "counter";
+    }
+
+    #[component]
+    fn Page() -> Html {
+        html! {
+            <main>
+                <Island<Counter> props={CounterProps { start: 3 }} on={HydrateOn::Visible} />
+            </main>
+        }
+    }
+
+    /// Decodes the properties rendered after an island.
+    fn island_props(s: &str) -> CounterProps {
+        use base64ct::{Base64, Encoding};
+
+        let start = r#"<script type="application/x-yew-comp-state">"#;
+        let state = s
+            .split(start)
+            .nth(1)
+            .and_then(|m| m.split("</script>").next());
+        let state = Base64::decode_vec(state.expect("no island properties")).unwrap();
+        let (props, _) = bincode::serde::decode_from_slice::<CounterProps, _>(
+            &state,
+            bincode::config::standard(),
+        )
+        .unwrap();
+
+        props
+    }
+
+    #[test]
+    async fn renders_island_with_props() {
+        let s = ServerRenderer::<Page>::new().render().await;
+
+        let open_tag = r#"<yew-island style="display: contents" data-component="counter" data-hydrate-on="visible">"#;
+        assert!(s.contains(open_tag), "{s}");
+        assert!(s.contains("<button>3</button>"), "{s}");
+        assert_eq!(island_props(&s), CounterProps { start: 3 });
+    }
+
+    #[test]
+    async fn renders_island_props_without_hydration_markers() {
+        let s = ServerRenderer::<Page>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        assert!(!s.contains("<!--"), "{s}");
+        assert_eq!(island_props(&s), CounterProps { start: 3 });
+    }
+}
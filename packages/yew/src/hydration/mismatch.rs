@@ -0,0 +1,272 @@
+use std::cell::RefCell;
+use std::fmt;
+
+use web_sys::Node;
+
+use crate::callback::Callback;
+use crate::html::AnyScope;
+
+/// The maximum number of characters of a node included in a [`HydrationMismatch`].
+#[cfg(feature = "hydration_diagnostics")]
+const SNIPPET_LEN: usize = 256;
+
+/// The mismatches reported while hydrating a component.
+struct Tracking {
+    mismatched: bool,
+    #[cfg(feature = "hydration_diagnostics")]
+    scope: AnyScope,
+}
+
+thread_local! {
+    static TRACKING: RefCell<Option<Tracking>> = const { RefCell::new(None) };
+}
+
+/// A difference between the server-side rendered DOM and the layout rendered by the application
+/// during hydration.
+///
+/// With the `hydration_diagnostics` feature, the mismatch also describes where it has been found,
+/// the virtual DOM node that has been expected and the DOM node that has been found instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HydrationMismatch {
+    message: String,
+    /// Whether the difference has been repaired in place.
+    repaired: bool,
+    #[cfg(feature = "hydration_diagnostics")]
+    component_path: Vec<&'static str>,
+    #[cfg(feature = "hydration_diagnostics")]
+    expected: Option<String>,
+    #[cfg(feature = "hydration_diagnostics")]
+    actual: Option<String>,
+}
+
+impl HydrationMismatch {
+    pub(crate) fn new(message: String) -> Self {
+        Self {
+            message,
+            repaired: false,
+            #[cfg(feature = "hydration_diagnostics")]
+            component_path: Vec::new(),
+            #[cfg(feature = "hydration_diagnostics")]
+            expected: None,
+            #[cfg(feature = "hydration_diagnostics")]
+            actual: None,
+        }
+    }
+
+    /// Records the virtual DOM node that has been expected.
+    pub(crate) fn with_expected(self, expected: &dyn fmt::Debug) -> Self {
+        #[cfg(feature = "hydration_diagnostics")]
+        {
+            let mut this = self;
+            this.expected = Some(snippet(format!("{expected:?}")));
+            this
+        }
+        #[cfg(not(feature = "hydration_diagnostics"))]
+        {
+            let _ = expected;
+            self
+        }
+    }
+
+    /// Records the DOM node that has been found instead, `None` if there are no nodes left.
+    pub(crate) fn with_actual(self, actual: Option<&Node>) -> Self {
+        #[cfg(feature = "hydration_diagnostics")]
+        {
+            let mut this = self;
+            this.actual = Some(actual.map(serialize).unwrap_or_else(|| "EOF".to_owned()));
+            this
+        }
+        #[cfg(not(feature = "hydration_diagnostics"))]
+        {
+            let _ = actual;
+            self
+        }
+    }
+
+    /// Returns a description of the mismatch.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns whether the difference has been repaired in place.
+    ///
+    /// Differences in attributes and text are repaired by updating the server-side rendered
+    /// nodes and do not cause the component to be rendered on the client side. They are only
+    /// reported with the `hydration_diagnostics` feature.
+    pub fn repaired(&self) -> bool {
+        self.repaired
+    }
+
+    /// Returns the type names of the components from the root of the application to the
+    /// component in which the mismatch has been found.
+    #[cfg(feature = "hydration_diagnostics")]
+    pub fn component_path(&self) -> &[&'static str] {
+        &self.component_path
+    }
+
+    /// Returns a description of the virtual DOM node that has been expected.
+    #[cfg(feature = "hydration_diagnostics")]
+    pub fn expected(&self) -> Option<&str> {
+        self.expected.as_deref()
+    }
+
+    /// Returns a snippet of the DOM that has been found instead.
+    #[cfg(feature = "hydration_diagnostics")]
+    pub fn actual(&self) -> Option<&str> {
+        self.actual.as_deref()
+    }
+}
+
+impl fmt::Display for HydrationMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)?;
+
+        #[cfg(feature = "hydration_diagnostics")]
+        {
+            if !self.component_path.is_empty() {
+                write!(f, "\n  in: {}", self.component_path.join(" > "))?;
+            }
+            if let Some(ref m) = self.expected {
+                write!(f, "\n  expected: {m}")?;
+            }
+            if let Some(ref m) = self.actual {
+                write!(f, "\n  found: {m}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// How hydration handles a server-side rendered DOM that does not match the application.
+///
+/// Unless the policy is [`Panic`](Self::Panic), the server-side rendered content of the
+/// component in which the mismatch was found is discarded and the component is rendered on the
+/// client side instead. The rest of the application is still hydrated.
+///
+/// Defaults to [`Panic`](Self::Panic) in debug builds and [`Warn`](Self::Warn) in release builds.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MismatchPolicy {
+    /// Panics with a description of the mismatch.
+    ///
+    /// Differences which have been repaired in place are logged as a warning instead.
+    Panic,
+    /// Logs a warning and renders the mismatched component on the client side.
+    Warn,
+    /// Notifies the callback and renders the mismatched component on the client side.
+    Callback(Callback<HydrationMismatch>),
+}
+
+impl Default for MismatchPolicy {
+    fn default() -> Self {
+        if cfg!(debug_assertions) {
+            Self::Panic
+        } else {
+            Self::Warn
+        }
+    }
+}
+
+impl MismatchPolicy {
+    /// Handles a mismatch found while hydrating.
+    ///
+    /// Only the first mismatch is handled while tracking mismatches, the ones that follow are
+    /// usually caused by it and the content is discarded anyway.
+    pub(crate) fn report(&self, mismatch: HydrationMismatch) {
+        let proceed = TRACKING.with(|m| match *m.borrow_mut() {
+            Some(ref m) if m.mismatched => false,
+            Some(ref mut m) => {
+                m.mismatched |= !mismatch.repaired;
+                true
+            }
+            None => true,
+        });
+        if !proceed {
+            return;
+        }
+
+        #[cfg(feature = "hydration_diagnostics")]
+        let mismatch = {
+            let mut mismatch = mismatch;
+            mismatch.component_path = TRACKING.with(|m| {
+                m.borrow()
+                    .as_ref()
+                    .map(|m| component_path(&m.scope))
+                    .unwrap_or_default()
+            });
+            mismatch
+        };
+
+        match self {
+            // Repaired differences leave the application intact, so they are only logged.
+            Self::Panic if !mismatch.repaired => panic!("{mismatch}"),
+            Self::Panic | Self::Warn => tracing::warn!("hydration mismatch: {mismatch}"),
+            Self::Callback(callback) => callback.emit(mismatch),
+        }
+    }
+
+    /// Handles a difference which has been repaired in place.
+    ///
+    /// Unlike other mismatches, these never panic, they are logged as a warning under
+    /// [`Panic`](Self::Panic).
+    #[cfg(feature = "hydration_diagnostics")]
+    pub(crate) fn report_repaired(&self, mismatch: HydrationMismatch) {
+        self.report(HydrationMismatch {
+            repaired: true,
+            ..mismatch
+        });
+    }
+}
+
+/// Runs `f` and returns whether a mismatch has been reported while it ran.
+///
+/// `scope` is the scope of the component being hydrated.
+pub(crate) fn track_mismatches<R>(scope: &AnyScope, f: impl FnOnce() -> R) -> (R, bool) {
+    #[cfg(not(feature = "hydration_diagnostics"))]
+    let _ = scope;
+
+    let tracking = Tracking {
+        mismatched: false,
+        #[cfg(feature = "hydration_diagnostics")]
+        scope: scope.clone(),
+    };
+
+    let prev = TRACKING.with(|m| m.replace(Some(tracking)));
+    let result = f();
+    let tracking = TRACKING.with(|m| m.replace(prev));
+
+    (result, tracking.is_some_and(|m| m.mismatched))
+}
+
+#[cfg(feature = "hydration_diagnostics")]
+fn component_path(scope: &AnyScope) -> Vec<&'static str> {
+    let mut path = std::iter::successors(Some(scope), |m| m.get_parent())
+        .map(|m| m.type_name())
+        .collect::<Vec<_>>();
+    path.reverse();
+
+    path
+}
+
+#[cfg(feature = "hydration_diagnostics")]
+fn serialize(node: &Node) -> String {
+    use wasm_bindgen::JsCast;
+    use web_sys::Element;
+
+    let s = match node.node_type() {
+        Node::ELEMENT_NODE => node.unchecked_ref::<Element>().outer_html(),
+        Node::COMMENT_NODE => format!("<!--{}-->", node.text_content().unwrap_or_default()),
+        Node::TEXT_NODE => format!("{:?}", node.text_content().unwrap_or_default()),
+        _ => node.node_name(),
+    };
+
+    snippet(s)
+}
+
+#[cfg(feature = "hydration_diagnostics")]
+fn snippet(s: String) -> String {
+    match s.char_indices().nth(SNIPPET_LEN) {
+        Some((i, _)) => format!("{}...", &s[..i]),
+        None => s,
+    }
+}
@@ -0,0 +1,64 @@
+//! This module provides utilities to control the hydration of server-side rendered applications.
+//!
+//! By default, [`Renderer::hydrate`](crate::Renderer) hydrates the whole application as soon as
+//! it starts. The [`Hydrate`] component defers the hydration of a subtree until it becomes
+//! visible, is interacted with or the browser is idle. Until then, the server-side rendered DOM
+//! of the subtree is left untouched.
+//!
+//! How a server-side rendered DOM that does not match the application is handled can be
+//! configured with [`MismatchPolicy`].
+//!
+//! Pages that are mostly static can skip the hydration of the application altogether and only
+//! hydrate [`Island`]s with [`Islands`].
+
+mod hydrate;
+mod island;
+#[cfg(feature = "hydration")]
+mod mismatch;
+#[cfg(feature = "hydration")]
+mod trigger;
+
+pub use hydrate::*;
+pub use island::*;
+#[cfg(feature = "hydration")]
+pub(crate) use mismatch::track_mismatches;
+#[cfg(feature = "hydration")]
+pub use mismatch::{HydrationMismatch, MismatchPolicy};
+#[cfg(feature = "hydration")]
+pub(crate) use trigger::HydrationTrigger;
+
+/// When a deferred subtree is hydrated.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum HydrateOn {
+    /// Hydrates the subtree together with the rest of the application.
+    #[default]
+    Load,
+    /// Hydrates the subtree once the browser is idle.
+    Idle,
+    /// Hydrates the subtree once any part of it becomes visible in the viewport.
+    Visible,
+    /// Hydrates the subtree once the pointer moves over it or it receives focus.
+    Interaction,
+}
+
+impl HydrateOn {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Load => "load",
+            Self::Idle => "idle",
+            Self::Visible => "visible",
+            Self::Interaction => "interaction",
+        }
+    }
+
+    #[cfg(feature = "hydration")]
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "load" => Some(Self::Load),
+            "idle" => Some(Self::Idle),
+            "visible" => Some(Self::Visible),
+            "interaction" => Some(Self::Interaction),
+            _ => None,
+        }
+    }
+}
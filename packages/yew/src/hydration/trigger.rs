@@ -0,0 +1,152 @@
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::rc::Rc;
+
+use gloo::events::EventListener;
+use gloo::timers::callback::Timeout;
+use gloo::utils::window;
+use wasm_bindgen::JsCast;
+use wasm_bindgen::prelude::Closure;
+use web_sys::{Element, IntersectionObserver, IntersectionObserverEntry, Node};
+
+use super::HydrateOn;
+use crate::platform::spawn_local;
+
+/// Events that start the hydration of a subtree with [`HydrateOn::Interaction`].
+const INTERACTION_EVENTS: [&str; 3] = ["pointerover", "pointerdown", "focusin"];
+
+// Closures and listeners are only held until the trigger is dropped.
+#[allow(dead_code)]
+enum Inner {
+    None,
+    Idle(u32, Closure<dyn FnMut()>),
+    Timeout(Timeout),
+    Visible(IntersectionObserver, Closure<dyn FnMut(js_sys::Array)>),
+    Interaction(Vec<EventListener>),
+}
+
+/// Waits for the condition of a [`HydrateOn`] to be met for server-side rendered nodes.
+///
+/// The callback is called at most once. Dropping the trigger cancels it.
+pub(crate) struct HydrationTrigger {
+    inner: Inner,
+}
+
+impl fmt::Debug for HydrationTrigger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HydrationTrigger").finish_non_exhaustive()
+    }
+}
+
+impl HydrationTrigger {
+    pub(crate) fn new<'a, F>(on: HydrateOn, nodes: impl IntoIterator<Item = &'a Node>, f: F) -> Self
+    where
+        F: 'static + FnOnce(),
+    {
+        let f = Rc::new(Cell::new(Some(f)));
+        let fire = move || {
+            if let Some(m) = f.take() {
+                m();
+            }
+        };
+
+        let elements = nodes
+            .into_iter()
+            .filter_map(|m| m.dyn_ref::<Element>().cloned())
+            .collect::<Vec<_>>();
+
+        let inner = match on {
+            HydrateOn::Load => {
+                fire();
+                Inner::None
+            }
+            // Content without elements can neither be seen nor interacted with.
+            HydrateOn::Visible | HydrateOn::Interaction if elements.is_empty() => {
+                fire();
+                Inner::None
+            }
+            HydrateOn::Idle => {
+                let fire = Rc::new(fire);
+                let callback = {
+                    let fire = fire.clone();
+                    Closure::<dyn FnMut()>::new(move || fire())
+                };
+
+                // `requestIdleCallback` is not available in all browsers.
+                match window().request_idle_callback(callback.as_ref().unchecked_ref()) {
+                    Ok(m) => Inner::Idle(m, callback),
+                    Err(_) => Inner::Timeout(Timeout::new(1, move || fire())),
+                }
+            }
+            HydrateOn::Visible => {
+                let callback =
+                    Closure::<dyn FnMut(js_sys::Array)>::new(move |entries: js_sys::Array| {
+                        let visible = entries.iter().any(|m| {
+                            m.unchecked_into::<IntersectionObserverEntry>()
+                                .is_intersecting()
+                        });
+
+                        if visible {
+                            fire();
+                        }
+                    });
+                let observer = IntersectionObserver::new(callback.as_ref().unchecked_ref())
+                    .expect("failed to create intersection observer");
+
+                for element in elements.iter() {
+                    observer.observe(element);
+                }
+
+                Inner::Visible(observer, callback)
+            }
+            HydrateOn::Interaction => {
+                let fire = Rc::new(fire);
+
+                let listeners = elements
+                    .iter()
+                    .flat_map(|element| {
+                        INTERACTION_EVENTS.iter().map(|event_type| {
+                            let fire = fire.clone();
+                            EventListener::new(element, *event_type, move |_| fire())
+                        })
+                    })
+                    .collect();
+
+                Inner::Interaction(listeners)
+            }
+        };
+
+        Self { inner }
+    }
+
+    /// Calls `f` once the condition has been met, without the need to hold on to a trigger.
+    pub(crate) fn spawn<'a, F>(on: HydrateOn, nodes: impl IntoIterator<Item = &'a Node>, f: F)
+    where
+        F: 'static + FnOnce(),
+    {
+        let holder: Rc<RefCell<Option<Self>>> = Rc::default();
+
+        let trigger = {
+            let holder = holder.clone();
+            Self::new(on, nodes, move || {
+                // The trigger cannot be dropped while its callback is running.
+                spawn_local(async move {
+                    drop(holder.take());
+                    f();
+                });
+            })
+        };
+
+        *holder.borrow_mut() = Some(trigger);
+    }
+}
+
+impl Drop for HydrationTrigger {
+    fn drop(&mut self) {
+        match self.inner {
+            Inner::Idle(handle, _) => window().cancel_idle_callback(handle),
+            Inner::Visible(ref observer, _) => observer.disconnect(),
+            Inner::None | Inner::Timeout(_) | Inner::Interaction(_) => {}
+        }
+    }
+}
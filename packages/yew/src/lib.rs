@@ -274,16 +274,22 @@ pub mod callback;
 pub mod context;
 #[cfg(feature = "csr")]
 mod dom_bundle;
+pub mod error_boundary;
 pub mod functional;
 pub mod html;
+pub mod hydration;
 pub mod platform;
+#[cfg(feature = "profiling")]
+pub mod profiler;
 pub mod scheduler;
 mod sealed;
 #[cfg(feature = "ssr")]
 mod server_renderer;
+pub mod store;
 pub mod suspense;
 pub mod utils;
 pub mod virtual_dom;
+pub mod virtual_list;
 #[cfg(feature = "ssr")]
 pub use server_renderer::*;
 
@@ -329,6 +335,7 @@ pub mod prelude {
     pub use crate::app_handle::AppHandle;
     pub use crate::callback::{Callback, CallbackRef, CallbackRefMut};
     pub use crate::context::{ContextHandle, ContextProvider};
+    pub use crate::error_boundary::ErrorBoundary;
     pub use crate::events::*;
     pub use crate::functional::*;
     pub use crate::html::{
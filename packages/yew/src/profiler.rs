@@ -0,0 +1,419 @@
+//! This module contains a profiler recording the lifecycle of components.
+//!
+//! The profiler is available with the `profiling` feature. Recording is started with [`start`]
+//! and the recorded [`Profile`] can be read with [`snapshot`], for example to find components
+//! which are rendered more often than expected:
+//!
+//! ```rust
+//! use yew::profiler;
+//!
+//! profiler::start();
+//! // Render and interact with the application...
+//! let profile = profiler::snapshot();
+//! profiler::stop();
+//!
+//! for m in profile.by_type() {
+//!     println!("{}: rendered {} times", m.name, m.stats.rendered);
+//! }
+//! ```
+//!
+//! [`Profile::to_chrome_trace`] exports the recorded spans in the
+//! [trace event format](https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU),
+//! which can be opened in `chrome://tracing` or [Perfetto](https://ui.perfetto.dev).
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::time::Duration;
+
+thread_local! {
+    static RECORDING: RefCell<Option<Recording>> = const { RefCell::new(None) };
+}
+
+/// Returns the time elapsed since an arbitrary point in time.
+#[cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+fn now() -> Duration {
+    use wasm_bindgen::prelude::*;
+
+    #[wasm_bindgen]
+    unsafe extern "C" {
+        #[wasm_bindgen(js_namespace = performance, js_name = now)]
+        fn performance_now() -> f64;
+    }
+
+    Duration::from_secs_f64(performance_now() / 1000.0)
+}
+
+/// Returns the time elapsed since an arbitrary point in time.
+#[cfg(not(all(target_arch = "wasm32", not(target_os = "wasi"))))]
+fn now() -> Duration {
+    use std::time::Instant;
+
+    thread_local! {
+        static ORIGIN: Instant = Instant::now();
+    }
+
+    ORIGIN.with(Instant::elapsed)
+}
+
+/// The kind of work recorded in a [`Span`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpanKind {
+    /// A component is created.
+    Create,
+    /// A component is updated with messages or new properties.
+    Update,
+    /// The `view` method of a component is called.
+    View,
+    /// The output of `view` is reconciled with the DOM, or hydrated.
+    Reconcile,
+    /// The `rendered` method of a component is called. This is when the effects of function
+    /// components are called.
+    Rendered,
+    /// A component is destroyed.
+    Destroy,
+    /// The scheduler runs queued work until it yields or runs out of work.
+    Scheduler,
+}
+
+impl SpanKind {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Create => "create",
+            Self::Update => "update",
+            Self::View => "view",
+            Self::Reconcile => "reconcile",
+            Self::Rendered => "rendered",
+            Self::Destroy => "destroy",
+            Self::Scheduler => "scheduler",
+        }
+    }
+}
+
+/// A component which a [`Span`] has been recorded for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ComponentId {
+    /// The id of the component instance, unique for the lifetime of the application.
+    pub id: usize,
+    /// The type name of the component.
+    pub name: &'static str,
+}
+
+/// A unit of work recorded by the profiler.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Span {
+    /// The kind of work.
+    pub kind: SpanKind,
+    /// The component the work has been done for, `None` for the scheduler.
+    pub component: Option<ComponentId>,
+    /// When the work started, relative to the start of the recording.
+    pub start: Duration,
+    /// How long the work took, including nested spans.
+    pub duration: Duration,
+}
+
+/// Lifecycle counts and timings of components.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ComponentStats {
+    /// How many times the component has been created.
+    pub created: u32,
+    /// How many times `view` has been called.
+    pub rendered: u32,
+    /// How many times the component has been updated with messages or new properties.
+    pub updated: u32,
+    /// How many times the component has been destroyed.
+    pub destroyed: u32,
+    /// The time spent in `view`.
+    pub view_time: Duration,
+    /// The time spent reconciling the output of `view` with the DOM.
+    pub reconcile_time: Duration,
+}
+
+impl ComponentStats {
+    fn record(&mut self, kind: SpanKind, duration: Duration) {
+        match kind {
+            SpanKind::Create => self.created += 1,
+            SpanKind::Update => self.updated += 1,
+            SpanKind::View => {
+                self.rendered += 1;
+                self.view_time += duration;
+            }
+            SpanKind::Reconcile => self.reconcile_time += duration,
+            SpanKind::Destroy => self.destroyed += 1,
+            SpanKind::Rendered | SpanKind::Scheduler => {}
+        }
+    }
+
+    fn merge(&mut self, other: &Self) {
+        self.created += other.created;
+        self.rendered += other.rendered;
+        self.updated += other.updated;
+        self.destroyed += other.destroyed;
+        self.view_time += other.view_time;
+        self.reconcile_time += other.reconcile_time;
+    }
+}
+
+/// The stats of a component instance.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComponentProfile {
+    /// The component instance.
+    pub component: ComponentId,
+    /// The stats recorded for the instance.
+    pub stats: ComponentStats,
+}
+
+/// The stats of all instances of a component type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeProfile {
+    /// The type name of the component.
+    pub name: &'static str,
+    /// How many instances have been recorded.
+    pub instances: usize,
+    /// The sum of the stats of all instances.
+    pub stats: ComponentStats,
+}
+
+/// A snapshot of the recording of the profiler, returned by [`snapshot`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Profile {
+    /// The stats of every component instance recorded, ordered by id.
+    pub components: Vec<ComponentProfile>,
+    /// The recorded spans, ordered by the time they finished.
+    pub spans: Vec<Span>,
+}
+
+impl Profile {
+    /// Returns the stats of each component type, ordered by name.
+    pub fn by_type(&self) -> Vec<TypeProfile> {
+        let mut types = BTreeMap::<&'static str, TypeProfile>::new();
+        for m in &self.components {
+            let profile = types
+                .entry(m.component.name)
+                .or_insert_with(|| TypeProfile {
+                    name: m.component.name,
+                    instances: 0,
+                    stats: ComponentStats::default(),
+                });
+            profile.instances += 1;
+            profile.stats.merge(&m.stats);
+        }
+
+        types.into_values().collect()
+    }
+
+    /// Exports the spans in the Chrome trace event format.
+    ///
+    /// Every span is exported as a complete event, named after its component, with the kind of
+    /// the span as its category.
+    pub fn to_chrome_trace(&self) -> String {
+        let mut s = String::from(r#"{"traceEvents":["#);
+        for (i, m) in self.spans.iter().enumerate() {
+            if i > 0 {
+                s.push(',');
+            }
+            let name = m.component.map_or(m.kind.name(), |m| m.name);
+            let _ = write!(
+                s,
+                r#"{{"name":"{}","cat":"{}","ph":"X","ts":{},"dur":{},"pid":1,"tid":1"#,
+                escape(name),
+                m.kind.name(),
+                m.start.as_micros(),
+                m.duration.as_micros(),
+            );
+            if let Some(component) = m.component {
+                let _ = write!(s, r#","args":{{"id":{}}}"#, component.id);
+            }
+            s.push('}');
+        }
+        s.push_str(r#"],"displayTimeUnit":"ms"}"#);
+
+        s
+    }
+}
+
+/// Escapes a string for a JSON string literal.
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str(r#"\""#),
+            '\\' => escaped.push_str(r"\\"),
+            c if c.is_control() => {
+                let _ = write!(escaped, "\\u{:04x}", c as u32);
+            }
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+struct Recording {
+    started: Duration,
+    components: BTreeMap<usize, ComponentProfile>,
+    spans: Vec<Span>,
+}
+
+impl Recording {
+    fn record(&mut self, kind: SpanKind, component: Option<ComponentId>, start: Duration) {
+        let duration = now().saturating_sub(start);
+        if let Some(component) = component {
+            self.components
+                .entry(component.id)
+                .or_insert_with(|| ComponentProfile {
+                    component,
+                    stats: ComponentStats::default(),
+                })
+                .stats
+                .record(kind, duration);
+        }
+        self.spans.push(Span {
+            kind,
+            component,
+            start: start.saturating_sub(self.started),
+            duration,
+        });
+    }
+}
+
+/// Starts recording, discarding the previous recording.
+pub fn start() {
+    RECORDING.with(|m| {
+        *m.borrow_mut() = Some(Recording {
+            started: now(),
+            components: BTreeMap::new(),
+            spans: Vec::new(),
+        })
+    });
+}
+
+/// Stops recording and discards the recording.
+pub fn stop() {
+    RECORDING.with(|m| *m.borrow_mut() = None);
+}
+
+/// Returns `true` if the profiler is recording.
+pub fn is_recording() -> bool {
+    RECORDING.with(|m| m.borrow().is_some())
+}
+
+/// Returns what has been recorded since [`start`] has been called.
+///
+/// Returns an empty profile if the profiler is not recording.
+pub fn snapshot() -> Profile {
+    RECORDING.with(|m| {
+        m.borrow()
+            .as_ref()
+            .map(|m| Profile {
+                components: m.components.values().cloned().collect(),
+                spans: m.spans.clone(),
+            })
+            .unwrap_or_default()
+    })
+}
+
+/// Records a span when dropped, if the profiler is recording.
+pub(crate) struct SpanGuard {
+    kind: SpanKind,
+    component: Option<ComponentId>,
+    start: Option<Duration>,
+}
+
+impl Drop for SpanGuard {
+    fn drop(&mut self) {
+        if let Some(start) = self.start {
+            RECORDING.with(|m| {
+                // A recording started during the span does not record it.
+                if let Some(m) = m.borrow_mut().as_mut().filter(|m| m.started <= start) {
+                    m.record(self.kind, self.component, start);
+                }
+            });
+        }
+    }
+}
+
+#[cfg(feature = "csr")]
+impl SpanGuard {
+    /// Drops the span without recording it.
+    pub(crate) fn discard(mut self) {
+        self.start = None;
+    }
+}
+
+/// Starts a span of `kind` for the component `id` of type `name`.
+#[cfg(any(feature = "ssr", feature = "csr"))]
+pub(crate) fn enter(kind: SpanKind, id: usize, name: &'static str) -> SpanGuard {
+    enter_span(kind, Some(ComponentId { id, name }))
+}
+
+/// Starts a span of the scheduler.
+pub(crate) fn enter_scheduler() -> SpanGuard {
+    enter_span(SpanKind::Scheduler, None)
+}
+
+fn enter_span(kind: SpanKind, component: Option<ComponentId>) -> SpanGuard {
+    SpanGuard {
+        kind,
+        component,
+        start: is_recording().then(now),
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32"), feature = "ssr"))]
+mod ssr_tests {
+    use tokio::test;
+
+    use super::*;
+    use crate::LocalServerRenderer;
+    use crate::prelude::*;
+
+    #[derive(Properties, PartialEq)]
+    struct ItemProps {
+        name: &'static str,
+    }
+
+    #[component]
+    fn Item(props: &ItemProps) -> Html {
+        html! { <li>{props.name}</li> }
+    }
+
+    #[component]
+    fn App() -> Html {
+        html! {
+            <ul>
+                <Item name="a" />
+                <Item name="b" />
+            </ul>
+        }
+    }
+
+    #[test]
+    async fn records_components() {
+        start();
+        LocalServerRenderer::<App>::new()
+            .hydratable(false)
+            .render()
+            .await;
+        let profile = snapshot();
+        stop();
+
+        let types = profile.by_type();
+        let stats = |name: &str| {
+            types
+                .iter()
+                .find(|m| m.name.ends_with(name))
+                .unwrap_or_else(|| panic!("{name} has not been recorded"))
+        };
+        assert_eq!(stats("::App").instances, 1);
+        assert_eq!(stats("::Item").instances, 2);
+        assert_eq!(stats("::Item").stats.created, 2);
+        assert_eq!(stats("::Item").stats.rendered, 2);
+
+        let trace = profile.to_chrome_trace();
+        assert!(trace.starts_with(r#"{"traceEvents":[{"name":"#));
+        assert!(trace.contains(r#""cat":"view","ph":"X""#));
+        assert!(!is_recording());
+        assert_eq!(snapshot(), Profile::default());
+    }
+}
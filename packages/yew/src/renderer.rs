@@ -6,6 +6,8 @@ use web_sys::Element;
 
 use crate::app_handle::AppHandle;
 use crate::html::BaseComponent;
+#[cfg(feature = "hydration")]
+use crate::hydration::MismatchPolicy;
 
 thread_local! {
     static PANIC_HOOK_IS_SET: Cell<bool> = const { Cell::new(false) };
@@ -42,6 +44,9 @@ where
 {
     root: Element,
     props: COMP::Properties,
+    id_prefix: Option<Rc<str>>,
+    #[cfg(feature = "hydration")]
+    mismatch_policy: MismatchPolicy,
 }
 
 impl<COMP> Default for Renderer<COMP>
@@ -85,13 +90,30 @@ where
 
     /// Creates a [Renderer] that renders into a custom root with custom properties.
     pub fn with_root_and_props(root: Element, props: COMP::Properties) -> Self {
-        Self { root, props }
+        Self {
+            root,
+            props,
+            id_prefix: None,
+            #[cfg(feature = "hydration")]
+            mismatch_policy: MismatchPolicy::default(),
+        }
+    }
+
+    /// Sets the prefix of the ids created by [`use_id`](crate::functional::use_id).
+    ///
+    /// Applications sharing a page need distinct prefixes for their ids to be unique. When
+    /// hydrating, the prefix must match the one used by the server renderer.
+    ///
+    /// Defaults to `yew-`.
+    pub fn id_prefix(mut self, prefix: impl Into<Rc<str>>) -> Self {
+        self.id_prefix = Some(prefix.into());
+        self
     }
 
     /// Renders the application.
     pub fn render(self) -> AppHandle<COMP> {
         set_default_panic_hook();
-        AppHandle::<COMP>::mount_with_props(self.root, Rc::new(self.props))
+        AppHandle::<COMP>::mount_with_props(self.root, Rc::new(self.props), self.id_prefix)
     }
 }
 
@@ -103,10 +125,25 @@ mod feat_hydration {
     where
         COMP: BaseComponent + 'static,
     {
+        /// Sets how the application handles server-side rendered content that does not match
+        /// it when hydrating.
+        ///
+        /// Defaults to [`MismatchPolicy::Panic`] in debug builds and [`MismatchPolicy::Warn`] in
+        /// release builds.
+        pub fn hydration_mismatch(mut self, policy: MismatchPolicy) -> Self {
+            self.mismatch_policy = policy;
+            self
+        }
+
         /// Hydrates the application.
         pub fn hydrate(self) -> AppHandle<COMP> {
             set_default_panic_hook();
-            AppHandle::<COMP>::hydrate_with_props(self.root, Rc::new(self.props))
+            AppHandle::<COMP>::hydrate_with_props(
+                self.root,
+                Rc::new(self.props),
+                self.id_prefix,
+                self.mismatch_policy,
+            )
         }
     }
 }
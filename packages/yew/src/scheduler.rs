@@ -1,6 +1,10 @@
 //! This module contains a scheduler.
+//!
+//! Work is scheduled with a [`Priority`]. Renders are processed from the most to the least urgent
+//! priority, and renders with a [deferred](Priority::Transition) priority yield to the browser
+//! after a short time budget, so that they do not block the handling of user input.
 
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::collections::BTreeMap;
 use std::rc::Rc;
 #[cfg(any(test, feature = "test"))]
@@ -41,8 +45,115 @@ pub trait Runnable {
     fn run(self: Box<Self>);
 }
 
+/// The priority of scheduled work, ordered from the most to the least urgent.
+///
+/// Updates are scheduled with the priority that is current when they are sent, see
+/// [`with_priority`]. Renders caused by an update, including the renders of its children, have
+/// the priority of the update.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Priority {
+    /// Discrete user input, such as clicks and key presses.
+    ///
+    /// Updates sent from the listeners of most events have this priority.
+    Discrete,
+    /// The priority of updates sent outside of [`with_priority`], and from the listeners of
+    /// continuous events, such as `mousemove` and `scroll`.
+    #[default]
+    Default,
+    /// Updates which are not needed immediately, such as rendering the results of a search.
+    ///
+    /// Renders with this priority are interrupted to handle user input and are restarted by
+    /// renders with a more urgent priority. Updates sent in
+    /// [`StartTransition::run`](crate::suspense::StartTransition::run) have this priority.
+    Transition,
+    /// Updates which can be rendered once there is nothing else to do.
+    ///
+    /// Renders with this priority are interrupted like transitions.
+    Idle,
+}
+
+impl Priority {
+    const ALL: [Self; 4] = [Self::Discrete, Self::Default, Self::Transition, Self::Idle];
+
+    /// Returns `true` if work with this priority is not interrupted.
+    fn is_urgent(self) -> bool {
+        self < Self::Transition
+    }
+}
+
+thread_local! {
+    static CURRENT_PRIORITY: Cell<Priority> = const { Cell::new(Priority::Default) };
+}
+
+/// Returns the priority work is currently being scheduled with.
+pub fn current_priority() -> Priority {
+    CURRENT_PRIORITY.with(Cell::get)
+}
+
+/// Runs `f` and schedules the work caused by it, such as updates of components, with `priority`.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew::scheduler::{Priority, with_priority};
+///
+/// #[component]
+/// fn Search() -> Html {
+///     let query = use_state(String::new);
+///     let results_query = use_state(String::new);
+///
+///     let oninput = {
+///         let query = query.clone();
+///         let results_query = results_query.clone();
+///         move |e: InputEvent| {
+///             let value = e
+///                 .target_unchecked_into::<web_sys::HtmlInputElement>()
+///                 .value();
+///             query.set(value.clone());
+///             // The results can be rendered after the input has been updated.
+///             with_priority(Priority::Transition, || results_query.set(value));
+///         }
+///     };
+///
+///     html! {
+///         <input value={(*query).clone()} {oninput} />
+///         <p>{"Results for "}{(*results_query).clone()}</p>
+///     }
+/// }
+/// ```
+pub fn with_priority<R>(priority: Priority, f: impl FnOnce() -> R) -> R {
+    /// Restores the previous priority, even if `f` panics.
+    struct Reset(Priority);
+
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            CURRENT_PRIORITY.with(|m| m.set(self.0));
+        }
+    }
+
+    let _reset = Reset(CURRENT_PRIORITY.with(|m| m.replace(priority)));
+    f()
+}
+
 struct QueueEntry {
     task: Box<dyn Runnable>,
+    priority: Priority,
+}
+
+impl QueueEntry {
+    fn new(task: Box<dyn Runnable>) -> Self {
+        Self {
+            task,
+            priority: current_priority(),
+        }
+    }
+
+    /// Runs the task, scheduling the work it causes with its priority.
+    fn run(self) {
+        let Self { task, priority } = self;
+        with_priority(priority, || task.run());
+    }
 }
 
 #[derive(Default)]
@@ -56,7 +167,7 @@ impl FifoQueue {
     }
 
     fn push(&mut self, task: Box<dyn Runnable>) {
-        self.inner.push(QueueEntry { task });
+        self.inner.push(QueueEntry::new(task));
     }
 
     fn drain_into(&mut self, queue: &mut Vec<QueueEntry>) {
@@ -80,7 +191,7 @@ impl TopologicalQueue {
 
     #[cfg(any(feature = "ssr", feature = "csr"))]
     fn push(&mut self, component_id: usize, task: Box<dyn Runnable>) {
-        self.inner.insert(component_id, QueueEntry { task });
+        self.inner.insert(component_id, QueueEntry::new(task));
     }
 
     /// Take a single entry, preferring parents over children
@@ -100,6 +211,83 @@ impl TopologicalQueue {
     }
 }
 
+/// Topological queues of renders, or of the effects that run after them, one for each
+/// [`Priority`].
+#[derive(Default)]
+struct RenderLanes {
+    lanes: [TopologicalQueue; Priority::ALL.len()],
+}
+
+impl RenderLanes {
+    const fn new() -> Self {
+        Self {
+            lanes: [const { TopologicalQueue::new() }; Priority::ALL.len()],
+        }
+    }
+
+    /// Push a render with the current priority
+    ///
+    /// Effects are pushed while their render runs, so they get the priority of the render.
+    #[cfg(any(feature = "ssr", feature = "csr"))]
+    fn push(&mut self, component_id: usize, task: Box<dyn Runnable>) {
+        let lane = current_priority() as usize;
+
+        // Renders use the latest state of the component, so a more urgent render which is
+        // already queued covers this one.
+        if self.lanes[..lane]
+            .iter()
+            .any(|m| m.inner.contains_key(&component_id))
+        {
+            return;
+        }
+        // Less urgent renders are restarted with this priority.
+        for m in &mut self.lanes[lane + 1..] {
+            m.inner.remove(&component_id);
+        }
+        self.lanes[lane].push(component_id, task);
+    }
+
+    /// Take a single entry with `priority`, preferring parents over children
+    fn pop_topmost(&mut self, priority: Priority) -> Option<QueueEntry> {
+        self.lanes[priority as usize].pop_topmost()
+    }
+
+    /// Take a single entry of the most urgent lane that is not interrupted, preferring parents over
+    /// children
+    fn pop_topmost_urgent(&mut self) -> Option<QueueEntry> {
+        Priority::ALL
+            .into_iter()
+            .filter(|m| m.is_urgent())
+            .find_map(|m| self.pop_topmost(m))
+    }
+
+    /// Returns `true` if the first `lanes` lanes are empty.
+    fn is_empty_up_to(&self, lanes: usize) -> bool {
+        self.lanes[..lanes].iter().all(|m| m.inner.is_empty())
+    }
+
+    /// Drain all entries of the first `lanes` lanes, such that children are queued before parents
+    fn drain_post_order_into(&mut self, lanes: usize, queue: &mut Vec<QueueEntry>) {
+        let mut settled = TopologicalQueue::new();
+        for m in &mut self.lanes[..lanes] {
+            settled.inner.append(&mut m.inner);
+        }
+        settled.drain_post_order_into(queue);
+    }
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        not(target_os = "wasi"),
+        not(feature = "not_browser_env")
+    ))]
+    fn is_urgent_empty(&self) -> bool {
+        Priority::ALL
+            .into_iter()
+            .filter(|m| m.is_urgent())
+            .all(|m| self.lanes[m as usize].inner.is_empty())
+    }
+}
+
 /// This is a global scheduler suitable to schedule and run any tasks.
 #[derive(Default)]
 struct Scheduler {
@@ -113,12 +301,24 @@ struct Scheduler {
     props_update: FifoQueue,
     update: FifoQueue,
 
-    render: TopologicalQueue,
-    render_first: TopologicalQueue,
+    render: RenderLanes,
+    render_first: RenderLanes,
     render_priority: TopologicalQueue,
 
-    rendered_first: TopologicalQueue,
-    rendered: TopologicalQueue,
+    layout: RenderLanes,
+    rendered_first: RenderLanes,
+    rendered: RenderLanes,
+
+    /// Whether layout effects have run and the updates they caused have not been rendered yet.
+    ///
+    /// The scheduler does not yield to the browser until then, so that the browser never paints
+    /// the layout before it has been adjusted by the layout effects.
+    #[cfg(all(
+        target_arch = "wasm32",
+        not(target_os = "wasi"),
+        not(feature = "not_browser_env")
+    ))]
+    layout_pending: bool,
 }
 
 impl Scheduler {
@@ -129,11 +329,18 @@ impl Scheduler {
             create: FifoQueue::new(),
             props_update: FifoQueue::new(),
             update: FifoQueue::new(),
-            render: TopologicalQueue::new(),
-            render_first: TopologicalQueue::new(),
+            render: RenderLanes::new(),
+            render_first: RenderLanes::new(),
             render_priority: TopologicalQueue::new(),
-            rendered_first: TopologicalQueue::new(),
-            rendered: TopologicalQueue::new(),
+            layout: RenderLanes::new(),
+            rendered_first: RenderLanes::new(),
+            rendered: RenderLanes::new(),
+            #[cfg(all(
+                target_arch = "wasm32",
+                not(target_os = "wasi"),
+                not(feature = "not_browser_env")
+            ))]
+            layout_pending: false,
         }
     }
 }
@@ -214,6 +421,11 @@ mod feat_csr {
         });
     }
 
+    /// Push a component layout [Runnable] to be executed right after its layout is rendered
+    pub(crate) fn push_component_layout(component_id: usize, layout: Box<dyn Runnable>) {
+        with(|s| s.layout.push(component_id, layout));
+    }
+
     pub(crate) fn push_component_props_update(props_update: Box<dyn Runnable>) {
         with(|s| s.props_update.push(props_update));
     }
@@ -236,17 +448,181 @@ mod feat_hydration {
 #[cfg(feature = "hydration")]
 pub(crate) use feat_hydration::*;
 
+thread_local! {
+    static RUNNING: Cell<bool> = const { Cell::new(false) };
+    static RENDERING: Cell<bool> = const { Cell::new(false) };
+    static BATCH_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Marks the scheduler as running [Runnable]s until dropped.
+struct Running(());
+
+impl Running {
+    /// Returns `None` if the scheduler is already running, because it is being called
+    /// recursively as part of a `runnable.run()`.
+    fn enter() -> Option<Self> {
+        RUNNING.with(|m| !m.replace(true)).then_some(Self(()))
+    }
+}
+
+impl Drop for Running {
+    fn drop(&mut self) {
+        RUNNING.with(|m| m.set(false));
+    }
+}
+
+/// Returns `true` if the scheduler is running [Runnable]s, such as lifecycle events.
+fn is_running() -> bool {
+    RUNNING.with(Cell::get)
+}
+
+/// Runs `f`, which renders a component.
+#[cfg(any(feature = "ssr", feature = "csr"))]
+pub(crate) fn rendering<R>(f: impl FnOnce() -> R) -> R {
+    /// Restores the previous state, even if `f` panics.
+    struct Reset(bool);
+
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            RENDERING.with(|m| m.set(self.0));
+        }
+    }
+
+    let _reset = Reset(RENDERING.with(|m| m.replace(true)));
+    f()
+}
+
+#[track_caller]
+fn assert_not_rendering(name: &str) {
+    assert!(
+        !RENDERING.with(Cell::get),
+        "`{name}` must not be called while a component is rendering, rendering must be free of \
+         side effects"
+    );
+}
+
+fn is_batching() -> bool {
+    BATCH_DEPTH.with(Cell::get) > 0
+}
+
+/// Runs `f` and defers all renders until it returns.
+///
+/// This can be used to render several updates sent from outside of Yew, such as from a
+/// third-party library callback, at once. Batches can be nested, renders are deferred until the
+/// outermost batch returns.
+///
+/// # Panics
+///
+/// Panics if called while a component is rendering.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew::scheduler::batch;
+///
+/// fn on_message(name: UseStateHandle<String>, unread: UseStateHandle<u32>) {
+///     // The component is rendered once, with both updates.
+///     batch(|| {
+///         name.set("yew".to_owned());
+///         unread.set(*unread + 1);
+///     });
+/// }
+/// ```
+#[track_caller]
+pub fn batch<R>(f: impl FnOnce() -> R) -> R {
+    /// Ends the batch, even if `f` panics.
+    struct Batch;
+
+    impl Drop for Batch {
+        fn drop(&mut self) {
+            let depth = BATCH_DEPTH.with(|m| {
+                m.set(m.get() - 1);
+                m.get()
+            });
+            // Work is not run while unwinding, it is started the next time work is scheduled.
+            if depth == 0 && !std::thread::panicking() {
+                start();
+            }
+        }
+    }
+
+    assert_not_rendering("batch");
+
+    BATCH_DEPTH.with(|m| m.set(m.get() + 1));
+    let _batch = Batch;
+    f()
+}
+
+/// Runs `f`, then applies all pending updates and reconciles the DOM before returning.
+///
+/// This can be used to read the layout of the DOM right after a state change. Renders are not
+/// interrupted, including the renders with a [deferred](Priority::Transition) priority.
+///
+/// When called while the scheduler is running, for example in an effect or in a callback emitted
+/// by one, the updates cannot be applied synchronously. They are applied by the running
+/// scheduler right after the current lifecycle event instead.
+///
+/// # Panics
+///
+/// Panics if called while a component is rendering.
+///
+/// # Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew::scheduler::flush_sync;
+///
+/// #[component]
+/// fn Chat() -> Html {
+///     let messages = use_state(Vec::<String>::new);
+///     let list_ref = use_node_ref();
+///
+///     let onclick = {
+///         let messages = messages.clone();
+///         let list_ref = list_ref.clone();
+///         move |_| {
+///             flush_sync(|| {
+///                 let mut next = (*messages).clone();
+///                 next.push("Hello".to_owned());
+///                 messages.set(next);
+///             });
+///             // The new message has been rendered.
+///             if let Some(list) = list_ref.cast::<web_sys::Element>() {
+///                 list.set_scroll_top(list.scroll_height());
+///             }
+///         }
+///     };
+///
+///     html! {
+///         <ul ref={list_ref}>{ for messages.iter().map(|m| html! { <li>{m}</li> }) }</ul>
+///         <button {onclick}>{"Send"}</button>
+///     }
+/// }
+/// ```
+#[track_caller]
+pub fn flush_sync<R>(f: impl FnOnce() -> R) -> R {
+    assert_not_rendering("flush_sync");
+
+    let result = batch(f);
+    if is_running() {
+        tracing::warn!(
+            "flush_sync called while the scheduler is running, updates are applied after the \
+             current lifecycle event"
+        );
+    } else {
+        start_now();
+    }
+
+    result
+}
+
 /// Execute any pending [Runnable]s
-#[cfg(any(
-    not(target_arch = "wasm32"),
-    target_os = "wasi",
-    feature = "not_browser_env",
-    test,
-    feature = "test"
-))]
 pub(crate) fn start_now() {
     #[tracing::instrument(level = tracing::Level::DEBUG)]
     fn scheduler_loop() {
+        #[cfg(feature = "profiling")]
+        let _span = crate::profiler::enter_scheduler();
         let mut queue = vec![];
         loop {
             with(|s| s.fill_queue(&mut queue));
@@ -254,24 +630,16 @@ pub(crate) fn start_now() {
                 break;
             }
             for r in queue.drain(..) {
-                r.task.run();
+                r.run();
             }
         }
     }
 
-    thread_local! {
-        // The lock is used to prevent recursion. If the lock cannot be acquired, it is because the
-        // `start()` method is being called recursively as part of a `runnable.run()`.
-        static LOCK: RefCell<()> = const { RefCell::new(()) };
+    if let Some(_running) = Running::enter() {
+        scheduler_loop();
+        #[cfg(any(test, feature = "test"))]
+        flush_wakers::wake_all();
     }
-
-    LOCK.with(|l| {
-        if let Ok(_lock) = l.try_borrow_mut() {
-            scheduler_loop();
-            #[cfg(any(test, feature = "test"))]
-            flush_wakers::wake_all();
-        }
-    });
 }
 
 #[cfg(all(
@@ -304,6 +672,8 @@ mod arch {
     }
 
     const YIELD_DEADLINE_MS: f64 = 16.0;
+    /// Deferred renders yield sooner, so that user input is handled without a noticeable delay.
+    const DEFERRED_YIELD_DEADLINE_MS: f64 = 5.0;
 
     #[wasm_bindgen]
     unsafe extern "C" {
@@ -312,19 +682,30 @@ mod arch {
     }
 
     fn run_scheduler(mut queue: Vec<super::QueueEntry>) {
-        let deadline = js_sys::Date::now() + YIELD_DEADLINE_MS;
+        #[cfg(feature = "profiling")]
+        let _span = crate::profiler::enter_scheduler();
+        // Never already running, the scheduler is only run synchronously by `start_now()`.
+        let _running = super::Running::enter();
+        let start = js_sys::Date::now();
 
         loop {
             super::with(|s| s.fill_queue(&mut queue));
             if queue.is_empty() {
                 break;
             }
+            let deferred = queue.iter().all(|r| !r.priority.is_urgent());
             for r in queue.drain(..) {
-                r.task.run();
+                r.run();
             }
-            if js_sys::Date::now() >= deadline {
-                // Only yield when no DOM-mutating work is pending, so event
-                // handlers that fire during the yield see a consistent DOM.
+            let deadline = if deferred {
+                DEFERRED_YIELD_DEADLINE_MS
+            } else {
+                YIELD_DEADLINE_MS
+            };
+            if js_sys::Date::now() - start >= deadline {
+                // Only yield when no urgent DOM-mutating work is pending, so event
+                // handlers that fire during the yield see a consistent DOM. Urgent work
+                // scheduled by them is processed before the pending deferred renders.
                 let can_yield = super::with(|s| s.can_yield());
                 if can_yield {
                     let cb = Closure::once_into_js(move || run_scheduler(queue));
@@ -341,10 +722,10 @@ mod arch {
 
     /// We delay the start of the scheduler to the end of the micro task queue.
     /// So any messages that needs to be queued can be queued.
-    /// Once running, we yield to the browser every ~16ms, but only at points
-    /// where the DOM is in a consistent state (no pending renders/destroys).
+    /// Once running, we yield to the browser every ~16ms, or every ~5ms while rendering deferred
+    /// work, but only at points where no urgent renders or destroys are pending.
     pub(crate) fn start() {
-        if check_scheduled() {
+        if check_scheduled() || super::is_batching() {
             return;
         }
         set_scheduled(true);
@@ -366,7 +747,9 @@ mod arch {
     // Until scheduler is future-capable which means we can join inside a future,
     // it can remain synchronous.
     pub(crate) fn start() {
-        super::start_now();
+        if !super::is_batching() {
+            super::start_now();
+        }
     }
 }
 
@@ -418,8 +801,11 @@ pub async fn flush() {
 }
 
 impl Scheduler {
-    /// Returns true when no DOM-mutating work is pending, meaning it's safe to
+    /// Returns true when no urgent DOM-mutating work is pending, meaning it's safe to
     /// yield to the browser without leaving the DOM in an inconsistent state.
+    ///
+    /// Deferred renders may be pending, components rendered with a deferred priority might not
+    /// have rendered their children yet.
     #[cfg(all(
         target_arch = "wasm32",
         not(target_os = "wasi"),
@@ -428,9 +814,27 @@ impl Scheduler {
     fn can_yield(&self) -> bool {
         self.destroy.inner.is_empty()
             && self.create.inner.is_empty()
-            && self.render_first.inner.is_empty()
-            && self.render.inner.is_empty()
+            && self.render_first.is_urgent_empty()
+            && self.render.is_urgent_empty()
             && self.render_priority.inner.is_empty()
+            && self.layout.is_urgent_empty()
+            && !self.layout_pending
+    }
+
+    /// Returns the number of lanes, from the most urgent one, whose effects can run.
+    ///
+    /// The effects of a deferred render are held until all renders with the same or a more urgent
+    /// priority have finished, so that they see the DOM of the children rendered by it.
+    fn settled_lanes(&self) -> usize {
+        Priority::ALL
+            .into_iter()
+            .take_while(|&m| {
+                m.is_urgent() || {
+                    let lane = m as usize + 1;
+                    self.render.is_empty_up_to(lane) && self.render_first.is_empty_up_to(lane)
+                }
+            })
+            .count()
     }
 
     /// Fill vector with tasks to be executed according to Runnable type execution priority
@@ -457,7 +861,7 @@ impl Scheduler {
         //
         // Should be processed one at time, because they can spawn more create and rendered events
         // for their children.
-        if let Some(r) = self.render_first.pop_topmost() {
+        if let Some(r) = self.render_first.pop_topmost_urgent() {
             to_run.push(r);
             return;
         }
@@ -473,7 +877,12 @@ impl Scheduler {
         }
 
         // Children rendered lifecycle happen before parents.
-        self.rendered_first.drain_post_order_into(to_run);
+        //
+        // Effects of the first render run after the layout effects of the same render.
+        let settled = self.settled_lanes();
+        if self.layout.is_empty_up_to(settled) {
+            self.rendered_first.drain_post_order_into(settled, to_run);
+        }
 
         // Updates are after the first render to ensure we always have the entire child tree
         // rendered, once an update is processed.
@@ -494,14 +903,59 @@ impl Scheduler {
 
         // Should be processed one at time, because they can spawn more create and rendered events
         // for their children.
-        if let Some(r) = self.render.pop_topmost() {
+        if let Some(r) = self.render.pop_topmost_urgent() {
             to_run.push(r);
             return;
         }
+
+        // Layout effects run once all urgent renders have finished and the DOM is up to date,
+        // before the browser gets a chance to paint the layout. Children layout lifecycle happen
+        // before parents.
+        let settled = self.settled_lanes();
+        if !self.layout.is_empty_up_to(settled) {
+            #[cfg(all(
+                target_arch = "wasm32",
+                not(target_os = "wasi"),
+                not(feature = "not_browser_env")
+            ))]
+            {
+                self.layout_pending = true;
+            }
+            self.layout.drain_post_order_into(settled, to_run);
+            return;
+        }
+
+        // All updates caused by layout effects have been rendered.
+        #[cfg(all(
+            target_arch = "wasm32",
+            not(target_os = "wasi"),
+            not(feature = "not_browser_env")
+        ))]
+        {
+            self.layout_pending = false;
+        }
+
         // These typically do nothing and don't spawn any other events - can be batched.
         // Should be run only after all renders have finished.
         // Children rendered lifecycle happen before parents.
-        self.rendered.drain_post_order_into(to_run);
+        self.rendered.drain_post_order_into(settled, to_run);
+        if !to_run.is_empty() {
+            return;
+        }
+
+        // Deferred renders are processed once there is no urgent work left, one at a time, so
+        // that they can be interrupted.
+        let deferred = Priority::ALL.into_iter().filter(|m| !m.is_urgent());
+        for priority in deferred {
+            if let Some(r) = self
+                .render_first
+                .pop_topmost(priority)
+                .or_else(|| self.render.pop_topmost(priority))
+            {
+                to_run.push(r);
+                return;
+            }
+        }
     }
 }
 
@@ -527,4 +981,135 @@ mod tests {
         push(Box::new(Test));
         FLAG.with(|v| assert!(v.get()));
     }
+
+    #[test]
+    fn batch_defers_runnables() {
+        thread_local! {
+            static RUNS: Cell<u32> = const { Cell::new(0) };
+        }
+
+        struct Test;
+        impl Runnable for Test {
+            fn run(self: Box<Self>) {
+                RUNS.with(|m| m.set(m.get() + 1));
+            }
+        }
+
+        batch(|| {
+            push(Box::new(Test));
+            batch(|| push(Box::new(Test)));
+            assert_eq!(RUNS.with(Cell::get), 0);
+        });
+        assert_eq!(RUNS.with(Cell::get), 2);
+
+        batch(|| {
+            flush_sync(|| push(Box::new(Test)));
+            assert_eq!(RUNS.with(Cell::get), 3);
+        });
+    }
+
+    #[cfg(any(feature = "ssr", feature = "csr"))]
+    #[test]
+    #[should_panic(expected = "`flush_sync` must not be called while a component is rendering")]
+    fn flush_sync_panics_during_render() {
+        rendering(|| flush_sync(|| ()));
+    }
+
+    #[cfg(any(feature = "ssr", feature = "csr"))]
+    #[test]
+    fn renders_by_priority() {
+        thread_local! {
+            static RENDERED: RefCell<Vec<(usize, Priority)>> = const { RefCell::new(Vec::new()) };
+        }
+
+        struct Render(usize);
+        impl Runnable for Render {
+            fn run(self: Box<Self>) {
+                RENDERED.with(|m| m.borrow_mut().push((self.0, current_priority())));
+            }
+        }
+
+        let mut scheduler = Scheduler::new();
+        let mut push = |id, priority| {
+            with_priority(priority, || scheduler.render.push(id, Box::new(Render(id))));
+        };
+        push(1, Priority::Idle);
+        push(2, Priority::Transition);
+        push(3, Priority::Default);
+        push(4, Priority::Discrete);
+        // Restarted with a more urgent priority.
+        push(2, Priority::Default);
+        // Covered by the render with a more urgent priority.
+        push(4, Priority::Idle);
+
+        let mut queue = vec![];
+        loop {
+            scheduler.fill_queue(&mut queue);
+            if queue.is_empty() {
+                break;
+            }
+            for r in queue.drain(..) {
+                r.run();
+            }
+        }
+
+        assert_eq!(
+            RENDERED.with(|m| m.take()),
+            [
+                (4, Priority::Discrete),
+                (2, Priority::Default),
+                (3, Priority::Default),
+                (1, Priority::Idle),
+            ]
+        );
+        assert_eq!(current_priority(), Priority::Default);
+    }
+
+    #[cfg(any(feature = "ssr", feature = "csr"))]
+    #[test]
+    fn holds_effects_of_deferred_renders() {
+        thread_local! {
+            static RAN: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+        }
+
+        struct Run(&'static str);
+        impl Runnable for Run {
+            fn run(self: Box<Self>) {
+                RAN.with(|m| m.borrow_mut().push(self.0));
+            }
+        }
+
+        let mut scheduler = Scheduler::new();
+        with_priority(Priority::Transition, || {
+            scheduler.rendered.push(1, Box::new(Run("parent rendered")));
+            scheduler.layout.push(1, Box::new(Run("parent layout")));
+            scheduler.render.push(2, Box::new(Run("child render")));
+        });
+        with_priority(Priority::Idle, || {
+            scheduler.render.push(3, Box::new(Run("idle render")));
+        });
+        scheduler.rendered.push(4, Box::new(Run("urgent rendered")));
+
+        let mut queue = vec![];
+        loop {
+            scheduler.fill_queue(&mut queue);
+            if queue.is_empty() {
+                break;
+            }
+            for r in queue.drain(..) {
+                r.run();
+            }
+        }
+
+        assert_eq!(
+            RAN.with(|m| m.take()),
+            [
+                "urgent rendered",
+                "child render",
+                "parent layout",
+                "parent rendered",
+                "idle render",
+            ]
+        );
+    }
 }
@@ -1,15 +1,27 @@
 use std::fmt;
+use std::rc::Rc;
 
 use futures::pin_mut;
 use futures::stream::{Stream, StreamExt};
 use tracing::Instrument;
 
+use crate::feat_ssr::DeferredBoundaries;
 use crate::html::{BaseComponent, Scope};
 use crate::platform::fmt::BufStream;
 use crate::platform::{LocalHandle, Runtime};
 
 #[cfg(feature = "ssr")]
 pub(crate) mod feat_ssr {
+    use std::cell::{Cell, RefCell};
+    use std::fmt::Write;
+    use std::pin::pin;
+    use std::rc::Rc;
+
+    use futures::future::{self, FutureExt};
+    use futures::stream::{self, LocalBoxStream, StreamExt};
+
+    use crate::platform::fmt::BufWriter;
+
     /// Passed top-down as context for `render_into_stream` functions to know the current innermost
     /// `VTag` kind to apply appropriate text escaping.
     /// Right now this is used to make `VText` nodes aware of their environment and correctly
@@ -37,6 +49,195 @@ pub(crate) mod feat_ssr {
             }
         }
     }
+
+    thread_local! {
+        static CURRENT_DEFERRED: RefCell<Option<Rc<DeferredBoundaries>>> =
+            const { RefCell::new(None) };
+    }
+
+    /// Defines `__yew_swap`, which replaces the content between the markers of a deferred part
+    /// of the document with the content of its template, see [`SwapMode`] for the markers.
+    ///
+    /// Without a template, the content is kept and only the markers are handled. If the markers
+    /// cannot be found, the part has already been rendered by the client and the content is
+    /// discarded.
+    const SWAP_SCRIPT: &str = concat!(
+        "<script>function __yew_swap(i,k){",
+        "var t=document.getElementById(\"yew-s-\"+i),s=document.currentScript,",
+        "w=document.createTreeWalker(document,128),o=null,n,d=1;",
+        "if(s)s.remove();",
+        "while(w.nextNode())if(w.currentNode.data===\"<?\"+i+\">\"){o=w.currentNode;break}",
+        "if(o){n=o.nextSibling;",
+        "while(n){var x=n.nextSibling;",
+        "if(n.nodeType===8){if(/^<\\?.*>$/.test(n.data))d++;",
+        "else if(n.data===\"</?>\"&&--d===0)break}",
+        "if(t)n.remove();n=x}",
+        "if(t)n.parentNode.insertBefore(t.content,n);",
+        "if(k===1)o.data=\"<?>\";else if(k!==2){o.remove();n.remove()}}",
+        "if(t)t.remove()}</script>",
+    );
+
+    /// What `__yew_swap` does with the markers of a deferred part of the document.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub(crate) enum SwapMode {
+        /// The markers are removed.
+        Unwrap = 0,
+        /// The markers are kept as the markers of a suspense boundary for the hydration.
+        Hydrate = 1,
+        /// The markers are kept, so the part can be swapped again.
+        Keep = 2,
+    }
+
+    impl SwapMode {
+        /// The mode which swaps in the resolved content of a suspense boundary.
+        pub(crate) fn content(hydratable: bool) -> Self {
+            if hydratable {
+                Self::Hydrate
+            } else {
+                Self::Unwrap
+            }
+        }
+    }
+
+    /// Returns the script which swaps `html` into the deferred part of the document marked with
+    /// `id`.
+    ///
+    /// Without `html`, the current content of the part is kept.
+    pub(crate) fn swap(id: usize, html: Option<&str>, mode: SwapMode) -> String {
+        let mut s = String::new();
+        if let Some(html) = html {
+            let _ = write!(s, r#"<template id="yew-s-{id}">{html}</template>"#);
+        }
+        let _ = write!(s, "<script>__yew_swap({id},{})</script>", mode as u8);
+
+        s
+    }
+
+    /// Streams `swap` followed by the swaps of the deferred parts nested in the swapped content,
+    /// which can only be swapped in once their markers are in place.
+    pub(crate) fn swap_with_nested(swap: String, nested: Vec<Swaps>) -> Swaps {
+        stream::once(future::ready(swap))
+            .chain(stream::select_all(nested))
+            .boxed_local()
+    }
+
+    /// Swaps streamed after the shell of an out-of-order render.
+    pub(crate) type Swaps = LocalBoxStream<'static, String>;
+
+    /// Collects the parts of the document deferred by an out-of-order render, such as suspense
+    /// boundaries.
+    ///
+    /// The collector is current while the render it belongs to is polled, so that boundaries
+    /// further down the tree can stream their fallback instead of blocking the stream.
+    #[derive(Default)]
+    pub(crate) struct DeferredBoundaries {
+        next_id: Rc<Cell<usize>>,
+        pending: RefCell<Vec<Swaps>>,
+    }
+
+    impl DeferredBoundaries {
+        /// Returns the collector of the out-of-order render currently being polled, if any.
+        pub(crate) fn current() -> Option<Rc<Self>> {
+            CURRENT_DEFERRED.with(|m| m.borrow().clone())
+        }
+
+        /// Creates a collector for the content of a boundary.
+        ///
+        /// Boundaries nested in a deferred boundary can only be swapped in once their parent has
+        /// been swapped in, so they are collected separately.
+        pub(crate) fn nested(&self) -> Rc<Self> {
+            Rc::new(Self {
+                next_id: self.next_id.clone(),
+                pending: RefCell::default(),
+            })
+        }
+
+        /// Polls `f` with this collector being current.
+        pub(crate) async fn run<F>(self: Rc<Self>, f: F) -> F::Output
+        where
+            F: Future,
+        {
+            let mut f = pin!(f);
+
+            future::poll_fn(|cx| {
+                let prev = CURRENT_DEFERRED.with(|m| m.replace(Some(self.clone())));
+                let output = f.as_mut().poll(cx);
+                CURRENT_DEFERRED.with(|m| *m.borrow_mut() = prev);
+
+                output
+            })
+            .await
+        }
+
+        /// Returns a new id to mark a deferred part of the document with.
+        pub(crate) fn next_id(&self) -> usize {
+            let id = self.next_id.get();
+            self.next_id.set(id + 1);
+
+            id
+        }
+
+        /// Returns whether nothing has been deferred.
+        pub(crate) fn is_empty(&self) -> bool {
+            self.pending.borrow().is_empty()
+        }
+
+        /// Streams `swaps` once the shell has been written.
+        pub(crate) fn push(&self, swaps: Swaps) {
+            self.pending.borrow_mut().push(swaps);
+        }
+
+        /// Takes the swaps collected so far.
+        pub(crate) fn take(&self) -> Vec<Swaps> {
+            self.pending.take()
+        }
+
+        /// Moves the swaps collected by `other` into this collector.
+        pub(crate) fn append(&self, other: &Self) {
+            let mut other = other.pending.take();
+            self.pending.borrow_mut().append(&mut other);
+        }
+
+        /// Defers a boundary whose content is rendered by `render`.
+        ///
+        /// Returns the id of the boundary.
+        pub(crate) fn defer<F>(&self, render: F, nested: Rc<Self>, hydratable: bool) -> usize
+        where
+            F: 'static + Future<Output = String>,
+        {
+            let id = self.next_id();
+
+            self.push(
+                async move {
+                    let html = render.await;
+                    let swap = swap(id, Some(&html), SwapMode::content(hydratable));
+
+                    swap_with_nested(swap, nested.take())
+                }
+                .into_stream()
+                .flatten()
+                .boxed_local(),
+            );
+
+            id
+        }
+
+        /// Streams the deferred parts in the order they resolve.
+        pub(crate) async fn write_into(&self, w: &mut BufWriter) {
+            let pending = self.pending.take();
+
+            if pending.is_empty() {
+                return;
+            }
+
+            let _ = w.write_str(SWAP_SCRIPT);
+
+            let mut swaps = stream::select_all(pending);
+            while let Some(m) = swaps.next().await {
+                let _ = w.write_str(&m);
+            }
+        }
+    }
 }
 
 /// A Yew Server-side Renderer that renders on the current thread.
@@ -56,6 +257,8 @@ where
 {
     props: COMP::Properties,
     hydratable: bool,
+    out_of_order: bool,
+    id_prefix: Option<Rc<str>>,
 }
 
 impl<COMP> Default for LocalServerRenderer<COMP>
@@ -86,6 +289,8 @@ where
         Self {
             props,
             hydratable: true,
+            out_of_order: false,
+            id_prefix: None,
         }
     }
 
@@ -101,6 +306,33 @@ where
         self
     }
 
+    /// Sets whether suspended content is streamed out of order.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// When this is set to `true`, the fallback of a [`Suspense`](crate::suspense::Suspense)
+    /// whose children are suspended is streamed immediately and the rest of the application
+    /// continues to render. The children are appended to the end of the stream once they have
+    /// been resolved, together with an inline script that swaps them into place.
+    pub fn out_of_order(mut self, val: bool) -> Self {
+        self.out_of_order = val;
+
+        self
+    }
+
+    /// Sets the prefix of the ids created by [`use_id`](crate::functional::use_id).
+    ///
+    /// Applications sharing a page need distinct prefixes for their ids to be unique. The
+    /// prefix must match the one set on the [`Renderer`](crate::Renderer) hydrating the
+    /// application.
+    ///
+    /// Defaults to `yew-`.
+    pub fn id_prefix(mut self, prefix: impl Into<Rc<str>>) -> Self {
+        self.id_prefix = Some(prefix.into());
+
+        self
+    }
+
     /// Renders Yew Application.
     pub async fn render(self) -> String {
         let s = self.render_stream();
@@ -120,21 +352,28 @@ where
     }
 
     fn render_stream_inner(self) -> impl Stream<Item = String> {
-        let scope = Scope::<COMP>::new(None);
+        let scope = Scope::<COMP>::new(None).with_id_prefix(self.id_prefix.clone());
 
         let outer_span = tracing::Span::current();
         BufStream::new(move |mut w| async move {
             let render_span = tracing::debug_span!("render_stream_item");
             render_span.follows_from(outer_span);
-            scope
+            let render = scope
                 .render_into_stream(
                     &mut w,
                     self.props.into(),
                     self.hydratable,
                     Default::default(),
                 )
-                .instrument(render_span)
-                .await;
+                .instrument(render_span);
+
+            if self.out_of_order {
+                let deferred = Rc::new(DeferredBoundaries::default());
+                deferred.clone().run(render).await;
+                deferred.write_into(&mut w).await;
+            } else {
+                render.await;
+            }
         })
     }
 
@@ -167,6 +406,8 @@ where
 {
     create_props: Box<dyn Send + FnOnce() -> COMP::Properties>,
     hydratable: bool,
+    out_of_order: bool,
+    id_prefix: Option<String>,
     rt: Option<Runtime>,
 }
 
@@ -215,6 +456,8 @@ where
         Self {
             create_props: Box::new(create_props),
             hydratable: true,
+            out_of_order: false,
+            id_prefix: None,
             rt: None,
         }
     }
@@ -238,21 +481,53 @@ where
         self
     }
 
+    /// Sets whether suspended content is streamed out of order.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// When this is set to `true`, the fallback of a [`Suspense`](crate::suspense::Suspense)
+    /// whose children are suspended is streamed immediately and the rest of the application
+    /// continues to render. The children are appended to the end of the stream once they have
+    /// been resolved, together with an inline script that swaps them into place.
+    pub fn out_of_order(mut self, val: bool) -> Self {
+        self.out_of_order = val;
+
+        self
+    }
+
+    /// Sets the prefix of the ids created by [`use_id`](crate::functional::use_id).
+    ///
+    /// Applications sharing a page need distinct prefixes for their ids to be unique. The
+    /// prefix must match the one set on the [`Renderer`](crate::Renderer) hydrating the
+    /// application.
+    ///
+    /// Defaults to `yew-`.
+    pub fn id_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.id_prefix = Some(prefix.into());
+
+        self
+    }
+
     /// Renders Yew Application.
     pub async fn render(self) -> String {
         let Self {
             create_props,
             hydratable,
+            out_of_order,
+            id_prefix,
             rt,
         } = self;
 
         let (tx, rx) = futures::channel::oneshot::channel();
         let create_task = move || async move {
             let props = create_props();
-            let s = LocalServerRenderer::<COMP>::with_props(props)
+            let mut renderer = LocalServerRenderer::<COMP>::with_props(props)
                 .hydratable(hydratable)
-                .render()
-                .await;
+                .out_of_order(out_of_order);
+            if let Some(m) = id_prefix {
+                renderer = renderer.id_prefix(m);
+            }
+            let s = renderer.render().await;
 
             let _ = tx.send(s);
         };
@@ -294,15 +569,21 @@ where
         let Self {
             create_props,
             hydratable,
+            out_of_order,
+            id_prefix,
             rt,
         } = self;
 
         let (tx, rx) = futures::channel::mpsc::unbounded();
         let create_task = move || async move {
             let props = create_props();
-            let s = LocalServerRenderer::<COMP>::with_props(props)
+            let mut renderer = LocalServerRenderer::<COMP>::with_props(props)
                 .hydratable(hydratable)
-                .render_stream();
+                .out_of_order(out_of_order);
+            if let Some(m) = id_prefix {
+                renderer = renderer.id_prefix(m);
+            }
+            let s = renderer.render_stream();
             pin_mut!(s);
 
             while let Some(m) = s.next().await {
@@ -0,0 +1,174 @@
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::fmt;
+use std::rc::Rc;
+
+use slab::Slab;
+
+use super::{Middleware, Next, Store};
+use crate::callback::Callback;
+
+struct Inner<S: Store> {
+    state: RefCell<Rc<S>>,
+    subscribers: RefCell<Slab<Callback<Rc<S>>>>,
+    middleware: RefCell<Vec<Rc<dyn Middleware<S>>>>,
+
+    dispatching: Cell<bool>,
+    pending: RefCell<VecDeque<S::Action>>,
+}
+
+/// Marks a store as no longer dispatching when dropped, even if a middleware or a subscriber
+/// panicked.
+struct DispatchingGuard<'a>(&'a Cell<bool>);
+
+impl Drop for DispatchingGuard<'_> {
+    fn drop(&mut self) {
+        self.0.set(false);
+    }
+}
+
+/// A handle to a [`Store`].
+///
+/// The handle dispatches actions to the store and can be used outside of components. Clones of
+/// the handle refer to the same store.
+pub struct Dispatch<S: Store> {
+    inner: Rc<Inner<S>>,
+}
+
+impl<S: Store> Dispatch<S> {
+    /// Creates a store with an initial state.
+    pub fn new(state: S) -> Self {
+        Self {
+            inner: Rc::new(Inner {
+                state: RefCell::new(Rc::new(state)),
+                subscribers: RefCell::default(),
+                middleware: RefCell::default(),
+                dispatching: Cell::new(false),
+                pending: RefCell::default(),
+            }),
+        }
+    }
+
+    /// Adds a middleware to the store.
+    ///
+    /// Actions are passed to the middleware in the order they have been added, the last
+    /// middleware passes them to the store.
+    pub fn with_middleware(self, middleware: impl Middleware<S>) -> Self {
+        self.inner.middleware.borrow_mut().push(Rc::new(middleware));
+        self
+    }
+
+    /// Returns the current state of the store.
+    pub fn get(&self) -> Rc<S> {
+        self.inner.state.borrow().clone()
+    }
+
+    /// Dispatches an action to the store.
+    ///
+    /// Actions dispatched while another action is being handled, for example by a middleware or a
+    /// subscriber, are handled once it has completed.
+    pub fn dispatch(&self, action: S::Action) {
+        self.inner.pending.borrow_mut().push_back(action);
+
+        if self.inner.dispatching.replace(true) {
+            return;
+        }
+        let _guard = DispatchingGuard(&self.inner.dispatching);
+
+        loop {
+            let action = self.inner.pending.borrow_mut().pop_front();
+            match action {
+                Some(m) => self.reduce(m),
+                None => break,
+            }
+        }
+    }
+
+    fn reduce(&self, action: S::Action) {
+        let state = self.get();
+        let middleware: Rc<[_]> = self.inner.middleware.borrow().clone().into();
+        let next_state = Next::new(middleware).run(state.clone(), action);
+
+        if Rc::ptr_eq(&state, &next_state) {
+            return;
+        }
+
+        *self.inner.state.borrow_mut() = next_state.clone();
+
+        let subscribers: Vec<Callback<Rc<S>>> = self
+            .inner
+            .subscribers
+            .borrow()
+            .iter()
+            .map(|(_, m)| m.clone())
+            .collect();
+        for subscriber in subscribers {
+            subscriber.emit(next_state.clone());
+        }
+    }
+
+    /// Creates a [`Callback`] which dispatches the action returned by `f`.
+    pub fn callback<IN, F>(&self, f: F) -> Callback<IN>
+    where
+        F: Fn(IN) -> S::Action + 'static,
+    {
+        let dispatch = self.clone();
+        Callback::from(move |m| dispatch.dispatch(f(m)))
+    }
+
+    /// Subscribes to the changes of the store.
+    ///
+    /// The callback is called with the new state whenever the state changes, until the returned
+    /// subscription is dropped.
+    pub fn subscribe(&self, callback: Callback<Rc<S>>) -> StoreSubscription<S> {
+        let key = self.inner.subscribers.borrow_mut().insert(callback);
+
+        StoreSubscription {
+            dispatch: self.clone(),
+            key,
+        }
+    }
+}
+
+impl<S: Store> Clone for Dispatch<S> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<S: Store> PartialEq for Dispatch<S> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl<S: Store> fmt::Debug for Dispatch<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Dispatch<_>").finish_non_exhaustive()
+    }
+}
+
+/// Owns a subscription to a [`Store`]. When dropped, the callback is no longer notified.
+pub struct StoreSubscription<S: Store> {
+    dispatch: Dispatch<S>,
+    key: usize,
+}
+
+impl<S: Store> Drop for StoreSubscription<S> {
+    fn drop(&mut self) {
+        self.dispatch
+            .inner
+            .subscribers
+            .borrow_mut()
+            .remove(self.key);
+    }
+}
+
+impl<S: Store> fmt::Debug for StoreSubscription<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StoreSubscription<_>")
+            .finish_non_exhaustive()
+    }
+}
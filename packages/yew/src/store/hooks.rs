@@ -0,0 +1,335 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::rc::Rc;
+
+use super::{Dispatch, Store, StoreSubscription};
+use crate::callback::Callback;
+use crate::context::ContextHandle;
+use crate::functional::{Hook, HookContext, hook, use_context};
+
+/// State handle for the [`use_store`] hook.
+pub struct UseStoreHandle<S: Store> {
+    state: Rc<S>,
+    dispatch: Dispatch<S>,
+}
+
+impl<S: Store> UseStoreHandle<S> {
+    /// Dispatches an action to the store.
+    pub fn dispatch(&self, action: S::Action) {
+        self.dispatch.dispatch(action)
+    }
+
+    /// Returns the dispatcher of the store.
+    pub fn dispatcher(&self) -> Dispatch<S> {
+        self.dispatch.clone()
+    }
+}
+
+impl<S: Store> Deref for UseStoreHandle<S> {
+    type Target = S;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state
+    }
+}
+
+impl<S: Store> Clone for UseStoreHandle<S> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            dispatch: self.dispatch.clone(),
+        }
+    }
+}
+
+impl<S: Store> PartialEq for UseStoreHandle<S> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.state, &other.state) && self.dispatch == other.dispatch
+    }
+}
+
+impl<S> fmt::Debug for UseStoreHandle<S>
+where
+    S: Store + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UseStoreHandle")
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
+type Selector<S, U> = Rc<dyn Fn(&Rc<S>) -> U>;
+
+struct Selected<S, U> {
+    value: Option<U>,
+    selector: Option<Selector<S, U>>,
+}
+
+struct UseStoreSelector<S: Store, U> {
+    _context: Option<ContextHandle<Dispatch<S>>>,
+    dispatch: Rc<RefCell<Option<Dispatch<S>>>>,
+    subscription: RefCell<Option<(Dispatch<S>, StoreSubscription<S>)>>,
+    selected: Rc<RefCell<Selected<S, U>>>,
+    re_render: Rc<dyn Fn()>,
+    changed: fn(&U, &U) -> bool,
+}
+
+impl<S, U> UseStoreSelector<S, U>
+where
+    S: Store,
+    U: 'static,
+{
+    /// Subscribes to the store, unless already subscribed to it.
+    fn subscribe(&self, dispatch: &Dispatch<S>) {
+        let mut subscription = self.subscription.borrow_mut();
+        if matches!(*subscription, Some((ref m, _)) if m == dispatch) {
+            return;
+        }
+
+        let selected = self.selected.clone();
+        let re_render = self.re_render.clone();
+        let changed = self.changed;
+
+        let on_change = Callback::from(move |state: Rc<S>| {
+            let mut selected = selected.borrow_mut();
+            let Some(selector) = selected.selector.clone() else {
+                return;
+            };
+
+            let next = selector(&state);
+            if selected.value.as_ref().is_none_or(|m| changed(m, &next)) {
+                selected.value = Some(next);
+                re_render();
+            }
+        });
+
+        *subscription = Some((dispatch.clone(), dispatch.subscribe(on_change)));
+    }
+}
+
+/// Subscribes to the store provided to the component and selects a part of it.
+///
+/// The component is re-rendered when `changed` returns `true` for the selected parts of the
+/// previous and the new state.
+fn use_store_selector<S, U>(
+    selector: Selector<S, U>,
+    changed: fn(&U, &U) -> bool,
+) -> impl Hook<Output = Option<(U, Dispatch<S>)>>
+where
+    S: Store,
+    U: Clone + 'static,
+{
+    struct HookProvider<S: Store, U> {
+        selector: Selector<S, U>,
+        changed: fn(&U, &U) -> bool,
+    }
+
+    impl<S, U> Hook for HookProvider<S, U>
+    where
+        S: Store,
+        U: Clone + 'static,
+    {
+        type Output = Option<(U, Dispatch<S>)>;
+
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            let Self { selector, changed } = self;
+            let scope = ctx.scope.clone();
+
+            let state = ctx.next_state(move |re_render| -> UseStoreSelector<S, U> {
+                let dispatch: Rc<RefCell<Option<Dispatch<S>>>> = Rc::default();
+
+                // The provider may be given another store.
+                let context = {
+                    let dispatch = dispatch.clone();
+                    let re_render = re_render.clone();
+
+                    scope.context(Callback::from(move |m| {
+                        *dispatch.borrow_mut() = Some(m);
+                        re_render();
+                    }))
+                }
+                .map(|(m, handle)| {
+                    *dispatch.borrow_mut() = Some(m);
+                    handle
+                });
+
+                UseStoreSelector {
+                    _context: context,
+                    dispatch,
+                    subscription: RefCell::default(),
+                    selected: Rc::new(RefCell::new(Selected {
+                        value: None,
+                        selector: None,
+                    })),
+                    re_render,
+                    changed,
+                }
+            });
+
+            let dispatch = state.dispatch.borrow().clone()?;
+            state.subscribe(&dispatch);
+
+            // The selector may depend on the properties, so it is applied on every render.
+            let value = selector(&dispatch.get());
+            let mut selected = state.selected.borrow_mut();
+            selected.value = Some(value.clone());
+            selected.selector = Some(selector);
+
+            Some((value, dispatch))
+        }
+    }
+
+    HookProvider { selector, changed }
+}
+
+/// Hook for reading a [`Store`] provided by a parent [`StoreProvider`](super::StoreProvider).
+///
+/// If there is no such store in scope, `None` is returned. A component which calls `use_store`
+/// re-renders whenever the state of the store changes, use [`use_selector`] to only re-render
+/// when a part of the state changes.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::rc::Rc;
+/// use yew::prelude::*;
+/// use yew::store::{Store, use_store};
+///
+/// #[derive(Default)]
+/// struct Todos {
+///     items: Vec<String>,
+/// }
+///
+/// enum TodoAction {
+///     Clear,
+/// }
+///
+/// impl Store for Todos {
+///     type Action = TodoAction;
+///
+///     fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+///         match action {
+///             TodoAction::Clear => Self::default().into(),
+///         }
+///     }
+/// }
+///
+/// #[component]
+/// fn TodoList() -> Html {
+///     let todos = use_store::<Todos>().expect("no store found");
+///     let onclick = {
+///         let todos = todos.clone();
+///         Callback::from(move |_| todos.dispatch(TodoAction::Clear))
+///     };
+///
+///     html! {
+///         <>
+///             <ul>{ for todos.items.iter().map(|m| html! { <li>{m}</li> }) }</ul>
+///             <button {onclick}>{"Clear"}</button>
+///         </>
+///     }
+/// }
+/// ```
+pub fn use_store<S>() -> impl Hook<Output = Option<UseStoreHandle<S>>>
+where
+    S: Store,
+{
+    struct HookProvider<S> {
+        _marker: PhantomData<S>,
+    }
+
+    impl<S> Hook for HookProvider<S>
+    where
+        S: Store,
+    {
+        type Output = Option<UseStoreHandle<S>>;
+
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            let selector: Selector<S, Rc<S>> = Rc::new(Rc::clone);
+            let changed = |prev: &Rc<S>, next: &Rc<S>| !Rc::ptr_eq(prev, next);
+
+            use_store_selector(selector, changed)
+                .run(ctx)
+                .map(|(state, dispatch)| UseStoreHandle { state, dispatch })
+        }
+    }
+
+    HookProvider {
+        _marker: PhantomData,
+    }
+}
+
+/// Hook for reading a part of a [`Store`] provided by a parent
+/// [`StoreProvider`](super::StoreProvider).
+///
+/// `selector` maps the state of the store to the part the component reads. If there is no such
+/// store in scope, `None` is returned. A component which calls `use_selector` only re-renders
+/// when the selected part changes.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::rc::Rc;
+/// use yew::prelude::*;
+/// use yew::store::{Store, use_selector};
+///
+/// struct Session {
+///     user_name: String,
+///     last_activity: f64,
+/// }
+/// # impl Store for Session {
+/// #     type Action = ();
+/// #     fn reduce(self: Rc<Self>, _: ()) -> Rc<Self> { self }
+/// # }
+///
+/// /// Does not re-render when the last activity changes.
+/// #[component]
+/// fn UserName() -> Html {
+///     let user_name = use_selector(|m: &Session| m.user_name.clone());
+///
+///     html! { <span>{user_name.unwrap_or_default()}</span> }
+/// }
+/// ```
+pub fn use_selector<S, U>(selector: impl Fn(&S) -> U + 'static) -> impl Hook<Output = Option<U>>
+where
+    S: Store,
+    U: Clone + PartialEq + 'static,
+{
+    struct HookProvider<S: Store, U> {
+        selector: Selector<S, U>,
+    }
+
+    impl<S, U> Hook for HookProvider<S, U>
+    where
+        S: Store,
+        U: Clone + PartialEq + 'static,
+    {
+        type Output = Option<U>;
+
+        fn run(self, ctx: &mut HookContext) -> Self::Output {
+            use_store_selector(self.selector, U::ne)
+                .run(ctx)
+                .map(|(value, _)| value)
+        }
+    }
+
+    HookProvider {
+        selector: Rc::new(move |m: &Rc<S>| selector(m)),
+    }
+}
+
+/// Hook for dispatching actions to a [`Store`] provided by a parent
+/// [`StoreProvider`](super::StoreProvider).
+///
+/// If there is no such store in scope, `None` is returned. A component which calls
+/// `use_dispatch` does not re-render when the state of the store changes.
+#[hook]
+pub fn use_dispatch<S>() -> Option<Dispatch<S>>
+where
+    S: Store,
+{
+    use_context::<Dispatch<S>>()
+}
@@ -0,0 +1,170 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+use super::Store;
+
+/// A step in the handling of the actions dispatched to a [`Store`].
+///
+/// A middleware receives the current state and an action and returns the new state. It usually
+/// passes the action on with [`Next::run`], but it can also replace the action, drop it or
+/// return another state.
+///
+/// Functions with the signature of [`handle`](Middleware::handle) are middleware as well.
+///
+/// # Example
+///
+/// A middleware that persists the state whenever it changes:
+///
+/// ```rust
+/// # use std::rc::Rc;
+/// # use yew::store::{Dispatch, Next, Store};
+/// # #[derive(Default)]
+/// # struct Settings;
+/// # impl Store for Settings {
+/// #     type Action = ();
+/// #     fn reduce(self: Rc<Self>, _: ()) -> Rc<Self> { self }
+/// # }
+/// # fn save(_: &Settings) {}
+/// let store = Dispatch::new(Settings::default()).with_middleware(
+///     |state: Rc<Settings>, action: (), next: Next<Settings>| {
+///         let next_state = next.run(state.clone(), action);
+///         if !Rc::ptr_eq(&state, &next_state) {
+///             save(&next_state);
+///         }
+///
+///         next_state
+///     },
+/// );
+/// ```
+pub trait Middleware<S: Store>: 'static {
+    /// Handles an action dispatched to the store.
+    fn handle(&self, state: Rc<S>, action: S::Action, next: Next<S>) -> Rc<S>;
+}
+
+impl<S, F> Middleware<S> for F
+where
+    S: Store,
+    F: Fn(Rc<S>, S::Action, Next<S>) -> Rc<S> + 'static,
+{
+    fn handle(&self, state: Rc<S>, action: S::Action, next: Next<S>) -> Rc<S> {
+        self(state, action, next)
+    }
+}
+
+/// The rest of the middleware chain of a [`Store`].
+pub struct Next<S: Store> {
+    middleware: Rc<[Rc<dyn Middleware<S>>]>,
+    index: usize,
+}
+
+impl<S: Store> Next<S> {
+    pub(super) fn new(middleware: Rc<[Rc<dyn Middleware<S>>]>) -> Self {
+        Self {
+            middleware,
+            index: 0,
+        }
+    }
+
+    /// Passes the action to the next middleware, or reduces it if there are none left.
+    pub fn run(self, state: Rc<S>, action: S::Action) -> Rc<S> {
+        match self.middleware.get(self.index).cloned() {
+            Some(m) => m.handle(
+                state,
+                action,
+                Self {
+                    middleware: self.middleware,
+                    index: self.index + 1,
+                },
+            ),
+            None => state.reduce(action),
+        }
+    }
+}
+
+impl<S: Store> fmt::Debug for Next<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Next<_>").finish_non_exhaustive()
+    }
+}
+
+/// A middleware that logs the actions and the resulting states.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Logger;
+
+impl<S> Middleware<S> for Logger
+where
+    S: Store + fmt::Debug,
+    S::Action: fmt::Debug,
+{
+    fn handle(&self, state: Rc<S>, action: S::Action, next: Next<S>) -> Rc<S> {
+        tracing::info!(
+            store = std::any::type_name::<S>(),
+            ?action,
+            "dispatching action"
+        );
+        let next_state = next.run(state, action);
+        tracing::info!(store = std::any::type_name::<S>(), state = ?next_state, "state updated");
+
+        next_state
+    }
+}
+
+type IsUndo<A> = Box<dyn Fn(&A) -> bool>;
+
+/// A middleware that restores the previous states of the store on an undo action.
+///
+/// Every change of the state is recorded, up to the [`limit`](Undo::limit). An action for which
+/// `is_undo` returns `true` restores the state that preceded the last change.
+pub struct Undo<S: Store> {
+    is_undo: IsUndo<S::Action>,
+    history: RefCell<Vec<Rc<S>>>,
+    limit: usize,
+}
+
+impl<S: Store> Undo<S> {
+    /// Creates an undo middleware recording up to 100 states.
+    pub fn new<F>(is_undo: F) -> Self
+    where
+        F: Fn(&S::Action) -> bool + 'static,
+    {
+        Self {
+            is_undo: Box::new(is_undo),
+            history: RefCell::default(),
+            limit: 100,
+        }
+    }
+
+    /// Sets the number of states that can be restored.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+}
+
+impl<S: Store> Middleware<S> for Undo<S> {
+    fn handle(&self, state: Rc<S>, action: S::Action, next: Next<S>) -> Rc<S> {
+        if (self.is_undo)(&action) {
+            return self.history.borrow_mut().pop().unwrap_or(state);
+        }
+
+        let next_state = next.run(state.clone(), action);
+        if !Rc::ptr_eq(&state, &next_state) {
+            let mut history = self.history.borrow_mut();
+            history.push(state);
+            if history.len() > self.limit {
+                history.remove(0);
+            }
+        }
+
+        next_state
+    }
+}
+
+impl<S: Store> fmt::Debug for Undo<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Undo<_>")
+            .field("limit", &self.limit)
+            .finish_non_exhaustive()
+    }
+}
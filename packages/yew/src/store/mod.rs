@@ -0,0 +1,297 @@
+//! This module provides stores, states shared by the components of an application.
+//!
+//! A [`Store`] is updated by dispatching actions to it, much like a reducer of
+//! [`use_reducer`](crate::functional::use_reducer). The store is created with a [`Dispatch`]
+//! handle and provided to the components with a [`StoreProvider`]. Components read the store with
+//! [`use_store`] or [`use_selector`], the latter only re-rendering the component when the
+//! selected part of the store changes.
+//!
+//! The [`Dispatch`] handle can be cloned and used outside of components, for example in timers
+//! or callbacks of JavaScript libraries. Actions go through a chain of [`Middleware`] before
+//! reaching the store, which can be used for logging, persistence or undo.
+//!
+//! Stores are not global. Each [`Dispatch`] is a separate store, so that an application rendered
+//! on the server can create a store for each render.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::rc::Rc;
+//!
+//! use yew::prelude::*;
+//! use yew::store::{Dispatch, Logger, Store, StoreProvider, use_selector};
+//!
+//! #[derive(Debug, Default, PartialEq)]
+//! struct Counter {
+//!     count: u32,
+//! }
+//!
+//! #[derive(Debug)]
+//! enum CounterAction {
+//!     Increment,
+//! }
+//!
+//! impl Store for Counter {
+//!     type Action = CounterAction;
+//!
+//!     fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+//!         match action {
+//!             CounterAction::Increment => Self {
+//!                 count: self.count + 1,
+//!             }
+//!             .into(),
+//!         }
+//!     }
+//! }
+//!
+//! #[component]
+//! fn Count() -> Html {
+//!     let count = use_selector(|m: &Counter| m.count).unwrap_or_default();
+//!
+//!     html! { <span>{count}</span> }
+//! }
+//!
+//! #[component]
+//! fn App() -> Html {
+//!     let store = use_memo((), |_| {
+//!         Dispatch::new(Counter::default()).with_middleware(Logger)
+//!     });
+//!     let onclick = store.callback(|_| CounterAction::Increment);
+//!
+//!     html! {
+//!         <StoreProvider<Counter> store={(*store).clone()}>
+//!             <button {onclick}>{"+1"}</button>
+//!             <Count />
+//!         </StoreProvider<Counter>>
+//!     }
+//! }
+//! ```
+
+use std::rc::Rc;
+
+mod dispatch;
+mod hooks;
+mod middleware;
+mod provider;
+
+pub use dispatch::{Dispatch, StoreSubscription};
+pub use hooks::*;
+pub use middleware::{Logger, Middleware, Next, Undo};
+pub use provider::{StoreProvider, StoreProviderProps};
+
+/// A state shared by the components of an application.
+///
+/// Like [`Reducible`](crate::functional::Reducible), the state is updated by reducing actions
+/// into a new state.
+pub trait Store: 'static {
+    /// The action type of the store.
+    type Action;
+
+    /// The reducer function.
+    ///
+    /// Returning the same [`Rc`] indicates that the state has not changed and does not notify the
+    /// subscribers.
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self>;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+    use crate::callback::Callback;
+
+    #[derive(Debug, Default, PartialEq)]
+    struct Counter {
+        count: u32,
+    }
+
+    #[derive(Debug)]
+    enum CounterAction {
+        Add(u32),
+        Undo,
+        Noop,
+    }
+
+    impl Store for Counter {
+        type Action = CounterAction;
+
+        fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+            match action {
+                CounterAction::Add(m) => Self {
+                    count: self.count + m,
+                }
+                .into(),
+                CounterAction::Undo | CounterAction::Noop => self,
+            }
+        }
+    }
+
+    #[test]
+    fn notifies_subscribers_on_change() {
+        let store = Dispatch::new(Counter::default());
+        let notified: Rc<RefCell<Vec<u32>>> = Rc::default();
+
+        let subscription = {
+            let notified = notified.clone();
+            store.subscribe(Callback::from(move |m: Rc<Counter>| {
+                notified.borrow_mut().push(m.count)
+            }))
+        };
+
+        store.dispatch(CounterAction::Add(1));
+        store.dispatch(CounterAction::Noop);
+        store.dispatch(CounterAction::Add(2));
+        drop(subscription);
+        store.dispatch(CounterAction::Add(3));
+
+        assert_eq!(*notified.borrow(), [1, 3]);
+        assert_eq!(store.get().count, 6);
+    }
+
+    #[test]
+    fn queues_actions_dispatched_while_dispatching() {
+        let store = Dispatch::new(Counter::default());
+        let notified: Rc<RefCell<Vec<u32>>> = Rc::default();
+
+        let _subscription = {
+            let store = store.clone();
+            let notified = notified.clone();
+            store
+                .clone()
+                .subscribe(Callback::from(move |m: Rc<Counter>| {
+                    notified.borrow_mut().push(m.count);
+                    if m.count == 1 {
+                        store.dispatch(CounterAction::Add(10));
+                    }
+                }))
+        };
+
+        store.dispatch(CounterAction::Add(1));
+
+        assert_eq!(*notified.borrow(), [1, 11]);
+    }
+
+    #[test]
+    fn dispatches_after_panicking_subscriber() {
+        let store = Dispatch::new(Counter::default());
+
+        let subscription = store.subscribe(Callback::from(|m: Rc<Counter>| {
+            if m.count == 1 {
+                panic!("subscriber panicked");
+            }
+        }));
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            store.dispatch(CounterAction::Add(1))
+        }));
+        assert!(result.is_err());
+        drop(subscription);
+
+        store.dispatch(CounterAction::Add(2));
+        assert_eq!(store.get().count, 3);
+    }
+
+    #[test]
+    fn runs_middleware_in_order() {
+        let calls: Rc<RefCell<Vec<&'static str>>> = Rc::default();
+        let middleware = |name: &'static str| {
+            let calls = calls.clone();
+            move |state: Rc<Counter>, action: CounterAction, next: Next<Counter>| {
+                calls.borrow_mut().push(name);
+                next.run(state, action)
+            }
+        };
+
+        let store = Dispatch::new(Counter::default())
+            .with_middleware(middleware("first"))
+            .with_middleware(middleware("second"))
+            .with_middleware(Logger);
+        store.dispatch(CounterAction::Add(1));
+
+        assert_eq!(*calls.borrow(), ["first", "second"]);
+        assert_eq!(store.get().count, 1);
+    }
+
+    #[test]
+    fn undo_restores_previous_states() {
+        let store = Dispatch::new(Counter::default())
+            .with_middleware(Undo::new(|m| matches!(m, CounterAction::Undo)).limit(2));
+
+        for i in 1..=3 {
+            store.dispatch(CounterAction::Add(i));
+        }
+        assert_eq!(store.get().count, 6);
+
+        store.dispatch(CounterAction::Undo);
+        assert_eq!(store.get().count, 3);
+        store.dispatch(CounterAction::Undo);
+        assert_eq!(store.get().count, 1);
+        // Only 2 states are recorded.
+        store.dispatch(CounterAction::Undo);
+        assert_eq!(store.get().count, 1);
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32"), feature = "ssr"))]
+mod ssr_tests {
+    use tokio::test;
+
+    use super::*;
+    use crate::ServerRenderer;
+    use crate::prelude::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Greeting {
+        name: String,
+        visits: u32,
+    }
+
+    impl Store for Greeting {
+        type Action = ();
+
+        fn reduce(self: Rc<Self>, _action: Self::Action) -> Rc<Self> {
+            self
+        }
+    }
+
+    #[component]
+    fn Name() -> Html {
+        let name = use_selector(|m: &Greeting| m.name.clone()).unwrap_or_default();
+        let visits = use_store::<Greeting>().map(|m| m.visits);
+
+        html! { <p>{name}{visits}</p> }
+    }
+
+    #[derive(Properties, PartialEq)]
+    struct AppProps {
+        store: Dispatch<Greeting>,
+    }
+
+    #[component]
+    fn App(props: &AppProps) -> Html {
+        html! {
+            <StoreProvider<Greeting> store={props.store.clone()}>
+                <Name />
+            </StoreProvider<Greeting>>
+        }
+    }
+
+    async fn render(name: &'static str) -> String {
+        ServerRenderer::<App>::with_props(move || AppProps {
+            store: Dispatch::new(Greeting {
+                name: name.to_owned(),
+                visits: 1,
+            }),
+        })
+        .hydratable(false)
+        .render()
+        .await
+    }
+
+    #[test]
+    async fn renders_with_store_per_render() {
+        assert_eq!(render("alice").await, "<p>alice1</p>");
+        assert_eq!(render("bob").await, "<p>bob1</p>");
+    }
+}
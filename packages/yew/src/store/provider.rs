@@ -0,0 +1,45 @@
+use super::{Dispatch, Store};
+use crate::context::ContextProvider;
+use crate::html::Html;
+use crate::{Properties, component, html};
+
+/// Properties for [`StoreProvider`].
+#[derive(Debug, Properties)]
+pub struct StoreProviderProps<S: Store> {
+    /// The store provided to the children.
+    pub store: Dispatch<S>,
+    /// Children
+    #[prop_or_default]
+    pub children: Html,
+}
+
+impl<S: Store> PartialEq for StoreProviderProps<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.store == other.store && self.children == other.children
+    }
+}
+
+impl<S: Store> Clone for StoreProviderProps<S> {
+    fn clone(&self) -> Self {
+        Self {
+            store: self.store.clone(),
+            children: self.children.clone(),
+        }
+    }
+}
+
+/// Provides a [`Store`] to its children.
+///
+/// Every child (direct or indirect) of this component may access the store with
+/// [`use_store`](super::use_store), [`use_selector`](super::use_selector) or
+/// [`use_dispatch`](super::use_dispatch).
+#[component]
+pub fn StoreProvider<S: Store>(props: &StoreProviderProps<S>) -> Html {
+    let StoreProviderProps { store, children } = props.clone();
+
+    html! {
+        <ContextProvider<Dispatch<S>> context={store}>
+            {children}
+        </ContextProvider<Dispatch<S>>>
+    }
+}
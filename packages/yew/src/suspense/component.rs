@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use crate::html::{Html, Properties};
 
 /// Properties for [Suspense].
@@ -10,20 +12,35 @@ pub struct SuspenseProps {
     /// The Fallback UI of the current Suspense Component.
     #[prop_or_default]
     pub fallback: Html,
+
+    /// How long the children need to be suspended before the fallback is shown.
+    ///
+    /// Until then, children that have already been rendered keep showing their previous content.
+    /// This avoids the fallback flashing for suspensions that resolve quickly.
+    #[prop_or_default]
+    pub delay: Option<Duration>,
+
+    /// The minimum duration the fallback is shown for once it has been shown.
+    #[prop_or_default]
+    pub min_duration: Option<Duration>,
 }
 
 #[cfg(any(feature = "csr", feature = "ssr"))]
 mod feat_csr_ssr {
+    use std::any::TypeId;
     #[cfg(feature = "csr")]
     use std::cell::RefCell;
+    use std::iter;
 
     use super::*;
+    use crate::context::{ContextHandle, ContextProvider};
     #[cfg(feature = "csr")]
     use crate::html::PendingRendered;
-    use crate::html::{Component, Context, Html, Scope};
-    use crate::suspense::Suspension;
+    use crate::html::{AnyScope, Component, Context, Html, Scope};
+    use crate::hydration::HydrateOn;
     #[cfg(feature = "hydration")]
     use crate::suspense::SuspensionHandle;
+    use crate::suspense::{SuspenseListSlot, Suspension};
     use crate::virtual_dom::{VNode, VSuspense};
     use crate::{component, html};
 
@@ -32,18 +49,60 @@ mod feat_csr_ssr {
         pub children: Html,
         #[prop_or(None)]
         pub fallback: Option<Html>,
+        #[prop_or_default]
+        pub delay: Option<Duration>,
+        #[prop_or_default]
+        pub min_duration: Option<Duration>,
+        #[prop_or_default]
+        pub hydrate_on: HydrateOn,
     }
 
     #[derive(Debug)]
     pub(crate) enum BaseSuspenseMsg {
         Suspend(Suspension),
+        #[cfg(feature = "csr")]
+        SuspendInTransition(Suspension),
         Resume(Suspension),
+        ListChanged(SuspenseListSlot),
+        #[cfg(feature = "csr")]
+        DelayElapsed(u32),
+        #[cfg(feature = "csr")]
+        MinDurationElapsed(u32),
+        /// The condition to hydrate the server-side rendered children has been met.
+        #[cfg(feature = "hydration")]
+        Hydrate,
     }
 
     pub(crate) struct BaseSuspense {
         suspensions: Vec<Suspension>,
+        /// Suspensions raised by components rendering as part of a transition.
+        ///
+        /// These components keep showing their previously committed content, so they do not
+        /// cause the fallback to be shown.
+        #[cfg(feature = "csr")]
+        transitions: Vec<Suspension>,
         #[cfg(feature = "hydration")]
         hydration_handle: Option<SuspensionHandle>,
+        /// Whether hydration is waiting for the condition set by `hydrate_on` to be met.
+        #[cfg(feature = "hydration")]
+        dehydrated: bool,
+        /// The slot of the [`SuspenseList`](crate::suspense::SuspenseList) coordinating this
+        /// boundary, if any.
+        list: Option<(SuspenseListSlot, ContextHandle<SuspenseListSlot>)>,
+        /// Whether the list has been told that this boundary is suspended.
+        list_suspended: bool,
+        /// Whether the fallback is currently shown.
+        #[cfg(feature = "csr")]
+        fallback_shown: bool,
+        /// Whether the fallback is held until the minimum duration has elapsed.
+        #[cfg(feature = "csr")]
+        fallback_held: bool,
+        /// Whether the fallback is waiting for the delay to elapse.
+        #[cfg(feature = "csr")]
+        delay_pending: bool,
+        /// Incremented whenever a timer is started, so that stale timers can be told apart.
+        #[cfg(feature = "csr")]
+        timer_epoch: u32,
         /// Rendered runners for child components that resumed while this
         /// Suspense was still suspended (because of other pending siblings).
         /// Drained in `rendered` once the Suspense fully un-suspends, so
@@ -61,7 +120,7 @@ mod feat_csr_ssr {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             f.debug_struct("BaseSuspense")
                 .field("suspensions", &self.suspensions)
-                .finish()
+                .finish_non_exhaustive()
         }
     }
 
@@ -69,9 +128,9 @@ mod feat_csr_ssr {
         type Message = BaseSuspenseMsg;
         type Properties = BaseSuspenseProps;
 
-        fn create(_ctx: &Context<Self>) -> Self {
+        fn create(ctx: &Context<Self>) -> Self {
             #[cfg(not(feature = "hydration"))]
-            let suspensions = Vec::new();
+            let suspensions: Vec<Suspension> = Vec::new();
 
             // We create a suspension to block suspense until its rendered method is notified.
             #[cfg(feature = "hydration")]
@@ -79,9 +138,9 @@ mod feat_csr_ssr {
                 use crate::callback::Callback;
                 use crate::html::RenderMode;
 
-                match _ctx.creation_mode() {
+                match ctx.creation_mode() {
                     RenderMode::Hydration => {
-                        let link = _ctx.link().clone();
+                        let link = ctx.link().clone();
                         let (s, handle) = Suspension::new();
                         s.listen(Callback::from(move |s| {
                             link.send_message(BaseSuspenseMsg::Resume(s));
@@ -93,71 +152,104 @@ mod feat_csr_ssr {
             };
 
             Self {
+                #[cfg(feature = "csr")]
+                fallback_shown: !suspensions.is_empty(),
                 suspensions,
+                #[cfg(feature = "csr")]
+                transitions: Vec::new(),
+                #[cfg(feature = "hydration")]
+                dehydrated: hydration_handle.is_some()
+                    && ctx.props().fallback.is_some()
+                    && ctx.props().hydrate_on != HydrateOn::Load,
                 #[cfg(feature = "hydration")]
                 hydration_handle,
+                list: Self::subscribe_list(ctx),
+                list_suspended: false,
+                #[cfg(feature = "csr")]
+                fallback_held: false,
+                #[cfg(feature = "csr")]
+                delay_pending: false,
+                #[cfg(feature = "csr")]
+                timer_epoch: 0,
                 #[cfg(feature = "csr")]
                 pending_rendered: RefCell::new(Vec::new()),
             }
         }
 
         fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
-            match msg {
-                Self::Message::Suspend(m) => {
-                    assert!(
-                        ctx.props().fallback.is_some(),
-                        "You cannot suspend from a component rendered as a fallback."
-                    );
-
-                    if m.resumed() {
-                        return false;
-                    }
-
-                    // If a suspension already exists, ignore it.
-                    if self.suspensions.iter().any(|n| n == &m) {
-                        return false;
-                    }
-
-                    self.suspensions.push(m);
+            let should_render = self.handle_message(ctx, msg);
+            self.sync_list(ctx);
+            #[cfg(feature = "csr")]
+            let should_render = self.sync_fallback(ctx) || should_render;
 
-                    true
-                }
-                Self::Message::Resume(ref m) => {
-                    let suspensions_len = self.suspensions.len();
-                    self.suspensions.retain(|n| m != n);
+            should_render
+        }
 
-                    suspensions_len != self.suspensions.len()
+        fn destroy(&mut self, ctx: &Context<Self>) {
+            if let Some((ref slot, _)) = self.list {
+                if self.list_suspended {
+                    slot.report(ctx.link().id, false);
                 }
             }
         }
 
         fn view(&self, ctx: &Context<Self>) -> Html {
-            let BaseSuspenseProps { children, fallback } = (*ctx.props()).clone();
+            let BaseSuspenseProps {
+                children, fallback, ..
+            } = (*ctx.props()).clone();
             let children = VNode::VList(::std::rc::Rc::new(
                 crate::virtual_dom::VList::with_children(vec![children], None),
             ));
 
             match fallback {
                 Some(fallback) => {
-                    let vsuspense = VSuspense::new(
+                    // Children that are ready are kept in the background until the list reveals
+                    // them.
+                    let show_fallback = self.list.as_ref().is_none_or(|(m, _)| m.show_fallback);
+                    // The list decides which fallbacks are shown while its boundaries are streamed
+                    // out of order.
+                    #[cfg(feature = "ssr")]
+                    let reveal = self.list.as_ref().and_then(|(m, _)| m.reveal_slot());
+                    #[cfg(feature = "ssr")]
+                    let show_fallback = show_fallback || reveal.is_some();
+                    let fallback = if show_fallback {
+                        fallback
+                    } else {
+                        Html::default()
+                    };
+
+                    #[allow(unused_mut)]
+                    let mut vsuspense = VSuspense::new(
                         children,
                         fallback,
-                        !self.suspensions.is_empty(),
+                        self.suspended() || !self.revealed(),
                         // We don't need to key this as the key will be applied to the component.
                         None,
                     );
 
+                    #[cfg(feature = "ssr")]
+                    {
+                        vsuspense.reveal = reveal;
+                    }
+
+                    #[cfg(feature = "hydration")]
+                    if self.dehydrated {
+                        vsuspense.lazy = Some((
+                            ctx.props().hydrate_on,
+                            ctx.link().callback(|_| BaseSuspenseMsg::Hydrate),
+                        ));
+                    }
+
                     VNode::from(vsuspense)
                 }
                 None => children,
             }
         }
 
-        fn rendered(&mut self, _ctx: &Context<Self>, first_render: bool) {
-            #[cfg(not(feature = "hydration"))]
-            let _ = first_render;
+        fn rendered(&mut self, _ctx: &Context<Self>, _first_render: bool) {
+            // Deferred hydration resumes once the children have been hydrated.
             #[cfg(feature = "hydration")]
-            if first_render {
+            if !self.dehydrated {
                 if let Some(m) = self.hydration_handle.take() {
                     m.resume();
                 }
@@ -166,7 +258,7 @@ mod feat_csr_ssr {
             // we were still suspended. Only safe now that we're un-suspended:
             // the last reconcile shifted their DOM into the live tree.
             #[cfg(feature = "csr")]
-            if self.suspensions.is_empty() {
+            if !self.suspended() && self.revealed() {
                 let pending = std::mem::take(&mut *self.pending_rendered.borrow_mut());
                 for (comp_id, p) in pending {
                     p.schedule(comp_id);
@@ -176,10 +268,241 @@ mod feat_csr_ssr {
     }
 
     impl BaseSuspense {
+        fn handle_message(&mut self, ctx: &Context<Self>, msg: BaseSuspenseMsg) -> bool {
+            match msg {
+                BaseSuspenseMsg::Suspend(m) => {
+                    assert!(
+                        ctx.props().fallback.is_some(),
+                        "You cannot suspend from a component rendered as a fallback."
+                    );
+
+                    if m.resumed() {
+                        return false;
+                    }
+
+                    // If a suspension already exists, ignore it.
+                    if self.suspensions.iter().any(|n| n == &m) {
+                        return false;
+                    }
+
+                    #[cfg(feature = "csr")]
+                    self.transitions.retain(|n| n != &m);
+                    self.suspensions.push(m);
+
+                    true
+                }
+                #[cfg(feature = "csr")]
+                BaseSuspenseMsg::SuspendInTransition(m) => {
+                    assert!(
+                        ctx.props().fallback.is_some(),
+                        "You cannot suspend from a component rendered as a fallback."
+                    );
+
+                    if m.resumed()
+                        || self.suspensions.iter().any(|n| n == &m)
+                        || self.transitions.iter().any(|n| n == &m)
+                    {
+                        return false;
+                    }
+
+                    // The content stays in place, so there is nothing to render.
+                    self.transitions.push(m);
+
+                    false
+                }
+                BaseSuspenseMsg::Resume(ref m) => {
+                    let suspensions_len = self.suspensions.len();
+                    self.suspensions.retain(|n| m != n);
+
+                    // The resumed component hands its `rendered` lifecycle over to this suspense,
+                    // so it needs to render even if its content was never hidden.
+                    #[cfg(feature = "csr")]
+                    let resumed_transition = {
+                        let transitions_len = self.transitions.len();
+                        self.transitions.retain(|n| m != n);
+
+                        transitions_len != self.transitions.len()
+                    };
+                    #[cfg(not(feature = "csr"))]
+                    let resumed_transition = false;
+
+                    suspensions_len != self.suspensions.len() || resumed_transition
+                }
+                BaseSuspenseMsg::ListChanged(slot) => match self.list {
+                    Some((ref mut m, _)) if *m != slot => {
+                        *m = slot;
+                        true
+                    }
+                    _ => false,
+                },
+                #[cfg(feature = "csr")]
+                BaseSuspenseMsg::DelayElapsed(epoch) => {
+                    if epoch != self.timer_epoch || !self.delay_pending {
+                        return false;
+                    }
+
+                    self.delay_pending = false;
+                    self.show_fallback(ctx);
+
+                    true
+                }
+                #[cfg(feature = "csr")]
+                BaseSuspenseMsg::MinDurationElapsed(epoch) => {
+                    if epoch != self.timer_epoch {
+                        return false;
+                    }
+
+                    // The fallback is hidden by `sync_fallback` if the children are ready.
+                    self.fallback_held = false;
+
+                    false
+                }
+                #[cfg(feature = "hydration")]
+                BaseSuspenseMsg::Hydrate => std::mem::replace(&mut self.dehydrated, false),
+            }
+        }
+
+        /// Returns whether the children are hidden behind the fallback.
+        fn suspended(&self) -> bool {
+            #[cfg(feature = "csr")]
+            {
+                self.fallback_shown
+            }
+            #[cfg(not(feature = "csr"))]
+            {
+                !self.suspensions.is_empty()
+            }
+        }
+
+        /// Shows or hides the fallback after the suspensions have changed, respecting the
+        /// `delay` and `min_duration` of the boundary.
+        ///
+        /// Returns whether the fallback has been shown or hidden.
+        #[cfg(feature = "csr")]
+        fn sync_fallback(&mut self, ctx: &Context<Self>) -> bool {
+            if self.suspensions.is_empty() {
+                // Stale delay timers are ignored.
+                self.delay_pending = false;
+
+                if !self.fallback_shown || self.fallback_held {
+                    return false;
+                }
+
+                self.fallback_shown = false;
+
+                return true;
+            }
+
+            if self.fallback_shown || self.delay_pending {
+                return false;
+            }
+
+            match ctx.props().delay.filter(|m| !m.is_zero()) {
+                Some(delay) => {
+                    self.delay_pending = true;
+                    self.timer_epoch = self.timer_epoch.wrapping_add(1);
+                    self.start_timer(ctx, delay, BaseSuspenseMsg::DelayElapsed);
+
+                    false
+                }
+                None => {
+                    self.show_fallback(ctx);
+
+                    true
+                }
+            }
+        }
+
+        #[cfg(feature = "csr")]
+        fn show_fallback(&mut self, ctx: &Context<Self>) {
+            self.fallback_shown = true;
+
+            if let Some(min_duration) = ctx.props().min_duration.filter(|m| !m.is_zero()) {
+                self.fallback_held = true;
+                self.timer_epoch = self.timer_epoch.wrapping_add(1);
+                self.start_timer(ctx, min_duration, BaseSuspenseMsg::MinDurationElapsed);
+            }
+        }
+
+        #[cfg(feature = "csr")]
+        fn start_timer(
+            &self,
+            ctx: &Context<Self>,
+            duration: Duration,
+            msg: fn(u32) -> BaseSuspenseMsg,
+        ) {
+            let link = ctx.link().clone();
+            let epoch = self.timer_epoch;
+
+            crate::platform::spawn_local(async move {
+                crate::platform::time::sleep(duration).await;
+                link.send_message(msg(epoch));
+            });
+        }
+
+        /// Subscribes to the closest [`SuspenseList`](crate::suspense::SuspenseList).
+        ///
+        /// Only the closest boundaries under a list are coordinated by it, boundaries nested in
+        /// another boundary are not.
+        fn subscribe_list(
+            ctx: &Context<Self>,
+        ) -> Option<(SuspenseListSlot, ContextHandle<SuspenseListSlot>)> {
+            let scope = AnyScope::from(ctx.link().clone());
+
+            for m in iter::successors(scope.get_parent(), |m| m.get_parent()) {
+                let type_id = *m.get_type_id();
+
+                if type_id == TypeId::of::<BaseSuspense>() {
+                    return None;
+                } else if type_id == TypeId::of::<ContextProvider<SuspenseListSlot>>() {
+                    return ctx
+                        .link()
+                        .context(ctx.link().callback(BaseSuspenseMsg::ListChanged));
+                }
+            }
+
+            None
+        }
+
+        /// Tells the list coordinating this boundary whether it is suspended.
+        fn sync_list(&mut self, ctx: &Context<Self>) {
+            let Some((ref slot, _)) = self.list else {
+                return;
+            };
+
+            // The server-side rendered content is kept while hydrating.
+            #[cfg(feature = "hydration")]
+            if self.hydration_handle.is_some() {
+                return;
+            }
+
+            let suspended = !self.suspensions.is_empty();
+            if suspended != self.list_suspended {
+                self.list_suspended = suspended;
+                slot.report(ctx.link().id, suspended);
+            }
+        }
+
+        /// Returns whether the list coordinating this boundary allows its content to be shown.
+        fn revealed(&self) -> bool {
+            #[cfg(feature = "hydration")]
+            if self.hydration_handle.is_some() {
+                return true;
+            }
+
+            self.list.as_ref().is_none_or(|(m, _)| m.revealed)
+        }
+
         pub(crate) fn suspend(scope: &Scope<Self>, s: Suspension) {
             scope.send_message(BaseSuspenseMsg::Suspend(s));
         }
 
+        /// Suspends a component which keeps showing its previously committed content.
+        #[cfg(feature = "csr")]
+        pub(crate) fn suspend_in_transition(scope: &Scope<Self>, s: Suspension) {
+            scope.send_message(BaseSuspenseMsg::SuspendInTransition(s));
+        }
+
         pub(crate) fn resume(scope: &Scope<Self>, s: Suspension) {
             scope.send_message(BaseSuspenseMsg::Resume(s));
         }
@@ -210,7 +533,12 @@ mod feat_csr_ssr {
     /// Suspend rendering and show a fallback UI until the underlying task completes.
     #[component]
     pub fn Suspense(props: &SuspenseProps) -> Html {
-        let SuspenseProps { children, fallback } = props.clone();
+        let SuspenseProps {
+            children,
+            fallback,
+            delay,
+            min_duration,
+        } = props.clone();
 
         let fallback = html! {
             <BaseSuspense>
@@ -219,7 +547,7 @@ mod feat_csr_ssr {
         };
 
         html! {
-            <BaseSuspense {fallback}>
+            <BaseSuspense {fallback} {delay} {min_duration}>
                 {children}
             </BaseSuspense>
         }
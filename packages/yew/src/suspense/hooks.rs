@@ -3,21 +3,46 @@ use std::fmt;
 use std::ops::Deref;
 use std::rc::Rc;
 
+use futures::future::Abortable;
+use yew::platform::spawn_local;
 use yew::prelude::*;
 use yew::suspense::{Suspension, SuspensionResult};
 
+use crate::functional::{AbortOnDrop, abortable};
+
 /// This hook is used to await a future in a suspending context.
 ///
 /// A [Suspension] is created from the passed future and the result of the future
 /// is the output of the suspension.
 pub struct UseFutureHandle<O> {
     inner: UseStateHandle<Option<O>>,
+    restart: Callback<()>,
+}
+
+impl<O> UseFutureHandle<O> {
+    /// Awaits a new future for the current dependencies.
+    ///
+    /// The pending future, if any, is aborted and the component suspends until the new future
+    /// resolves.
+    pub fn restart(&self) {
+        self.restart.emit(());
+    }
+}
+
+impl<T, E> UseFutureHandle<Result<T, E>> {
+    /// Restarts the future if it resolved to an error.
+    pub fn retry(&self) {
+        if self.is_err() {
+            self.restart();
+        }
+    }
 }
 
 impl<O> Clone for UseFutureHandle<O> {
     fn clone(&self) -> Self {
         Self {
             inner: self.inner.clone(),
+            restart: self.restart.clone(),
         }
     }
 }
@@ -82,12 +107,85 @@ where
     use_future_with((), move |_| init_f())
 }
 
+/// A future awaited by the hooks of this module, aborted when dropped.
+struct SpawnedFuture<D, S> {
+    deps: Rc<D>,
+    restarts: u32,
+    id: u32,
+    spawned: S,
+    _handle: AbortOnDrop,
+}
+
+/// Creates a future with `f` whenever the dependencies change or the future is restarted, and
+/// passes it to `spawn` with its id.
+///
+/// The previous future is aborted when a new one is created or the component is destroyed.
+/// Returns the id of the latest future and the value returned by `spawn` for it.
+#[hook]
+fn use_spawned_future<F, D, T, S, G>(deps: D, restarts: u32, f: F, spawn: G) -> (u32, S)
+where
+    F: FnOnce(Rc<D>) -> T,
+    T: Future + 'static,
+    D: PartialEq + 'static,
+    S: Clone + 'static,
+    G: FnOnce(u32, Abortable<T>) -> S,
+{
+    let state = use_mut_ref(|| -> Option<SpawnedFuture<D, S>> { None });
+
+    let mut state = state.borrow_mut();
+    let outdated = state
+        .as_ref()
+        .is_none_or(|m| *m.deps != deps || m.restarts != restarts);
+    if outdated {
+        // As long as less than 2**32 futures are in flight wrapping_add is fine
+        let id = state.as_ref().map_or(0, |m| m.id.wrapping_add(1));
+        // Aborts the previous future.
+        *state = None;
+
+        let deps = Rc::new(deps);
+        let (task, handle) = abortable(f(deps.clone()));
+        let spawned = spawn(id, task);
+
+        *state = Some(SpawnedFuture {
+            deps,
+            restarts,
+            id,
+            spawned,
+            _handle: handle,
+        });
+    }
+
+    let spawned = state.as_ref().expect("future has been spawned");
+    (spawned.id, spawned.spawned.clone())
+}
+
+/// Returns the number of times the future has been restarted and a callback restarting it.
+#[hook]
+fn use_restart() -> (u32, Callback<()>) {
+    let restarts = use_ref(|| Cell::new(0u32));
+    let trigger = use_force_update();
+
+    let restart = {
+        let restarts = restarts.clone();
+        Callback::from(move |_| {
+            restarts.set(restarts.get().wrapping_add(1));
+            trigger.force_update();
+        })
+    };
+
+    (restarts.get(), restart)
+}
+
 /// Use the result of an async computation with dependencies, suspending while waiting.
 ///
 /// Awaits the future returned from `f` for the latest `deps`. Even if the future is immediately
-/// [ready], the hook suspends at least once. If the dependencies
-/// change while a future is still pending, the result is never used. This guarantees that your
-/// component always sees up-to-date values while it is not suspended.
+/// [ready], the hook suspends at least once. If the dependencies change while a future is still
+/// pending, the pending future is aborted and its result is never used. This guarantees that your
+/// component always sees up-to-date values while it is not suspended. The pending future is also
+/// aborted when the component is destroyed.
+///
+/// The future can be awaited again with [`UseFutureHandle::restart`] or, if it resolved to an
+/// error, [`UseFutureHandle::retry`].
 ///
 /// [ready]: std::task::Poll::Ready
 #[hook]
@@ -98,35 +196,192 @@ where
     O: 'static,
     D: PartialEq + 'static,
 {
-    let output = use_state(|| None);
-    // We only commit a result if it comes from the latest spawned future. Otherwise, this
-    // might trigger pointless updates or even override newer state.
-    let latest_id = use_ref(|| Cell::new(0u32));
-    let suspension = {
+    let output = use_state(|| None::<O>);
+    let (restarts, restart) = use_restart();
+    let (_, suspension) = {
         let output = output.clone();
 
-        use_memo_base(
-            move |deps| {
-                let self_id = latest_id.get().wrapping_add(1);
-                // As long as less than 2**32 futures are in flight wrapping_add is fine
-                (*latest_id).set(self_id);
-                let deps = Rc::new(deps);
-                let task = f(deps.clone());
-                let suspension = Suspension::from_future(async move {
-                    let result = task.await;
-                    if latest_id.get() == self_id {
-                        output.set(Some(result));
-                    }
-                });
-                (suspension, deps)
-            },
-            deps,
-        )
+        use_spawned_future(deps, restarts, f, move |_, task| {
+            Suspension::from_future(async move {
+                if let Ok(result) = task.await {
+                    output.set(Some(result));
+                }
+            })
+        })
     };
 
     if suspension.resumed() {
-        Ok(UseFutureHandle { inner: output })
+        Ok(UseFutureHandle {
+            inner: output,
+            restart,
+        })
     } else {
-        Err((*suspension).clone())
+        Err(suspension)
+    }
+}
+
+/// The state of a future awaited with [`use_future_state`] or [`use_future_state_with`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FutureState<T, E> {
+    /// The future is pending.
+    Loading,
+    /// The future resolved to a value.
+    Ready(T),
+    /// The future resolved to an error.
+    Failed(E),
+}
+
+impl<T, E> FutureState<T, E> {
+    /// Returns `true` if the future is pending.
+    pub fn is_loading(&self) -> bool {
+        matches!(self, Self::Loading)
+    }
+}
+
+impl<T, E> From<Result<T, E>> for FutureState<T, E> {
+    fn from(result: Result<T, E>) -> Self {
+        match result {
+            Ok(m) => Self::Ready(m),
+            Err(e) => Self::Failed(e),
+        }
+    }
+}
+
+/// State handle for the [`use_future_state`] and [`use_future_state_with`] hooks.
+pub struct UseFutureStateHandle<T, E> {
+    state: Rc<FutureState<T, E>>,
+    restart: Callback<()>,
+}
+
+impl<T, E> UseFutureStateHandle<T, E> {
+    /// Awaits a new future for the current dependencies.
+    ///
+    /// The pending future, if any, is aborted and the state is [`FutureState::Loading`] until
+    /// the new future resolves.
+    pub fn restart(&self) {
+        self.restart.emit(());
+    }
+
+    /// Restarts the future if it resolved to an error.
+    pub fn retry(&self) {
+        if matches!(*self.state, FutureState::Failed(_)) {
+            self.restart();
+        }
+    }
+}
+
+impl<T, E> Clone for UseFutureStateHandle<T, E> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            restart: self.restart.clone(),
+        }
+    }
+}
+
+impl<T, E> Deref for UseFutureStateHandle<T, E> {
+    type Target = FutureState<T, E>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state
+    }
+}
+
+impl<T: fmt::Debug, E: fmt::Debug> fmt::Debug for UseFutureStateHandle<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UseFutureStateHandle")
+            .field("state", &self.state)
+            .finish_non_exhaustive()
     }
 }
+
+/// Use the result of a fallible async computation, without suspending.
+///
+/// Awaits the future returned from the first call to `init_f`. Unlike [`use_future`], the
+/// component does not suspend, the state of the future is returned as a [`FutureState`] instead.
+/// This is useful for components that must keep rendering while waiting, for example to show
+/// their own loading indicator.
+///
+/// When rendered on the server, the state is always [`FutureState::Loading`].
+///
+/// # Example
+///
+/// ```
+/// # use yew::prelude::*;
+/// # use yew::suspense::{FutureState, use_future_state};
+/// use gloo::net::http::Request;
+///
+/// const URL: &str = "https://en.wikipedia.org/w/api.php?\
+///                    action=query&origin=*&format=json&generator=search&\
+///                    gsrnamespace=0&gsrlimit=5&gsrsearch='New_England_Patriots'";
+///
+/// #[component]
+/// fn WikipediaSearch() -> Html {
+///     let res = use_future_state(|| async { Request::get(URL).send().await?.text().await });
+///     let onclick = {
+///         let res = res.clone();
+///         Callback::from(move |_| res.retry())
+///     };
+///
+///     match &*res {
+///         FutureState::Loading => html! { <p>{"Searching..."}</p> },
+///         FutureState::Ready(res) => html! { <p>{res}</p> },
+///         FutureState::Failed(failure) => html! {
+///             <p>
+///                 {failure.to_string()}
+///                 <button {onclick}>{"Retry"}</button>
+///             </p>
+///         },
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_future_state<F, T, R, E>(init_f: F) -> UseFutureStateHandle<R, E>
+where
+    F: FnOnce() -> T,
+    T: Future<Output = Result<R, E>> + 'static,
+    R: 'static,
+    E: 'static,
+{
+    use_future_state_with((), move |_| init_f())
+}
+
+/// Use the result of a fallible async computation with dependencies, without suspending.
+///
+/// Awaits the future returned from `f` for the latest `deps`, the state is
+/// [`FutureState::Loading`] until it resolves. If the dependencies change while a future is still
+/// pending, the pending future is aborted. The pending future is also aborted when the component
+/// is destroyed.
+///
+/// See [`use_future_state`] for more details.
+#[hook]
+pub fn use_future_state_with<F, D, T, R, E>(deps: D, f: F) -> UseFutureStateHandle<R, E>
+where
+    F: FnOnce(Rc<D>) -> T,
+    T: Future<Output = Result<R, E>> + 'static,
+    R: 'static,
+    E: 'static,
+    D: PartialEq + 'static,
+{
+    let output = use_state(|| -> Option<(u32, Rc<FutureState<R, E>>)> { None });
+    let (restarts, restart) = use_restart();
+    let (id, _) = {
+        let output = output.clone();
+
+        use_spawned_future(deps, restarts, f, move |id, task| {
+            spawn_local(async move {
+                if let Ok(result) = task.await {
+                    output.set(Some((id, Rc::new(result.into()))));
+                }
+            });
+        })
+    };
+
+    // The output of a previous future is not used.
+    let state = match *output {
+        Some((output_id, ref m)) if output_id == id => m.clone(),
+        _ => Rc::new(FutureState::Loading),
+    };
+
+    UseFutureStateHandle { state, restart }
+}
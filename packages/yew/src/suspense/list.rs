@@ -0,0 +1,935 @@
+use crate::html::{Html, Properties};
+
+/// The order in which a [`SuspenseList`] reveals its children.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RevealOrder {
+    /// Children are revealed from the first to the last one.
+    ///
+    /// A child is only revealed once all children before it have finished loading.
+    #[default]
+    Forwards,
+    /// Children are revealed from the last to the first one.
+    ///
+    /// A child is only revealed once all children after it have finished loading.
+    Backwards,
+    /// All children are revealed at the same time, once all of them have finished loading.
+    Together,
+}
+
+/// How a [`SuspenseList`] shows the fallbacks of children that have not been revealed yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SuspenseListTail {
+    /// The fallbacks of all children that have not been revealed are shown.
+    #[default]
+    Visible,
+    /// Only the fallback of the next child to be revealed is shown.
+    Collapsed,
+    /// No fallbacks are shown.
+    Hidden,
+}
+
+/// Properties for [SuspenseList].
+#[derive(Properties, PartialEq, Debug, Clone)]
+pub struct SuspenseListProps {
+    /// The Children of the current SuspenseList Component.
+    #[prop_or_default]
+    pub children: Html,
+
+    /// The order in which the children are revealed.
+    #[prop_or_default]
+    pub reveal_order: RevealOrder,
+
+    /// How the fallbacks of children that have not been revealed are shown.
+    #[prop_or_default]
+    pub tail: SuspenseListTail,
+}
+
+#[cfg(any(feature = "csr", feature = "ssr"))]
+mod feat_csr_ssr {
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::callback::Callback;
+    use crate::context::{ContextProvider, ContextProviderProps};
+    #[cfg(feature = "ssr")]
+    use crate::html::RenderMode;
+    use crate::html::{Component, Context};
+    use crate::virtual_dom::{VChild, VList, VNode};
+    use crate::{component, html};
+
+    /// The state of a child of a [`BaseSuspenseList`].
+    ///
+    /// This is provided as a context to the suspense boundaries of the child.
+    #[derive(Debug, Clone, PartialEq)]
+    pub(crate) struct SuspenseListSlot {
+        index: usize,
+        /// Whether the content of the child may be shown.
+        pub revealed: bool,
+        /// Whether the fallback is shown while the child has not been revealed.
+        pub show_fallback: bool,
+        report: Callback<SuspenseListReport>,
+        #[cfg(feature = "ssr")]
+        queue: Option<feat_ssr::RevealQueue>,
+    }
+
+    impl SuspenseListSlot {
+        /// Returns the slot through which the swaps of the boundaries in this slot are queued,
+        /// if the list is rendered on the server side.
+        #[cfg(feature = "ssr")]
+        pub(crate) fn reveal_slot(&self) -> Option<RevealSlot> {
+            self.queue.clone().map(|queue| RevealSlot {
+                queue,
+                index: self.index,
+            })
+        }
+
+        /// Reports whether the boundary with `boundary_id` in this slot is suspended.
+        pub(crate) fn report(&self, boundary_id: usize, suspended: bool) {
+            self.report.emit(SuspenseListReport {
+                index: self.index,
+                boundary_id,
+                suspended,
+            });
+        }
+    }
+
+    #[derive(Debug)]
+    pub(crate) struct SuspenseListReport {
+        index: usize,
+        boundary_id: usize,
+        suspended: bool,
+    }
+
+    #[derive(Debug)]
+    pub(crate) struct BaseSuspenseList {
+        /// The suspended boundaries as pairs of the index of the child and the boundary id.
+        suspended: Vec<(usize, usize)>,
+        report: Callback<SuspenseListReport>,
+        #[cfg(feature = "ssr")]
+        queue: Option<feat_ssr::RevealQueue>,
+    }
+
+    impl Component for BaseSuspenseList {
+        type Message = SuspenseListReport;
+        type Properties = SuspenseListProps;
+
+        fn create(ctx: &Context<Self>) -> Self {
+            #[cfg(feature = "ssr")]
+            let queue = (ctx.creation_mode() == RenderMode::Ssr).then(|| {
+                let SuspenseListProps {
+                    reveal_order, tail, ..
+                } = *ctx.props();
+                let queue = feat_ssr::RevealQueue::new(reveal_order, tail);
+
+                ctx.set_ssr_content(feat_ssr::ListContent {
+                    scope: ctx.link().clone(),
+                    queue: queue.clone(),
+                    reveal_order,
+                });
+
+                queue
+            });
+
+            Self {
+                suspended: Vec::new(),
+                report: ctx.link().callback(|m| m),
+                #[cfg(feature = "ssr")]
+                queue,
+            }
+        }
+
+        fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+            let entry = (msg.index, msg.boundary_id);
+            let pos = self.suspended.iter().position(|m| *m == entry);
+
+            match (msg.suspended, pos) {
+                (true, None) => {
+                    self.suspended.push(entry);
+                    true
+                }
+                (false, Some(pos)) => {
+                    self.suspended.swap_remove(pos);
+                    true
+                }
+                _ => false,
+            }
+        }
+
+        fn view(&self, ctx: &Context<Self>) -> Html {
+            let SuspenseListProps {
+                ref children,
+                reveal_order,
+                tail,
+            } = *ctx.props();
+
+            let mut items = Vec::new();
+            flatten_children(children, &mut items);
+
+            let suspended = (0..items.len())
+                .map(|i| self.suspended.iter().any(|(index, _)| *index == i))
+                .collect::<Vec<_>>();
+
+            let slots = reveal(reveal_order, tail, &suspended);
+
+            let children = items
+                .into_iter()
+                .zip(slots)
+                .enumerate()
+                .map(|(index, (child, (revealed, show_fallback)))| {
+                    let key = child.key().cloned();
+                    let props = ContextProviderProps {
+                        context: SuspenseListSlot {
+                            index,
+                            revealed,
+                            show_fallback,
+                            report: self.report.clone(),
+                            #[cfg(feature = "ssr")]
+                            queue: self.queue.clone(),
+                        },
+                        children: child,
+                    };
+
+                    VChild::<ContextProvider<SuspenseListSlot>>::new(props, key).into()
+                })
+                .collect::<Vec<VNode>>();
+
+            VNode::VList(Rc::new(VList::with_children(children, None)))
+        }
+    }
+
+    #[cfg(feature = "ssr")]
+    mod feat_ssr {
+        use std::any::TypeId;
+        use std::cell::RefCell;
+        use std::collections::VecDeque;
+        use std::fmt::{self as std_fmt, Write};
+        use std::task::{Poll, Waker};
+        use std::{iter, mem};
+
+        use futures::future::LocalBoxFuture;
+        use futures::stream::{self, SelectAll, StreamExt};
+
+        use super::*;
+        use crate::feat_ssr::{DeferredBoundaries, SwapMode, Swaps, VTagKind, swap};
+        use crate::html::{AnyScope, Scope, SsrAlive, SsrContent};
+        use crate::platform::fmt::{self, BufWriter};
+        use crate::suspense::BaseSuspense;
+
+        /// Renders the content of a list on the server side.
+        ///
+        /// During an out-of-order render, the boundaries of the list are deferred and swapped in
+        /// by the [`RevealQueue`] of the list. Otherwise, content can only be streamed in
+        /// document order, so it is buffered until all children have finished rendering if they
+        /// are revealed in any other order.
+        #[derive(Debug)]
+        pub(crate) struct ListContent {
+            pub scope: Scope<BaseSuspenseList>,
+            pub queue: RevealQueue,
+            pub reveal_order: RevealOrder,
+        }
+
+        impl SsrContent for ListContent {
+            fn render_into_stream<'a>(
+                &'a self,
+                w: &'a mut BufWriter,
+                html: Html,
+                hydratable: bool,
+                parent_vtag_kind: VTagKind,
+                _alive: &'a Rc<SsrAlive>,
+            ) -> LocalBoxFuture<'a, ()> {
+                Box::pin(async move {
+                    let self_any_scope = AnyScope::from(self.scope.clone());
+
+                    if let Some(deferred) = DeferredBoundaries::current() {
+                        html.render_into_stream(w, &self_any_scope, hydratable, parent_vtag_kind)
+                            .await;
+
+                        if !self.queue.is_empty() {
+                            deferred.push(self.queue.stream());
+                        }
+                        return;
+                    }
+
+                    if self.reveal_order == RevealOrder::Forwards {
+                        html.render_into_stream(w, &self_any_scope, hydratable, parent_vtag_kind)
+                            .await;
+                        return;
+                    }
+
+                    let (mut buf_w, mut buf_r) = fmt::buffer();
+                    html.render_into_stream(
+                        &mut buf_w,
+                        &self_any_scope,
+                        hydratable,
+                        parent_vtag_kind,
+                    )
+                    .await;
+                    drop(buf_w);
+
+                    while let Some(m) = buf_r.next().await {
+                        let _ = w.write_str(m.as_str());
+                    }
+                })
+            }
+        }
+
+        /// The content of a boundary deferred by an out-of-order render.
+        enum Content {
+            Pending,
+            /// The swap of the content and the swaps of the boundaries nested in it.
+            Resolved(String, Vec<Swaps>),
+            /// The content has been swapped in, or will never be.
+            Done,
+        }
+
+        struct Boundary {
+            id: usize,
+            /// The fallback, if it has not been rendered in place.
+            fallback: Option<String>,
+            content: Content,
+        }
+
+        #[derive(Default)]
+        struct Slot {
+            boundaries: Vec<Boundary>,
+            revealed: bool,
+            fallback_shown: bool,
+        }
+
+        struct QueueState {
+            reveal_order: RevealOrder,
+            tail: SuspenseListTail,
+            slots: Vec<Slot>,
+            /// Swaps to be streamed, in the order they have been revealed.
+            ready: VecDeque<String>,
+            /// Swaps of the boundaries nested in revealed content.
+            nested: SelectAll<Swaps>,
+            waker: Option<Waker>,
+        }
+
+        impl QueueState {
+            /// Queues the swaps of the children that have been revealed since the last call and
+            /// the fallbacks that are to be shown in the meantime.
+            fn advance(&mut self) {
+                let suspended = self
+                    .slots
+                    .iter()
+                    .map(|m| {
+                        m.boundaries
+                            .iter()
+                            .any(|m| matches!(m.content, Content::Pending))
+                    })
+                    .collect::<Vec<_>>();
+                let reveal = reveal(self.reveal_order, self.tail, &suspended);
+
+                let mut indices = (0..self.slots.len()).collect::<Vec<_>>();
+                if self.reveal_order == RevealOrder::Backwards {
+                    indices.reverse();
+                }
+
+                for i in indices {
+                    let slot = &mut self.slots[i];
+
+                    match reveal[i] {
+                        (true, _) if !slot.revealed => {
+                            slot.revealed = true;
+
+                            for m in slot.boundaries.iter_mut() {
+                                if let Content::Resolved(swap, nested) =
+                                    mem::replace(&mut m.content, Content::Done)
+                                {
+                                    self.ready.push_back(swap);
+                                    self.nested.extend(nested);
+                                }
+                            }
+                        }
+                        (false, true) if !slot.fallback_shown => {
+                            slot.fallback_shown = true;
+
+                            for m in slot.boundaries.iter_mut() {
+                                if let Some(fallback) = m.fallback.take() {
+                                    self.ready.push_back(swap(
+                                        m.id,
+                                        Some(&fallback),
+                                        SwapMode::Keep,
+                                    ));
+                                }
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+
+            fn wake(&mut self) {
+                if let Some(m) = self.waker.take() {
+                    m.wake();
+                }
+            }
+        }
+
+        /// Swaps in the boundaries of a list in the order the list reveals its children during an
+        /// out-of-order render.
+        ///
+        /// The boundaries are always deferred, as a boundary that has finished loading may still
+        /// have to wait for the boundaries of other children.
+        #[derive(Clone)]
+        pub(crate) struct RevealQueue(Rc<RefCell<QueueState>>);
+
+        impl std_fmt::Debug for RevealQueue {
+            fn fmt(&self, f: &mut std_fmt::Formatter<'_>) -> std_fmt::Result {
+                f.write_str("RevealQueue { .. }")
+            }
+        }
+
+        impl PartialEq for RevealQueue {
+            fn eq(&self, other: &Self) -> bool {
+                Rc::ptr_eq(&self.0, &other.0)
+            }
+        }
+
+        impl RevealQueue {
+            pub fn new(reveal_order: RevealOrder, tail: SuspenseListTail) -> Self {
+                Self(Rc::new(RefCell::new(QueueState {
+                    reveal_order,
+                    tail,
+                    slots: Vec::new(),
+                    ready: VecDeque::new(),
+                    nested: SelectAll::new(),
+                    waker: None,
+                })))
+            }
+
+            /// Returns whether no boundary has been deferred.
+            pub fn is_empty(&self) -> bool {
+                self.0.borrow().slots.is_empty()
+            }
+
+            /// Streams the swaps of the boundaries, the stream ends once all children have been
+            /// revealed.
+            pub fn stream(&self) -> Swaps {
+                let queue = self.clone();
+
+                stream::poll_fn(move |cx| {
+                    let mut state = queue.0.borrow_mut();
+                    state.advance();
+
+                    if let Some(m) = state.ready.pop_front() {
+                        return Poll::Ready(Some(m));
+                    }
+
+                    let mut nested = mem::take(&mut state.nested);
+                    drop(state);
+                    let next = match nested.is_empty() {
+                        true => Poll::Ready(None),
+                        false => nested.poll_next_unpin(cx),
+                    };
+                    let mut state = queue.0.borrow_mut();
+                    state.nested = nested;
+
+                    match next {
+                        Poll::Ready(Some(m)) => Poll::Ready(Some(m)),
+                        _ if state.slots.iter().all(|m| m.revealed) && state.nested.is_empty() => {
+                            Poll::Ready(None)
+                        }
+                        _ => {
+                            state.waker = Some(cx.waker().clone());
+                            Poll::Pending
+                        }
+                    }
+                })
+                .boxed_local()
+            }
+        }
+
+        /// The child of a list a deferred boundary belongs to.
+        #[derive(Debug, Clone, PartialEq)]
+        pub(crate) struct RevealSlot {
+            pub(super) queue: RevealQueue,
+            pub(super) index: usize,
+        }
+
+        impl RevealSlot {
+            /// Finds the child of a list the content of the component at `scope` belongs to, if
+            /// its boundaries are swapped in by the list.
+            pub(crate) fn find(scope: &AnyScope) -> Option<Self> {
+                for m in iter::successors(scope.get_parent(), |m| m.get_parent()) {
+                    let type_id = *m.get_type_id();
+
+                    if type_id == TypeId::of::<BaseSuspense>() {
+                        return None;
+                    } else if type_id == TypeId::of::<ContextProvider<SuspenseListSlot>>() {
+                        return scope
+                            .context::<SuspenseListSlot>(Callback::noop())
+                            .and_then(|(m, _)| m.reveal_slot());
+                    }
+                }
+
+                None
+            }
+
+            /// Returns whether the fallback of a deferred boundary is rendered in place, otherwise
+            /// it is swapped in once the list shows it.
+            pub(crate) fn fallback_in_place(&self) -> bool {
+                self.queue.0.borrow().tail == SuspenseListTail::Visible
+            }
+
+            /// Registers the deferred boundary marked with `id`.
+            pub(crate) fn register(&self, id: usize, fallback: Option<String>) -> RevealTicket {
+                let mut state = self.queue.0.borrow_mut();
+
+                if state.slots.len() <= self.index {
+                    state.slots.resize_with(self.index + 1, Slot::default);
+                }
+                let boundaries = &mut state.slots[self.index].boundaries;
+                boundaries.push(Boundary {
+                    id,
+                    fallback,
+                    content: Content::Pending,
+                });
+
+                RevealTicket {
+                    queue: self.queue.clone(),
+                    index: self.index,
+                    position: boundaries.len() - 1,
+                }
+            }
+        }
+
+        /// Resolves a deferred boundary, the boundary is given up if it is dropped before.
+        pub(crate) struct RevealTicket {
+            queue: RevealQueue,
+            index: usize,
+            position: usize,
+        }
+
+        impl RevealTicket {
+            fn set(&self, content: Content) {
+                let mut state = self.queue.0.borrow_mut();
+                let boundary = &mut state.slots[self.index].boundaries[self.position];
+
+                if matches!(boundary.content, Content::Pending) {
+                    boundary.content = content;
+                    state.wake();
+                }
+            }
+
+            /// Queues `swap`, the swap of the content of the boundary, and the swaps of the
+            /// boundaries nested in it until the list reveals it.
+            pub(crate) fn resolve(self, swap: String, nested: Vec<Swaps>) {
+                self.set(Content::Resolved(swap, nested));
+            }
+        }
+
+        impl Drop for RevealTicket {
+            fn drop(&mut self) {
+                self.set(Content::Done);
+            }
+        }
+    }
+
+    #[cfg(feature = "ssr")]
+    pub(crate) use feat_ssr::RevealSlot;
+
+    /// Collects the children of a list, unkeyed fragments are coordinated child by child.
+    fn flatten_children(node: &Html, items: &mut Vec<Html>) {
+        match node {
+            VNode::VList(m) if m.key.is_none() => {
+                for child in m.iter() {
+                    flatten_children(child, items);
+                }
+            }
+            _ => items.push(node.clone()),
+        }
+    }
+
+    /// Returns whether each child is revealed and whether it shows its fallback.
+    fn reveal(order: RevealOrder, tail: SuspenseListTail, suspended: &[bool]) -> Vec<(bool, bool)> {
+        let ready_until = |ready: &mut bool, suspended: &bool| {
+            *ready &= !*suspended;
+            Some(*ready)
+        };
+
+        let revealed = match order {
+            RevealOrder::Forwards => suspended.iter().scan(true, ready_until).collect(),
+            RevealOrder::Backwards => {
+                let mut revealed = suspended
+                    .iter()
+                    .rev()
+                    .scan(true, ready_until)
+                    .collect::<Vec<_>>();
+                revealed.reverse();
+                revealed
+            }
+            RevealOrder::Together => {
+                let ready = !suspended.iter().any(|m| *m);
+                vec![ready; suspended.len()]
+            }
+        };
+
+        let next = match order {
+            RevealOrder::Backwards => revealed.iter().rposition(|m| !*m),
+            RevealOrder::Forwards | RevealOrder::Together => revealed.iter().position(|m| !*m),
+        };
+
+        revealed
+            .into_iter()
+            .enumerate()
+            .map(|(i, revealed)| {
+                let show_fallback = match tail {
+                    SuspenseListTail::Visible => true,
+                    SuspenseListTail::Collapsed => next == Some(i),
+                    SuspenseListTail::Hidden => false,
+                };
+
+                (revealed, show_fallback)
+            })
+            .collect()
+    }
+
+    /// Coordinates the order in which the [`Suspense`](super::super::Suspense) boundaries among
+    /// its children are revealed.
+    ///
+    /// Each child of the list is revealed according to `reveal_order` once the closest suspense
+    /// boundaries in it have finished loading, so the layout does not shift as content pops in at
+    /// random. Children which are ready but are not revealed yet keep showing their fallback, or
+    /// no fallback at all according to `tail`.
+    ///
+    /// During an out-of-order server-side render, the content of the boundaries is swapped in
+    /// following `reveal_order` and `tail` as well. Otherwise, the content is streamed in document
+    /// order. For [`RevealOrder::Backwards`] and [`RevealOrder::Together`], the list is then sent
+    /// once all of its children have finished rendering.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use yew::prelude::*;
+    /// use yew::suspense::{RevealOrder, SuspenseList, SuspenseListTail};
+    /// # #[component]
+    /// # fn Feed() -> Html { html! {} }
+    /// # #[component]
+    /// # fn Comments() -> Html { html! {} }
+    ///
+    /// #[component]
+    /// fn App() -> Html {
+    ///     let fallback = html! {"Loading..."};
+    ///
+    ///     html! {
+    ///         <SuspenseList reveal_order={RevealOrder::Forwards} tail={SuspenseListTail::Collapsed}>
+    ///             <Suspense fallback={fallback.clone()}>
+    ///                 <Feed />
+    ///             </Suspense>
+    ///             <Suspense {fallback}>
+    ///                 <Comments />
+    ///             </Suspense>
+    ///         </SuspenseList>
+    ///     }
+    /// }
+    /// ```
+    #[component]
+    pub fn SuspenseList(props: &SuspenseListProps) -> Html {
+        let SuspenseListProps {
+            children,
+            reveal_order,
+            tail,
+        } = props.clone();
+
+        html! {
+            <BaseSuspenseList {reveal_order} {tail}>
+                {children}
+            </BaseSuspenseList>
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        const VISIBLE: SuspenseListTail = SuspenseListTail::Visible;
+
+        #[test]
+        fn reveals_forwards() {
+            let slots = reveal(RevealOrder::Forwards, VISIBLE, &[false, true, false]);
+            assert_eq!(slots, [(true, true), (false, true), (false, true)]);
+        }
+
+        #[test]
+        fn reveals_backwards() {
+            let slots = reveal(RevealOrder::Backwards, VISIBLE, &[false, true, false]);
+            assert_eq!(slots, [(false, true), (false, true), (true, true)]);
+        }
+
+        #[test]
+        fn reveals_together() {
+            let slots = reveal(RevealOrder::Together, VISIBLE, &[false, true, false]);
+            assert_eq!(slots, [(false, true), (false, true), (false, true)]);
+
+            let slots = reveal(RevealOrder::Together, VISIBLE, &[false, false]);
+            assert_eq!(slots, [(true, true), (true, true)]);
+        }
+
+        #[test]
+        fn collapses_tail() {
+            let tail = SuspenseListTail::Collapsed;
+
+            let slots = reveal(RevealOrder::Forwards, tail, &[false, true, false]);
+            assert_eq!(slots, [(true, false), (false, true), (false, false)]);
+
+            let slots = reveal(RevealOrder::Backwards, tail, &[false, true, false]);
+            assert_eq!(slots, [(false, false), (false, true), (true, false)]);
+        }
+
+        #[test]
+        fn hides_tail() {
+            let slots = reveal(
+                RevealOrder::Forwards,
+                SuspenseListTail::Hidden,
+                &[true, false],
+            );
+            assert_eq!(slots, [(false, false), (false, false)]);
+        }
+    }
+}
+
+#[cfg(any(feature = "csr", feature = "ssr"))]
+pub use feat_csr_ssr::SuspenseList;
+#[cfg(any(feature = "csr", feature = "ssr"))]
+pub(crate) use feat_csr_ssr::*;
+
+#[cfg(not(any(feature = "ssr", feature = "csr")))]
+mod feat_no_csr_ssr {
+    use super::*;
+    use crate::component;
+
+    /// Coordinates the order in which the suspense boundaries among its children are revealed.
+    #[component]
+    pub fn SuspenseList(_props: &SuspenseListProps) -> Html {
+        Html::default()
+    }
+}
+
+#[cfg(not(any(feature = "ssr", feature = "csr")))]
+pub use feat_no_csr_ssr::*;
+
+#[cfg(all(test, not(target_arch = "wasm32"), feature = "ssr"))]
+mod ssr_tests {
+    use std::time::Duration;
+
+    use tokio::task::LocalSet;
+    use tokio::test;
+
+    use crate::ServerRenderer;
+    use crate::html::{BaseComponent, RenderError};
+    use crate::platform::time::sleep;
+    use crate::prelude::*;
+    use crate::suspense::{RevealOrder, SuspenseList, SuspenseListTail, use_future};
+
+    #[derive(PartialEq, Properties, Debug)]
+    struct ItemProps {
+        name: &'static str,
+        delay: u64,
+    }
+
+    #[component]
+    fn Item(props: &ItemProps) -> HtmlResult {
+        let delay = props.delay;
+        use_future(move || sleep(Duration::from_millis(delay)))?;
+
+        Ok(html! { <div>{props.name}</div> })
+    }
+
+    #[derive(PartialEq, Properties, Debug)]
+    struct ListProps {
+        reveal_order: RevealOrder,
+        #[prop_or_default]
+        tail: SuspenseListTail,
+    }
+
+    #[component]
+    fn List(props: &ListProps) -> Html {
+        let fallback = html! {"loading..."};
+
+        html! {
+            <SuspenseList reveal_order={props.reveal_order} tail={props.tail}>
+                <Suspense fallback={fallback.clone()}>
+                    <Item name="feed" delay=10 />
+                </Suspense>
+                <Suspense {fallback}>
+                    <Item name="comments" delay=30 />
+                </Suspense>
+            </SuspenseList>
+        }
+    }
+
+    async fn render(reveal_order: RevealOrder) -> String {
+        LocalSet::new()
+            .run_until(async move {
+                ServerRenderer::<List>::with_props(move || ListProps {
+                    reveal_order,
+                    tail: SuspenseListTail::Visible,
+                })
+                .hydratable(false)
+                .render()
+                .await
+            })
+            .await
+    }
+
+    #[test]
+    async fn renders_children_in_document_order() {
+        for reveal_order in [
+            RevealOrder::Forwards,
+            RevealOrder::Backwards,
+            RevealOrder::Together,
+        ] {
+            assert_eq!(
+                render(reveal_order).await,
+                "<div>feed</div><div>comments</div>"
+            );
+        }
+    }
+
+    /// Renders out of order, returns the shell and the swaps streamed after it.
+    async fn render_out_of_order<COMP>(props: COMP::Properties) -> (String, String)
+    where
+        COMP: BaseComponent,
+        COMP::Properties: Send,
+    {
+        let s = LocalSet::new()
+            .run_until(async move {
+                ServerRenderer::<COMP>::with_props(move || props)
+                    .hydratable(false)
+                    .out_of_order(true)
+                    .render()
+                    .await
+            })
+            .await;
+
+        let (shell, rest) = s.split_once("<script>function __yew_swap").unwrap();
+        let (_, swaps) = rest.split_once("</script>").unwrap();
+
+        (shell.to_string(), swaps.to_string())
+    }
+
+    /// Swaps in the content of the boundary with `id`.
+    fn content(id: usize, name: &str) -> String {
+        format!(
+            r#"<template id="yew-s-{id}"><div>{name}</div></template><script>__yew_swap({id},0)</script>"#
+        )
+    }
+
+    /// Swaps in the fallback of the boundary with `id`.
+    fn fallback(id: usize) -> String {
+        format!(
+            r#"<template id="yew-s-{id}">loading...</template><script>__yew_swap({id},2)</script>"#
+        )
+    }
+
+    #[test]
+    async fn streams_children_in_reveal_order() {
+        use RevealOrder::*;
+        use SuspenseListTail::*;
+
+        let shown = "<!--<?0>-->loading...<!--</?>--><!--<?1>-->loading...<!--</?>-->";
+        let hidden = "<!--<?0>--><!--</?>--><!--<?1>--><!--</?>-->";
+        let feed = content(0, "feed");
+        let comments = content(1, "comments");
+
+        let cases = [
+            (
+                Forwards,
+                Visible,
+                shown,
+                [feed.clone(), comments.clone()].concat(),
+            ),
+            (
+                Backwards,
+                Visible,
+                shown,
+                [comments.clone(), feed.clone()].concat(),
+            ),
+            (
+                Forwards,
+                Hidden,
+                hidden,
+                [feed.clone(), comments.clone()].concat(),
+            ),
+            (
+                Forwards,
+                Collapsed,
+                hidden,
+                [fallback(0), feed.clone(), fallback(1), comments.clone()].concat(),
+            ),
+            (
+                Backwards,
+                Collapsed,
+                hidden,
+                [fallback(1), comments.clone(), feed.clone()].concat(),
+            ),
+            (
+                Together,
+                Collapsed,
+                hidden,
+                [fallback(0), feed.clone(), comments.clone()].concat(),
+            ),
+        ];
+
+        for (reveal_order, tail, shell, swaps) in cases {
+            assert_eq!(
+                render_out_of_order::<List>(ListProps { reveal_order, tail }).await,
+                (shell.to_string(), swaps),
+                "{reveal_order:?}, {tail:?}"
+            );
+        }
+    }
+
+    #[test]
+    async fn queues_fallback_of_error_boundary() {
+        #[component]
+        fn Failing() -> HtmlResult {
+            use_future(|| sleep(Duration::from_millis(10)))?;
+
+            Err(RenderError::failed(std::fmt::Error))
+        }
+
+        #[component]
+        fn Page() -> Html {
+            let fallback = html! {"loading..."};
+
+            html! {
+                <SuspenseList reveal_order={RevealOrder::Backwards}>
+                    <ErrorBoundary fallback={Callback::from(|_| html! {"failed"})}>
+                        <Suspense fallback={fallback.clone()}>
+                            <Failing />
+                        </Suspense>
+                    </ErrorBoundary>
+                    <Suspense {fallback}>
+                        <Item name="comments" delay=30 />
+                    </Suspense>
+                </SuspenseList>
+            }
+        }
+
+        let (shell, swaps) = render_out_of_order::<Page>(()).await;
+
+        assert_eq!(
+            shell,
+            "<!--<?1>--><!--<?0>-->loading...<!--</?>--><!--</?>--><!--<?2>-->loading...<!--</?\
+             >-->"
+        );
+        // The fallback of the boundary waits for the last child to be revealed first.
+        assert_eq!(
+            swaps,
+            [
+                content(2, "comments"),
+                r#"<template id="yew-s-0"></template><script>__yew_swap(0,0)</script>"#.into(),
+                concat!(
+                    r#"<template id="yew-s-1">failed<script type="application/x-yew-comp-state">AQ==</script></template>"#,
+                    "<script>__yew_swap(1,0)</script>",
+                )
+                .into(),
+            ]
+            .concat()
+        );
+    }
+}
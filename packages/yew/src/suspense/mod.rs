@@ -2,10 +2,20 @@
 
 mod component;
 mod hooks;
+mod list;
 mod suspension;
+mod transition;
 
 #[cfg(any(feature = "csr", feature = "ssr"))]
 pub(crate) use component::BaseSuspense;
 pub use component::{Suspense, SuspenseProps};
 pub use hooks::*;
+#[cfg(feature = "ssr")]
+pub(crate) use list::RevealSlot;
+#[cfg(any(feature = "csr", feature = "ssr"))]
+pub(crate) use list::SuspenseListSlot;
+pub use list::{RevealOrder, SuspenseList, SuspenseListProps, SuspenseListTail};
 pub use suspension::{Suspension, SuspensionHandle, SuspensionResult};
+#[cfg(feature = "csr")]
+pub(crate) use transition::Transition;
+pub use transition::{StartTransition, use_transition};
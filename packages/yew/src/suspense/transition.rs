@@ -0,0 +1,224 @@
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::rc::Rc;
+
+use crate::functional::{Reducible, UseReducerDispatcher, hook, use_reducer};
+use crate::scheduler::{Priority, with_priority};
+
+thread_local! {
+    static CURRENT_TRANSITION: RefCell<Option<Transition>> = const { RefCell::new(None) };
+}
+
+struct TransitionInner {
+    on_finish: Cell<Option<Box<dyn FnOnce()>>>,
+}
+
+impl Drop for TransitionInner {
+    fn drop(&mut self) {
+        if let Some(m) = self.on_finish.take() {
+            m();
+        }
+    }
+}
+
+/// A transition started with [`StartTransition::run`].
+///
+/// Components which are scheduled to render as part of a transition hold a clone of it until
+/// their render has been committed. The transition finishes once the last clone is dropped.
+#[derive(Clone)]
+pub(crate) struct Transition {
+    // Only held for its `Drop` implementation.
+    _inner: Rc<TransitionInner>,
+}
+
+impl fmt::Debug for Transition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Transition").finish_non_exhaustive()
+    }
+}
+
+impl Transition {
+    fn new(on_finish: impl FnOnce() + 'static) -> Self {
+        Self {
+            _inner: Rc::new(TransitionInner {
+                on_finish: Cell::new(Some(Box::new(on_finish))),
+            }),
+        }
+    }
+
+    /// Returns the transition work is currently being scheduled for, if any.
+    #[cfg(feature = "csr")]
+    pub(crate) fn current() -> Option<Self> {
+        CURRENT_TRANSITION.with(|m| m.borrow().clone())
+    }
+
+    /// Makes this transition current until the returned guard is dropped.
+    pub(crate) fn enter(&self) -> TransitionGuard {
+        let prev = CURRENT_TRANSITION.with(|m| m.replace(Some(self.clone())));
+
+        TransitionGuard { prev }
+    }
+}
+
+/// Restores the previously current transition when dropped.
+pub(crate) struct TransitionGuard {
+    prev: Option<Transition>,
+}
+
+impl Drop for TransitionGuard {
+    fn drop(&mut self) {
+        let prev = self.prev.take();
+        CURRENT_TRANSITION.with(|m| *m.borrow_mut() = prev);
+    }
+}
+
+#[derive(Default, PartialEq)]
+struct PendingTransitions {
+    count: usize,
+}
+
+enum PendingTransitionsAction {
+    Start,
+    Finish,
+}
+
+impl Reducible for PendingTransitions {
+    type Action = PendingTransitionsAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let count = match action {
+            PendingTransitionsAction::Start => self.count + 1,
+            PendingTransitionsAction::Finish => self.count.saturating_sub(1),
+        };
+
+        Self { count }.into()
+    }
+}
+
+/// Starts transitions, returned by [`use_transition`].
+#[derive(Clone, PartialEq)]
+pub struct StartTransition {
+    pending: UseReducerDispatcher<PendingTransitions>,
+}
+
+impl fmt::Debug for StartTransition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StartTransition").finish_non_exhaustive()
+    }
+}
+
+impl StartTransition {
+    /// Runs `f` and marks all updates scheduled by it as a transition.
+    ///
+    /// The updates are rendered with [`Priority::Transition`], so more urgent updates, such as
+    /// user input, are rendered first.
+    ///
+    /// If a component that has already been rendered suspends while rendering one of these
+    /// updates, its previously committed content stays visible instead of the fallback of the
+    /// closest [`Suspense`](crate::suspense::Suspense) until the suspension resolves.
+    pub fn run<F>(&self, f: F)
+    where
+        F: FnOnce(),
+    {
+        let pending = self.pending.clone();
+        let transition = Transition::new(move || {
+            pending.dispatch(PendingTransitionsAction::Finish);
+        });
+
+        {
+            let _guard = transition.enter();
+            with_priority(Priority::Transition, f);
+        }
+
+        // Marked as pending after `f` has run, updates of this component would otherwise be
+        // scheduled by this message, outside of the transition.
+        self.pending.dispatch(PendingTransitionsAction::Start);
+    }
+}
+
+/// This hook is used to update state without hiding content that has already been rendered.
+///
+/// Returns whether a transition started by this hook is still pending and a [`StartTransition`]
+/// handle to start new transitions. Updates made inside of [`StartTransition::run`] keep
+/// showing the previously committed content of components that suspend while rendering them,
+/// instead of the fallback of the closest [`Suspense`](crate::suspense::Suspense). Components
+/// that are mounted for the first time still suspend as usual.
+///
+/// # Example
+///
+/// ```
+/// # use yew::prelude::*;
+/// use yew::suspense::use_transition;
+///
+/// #[derive(Properties, PartialEq)]
+/// struct TabProps {
+///     tab: usize,
+/// }
+///
+/// #[component]
+/// fn TabContent(props: &TabProps) -> Html {
+///     // Suspends while the content of `props.tab` is loading.
+/// #   let _ = props.tab;
+///     html! {}
+/// }
+///
+/// #[component]
+/// fn Tabs() -> Html {
+///     let tab = use_state(|| 0);
+///     let (is_pending, start_transition) = use_transition();
+///
+///     let onclick = {
+///         let tab = tab.clone();
+///         move |_| {
+///             let tab = tab.clone();
+///             start_transition.run(move || tab.set(*tab + 1));
+///         }
+///     };
+///
+///     html! {
+///         <>
+///             <button {onclick} disabled={is_pending}>{"Next"}</button>
+///             <Suspense fallback={html! {"Loading..."}}>
+///                 <TabContent tab={*tab} />
+///             </Suspense>
+///         </>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_transition() -> (bool, StartTransition) {
+    let pending = use_reducer(PendingTransitions::default);
+
+    let start_transition = StartTransition {
+        pending: pending.dispatcher(),
+    };
+
+    (pending.count > 0, start_transition)
+}
+
+#[cfg(all(test, feature = "csr"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finishes_when_last_clone_is_dropped() {
+        let finished = Rc::new(Cell::new(false));
+        let transition = {
+            let finished = finished.clone();
+            Transition::new(move || finished.set(true))
+        };
+
+        let held = {
+            let _guard = transition.enter();
+            Transition::current()
+        };
+        assert!(held.is_some());
+        assert!(Transition::current().is_none());
+
+        drop(transition);
+        assert!(!finished.get());
+
+        drop(held);
+        assert!(finished.get());
+    }
+}
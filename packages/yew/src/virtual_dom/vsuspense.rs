@@ -1,5 +1,11 @@
 use super::{Key, VNode};
+#[cfg(feature = "hydration")]
+use crate::callback::Callback;
 use crate::html::ImplicitClone;
+#[cfg(feature = "hydration")]
+use crate::hydration::HydrateOn;
+#[cfg(feature = "ssr")]
+use crate::suspense::RevealSlot;
 
 /// This struct represents a suspendable DOM fragment.
 #[derive(Clone, ImplicitClone, Debug, PartialEq)]
@@ -12,6 +18,14 @@ pub struct VSuspense {
     pub(crate) suspended: bool,
     /// The Key.
     pub(crate) key: Option<Key>,
+    /// When the server-side rendered children are hydrated and the callback to notify once the
+    /// condition has been met, if hydration is deferred.
+    #[cfg(feature = "hydration")]
+    pub(crate) lazy: Option<(HydrateOn, Callback<()>)>,
+    /// The child of a list whose boundaries are swapped in by the list, if the boundary is
+    /// coordinated by a list during server-side rendering.
+    #[cfg(feature = "ssr")]
+    pub(crate) reveal: Option<RevealSlot>,
 }
 
 impl VSuspense {
@@ -21,16 +35,26 @@ impl VSuspense {
             fallback,
             suspended,
             key,
+            #[cfg(feature = "hydration")]
+            lazy: None,
+            #[cfg(feature = "ssr")]
+            reveal: None,
         }
     }
 }
 
 #[cfg(feature = "ssr")]
 mod feat_ssr {
+    use std::fmt::Write;
+    use std::future::Future;
+    use std::task::Poll;
+
+    use futures::{FutureExt, StreamExt, future};
+
     use super::*;
-    use crate::feat_ssr::VTagKind;
+    use crate::feat_ssr::{DeferredBoundaries, SwapMode, VTagKind, swap};
     use crate::html::AnyScope;
-    use crate::platform::fmt::BufWriter;
+    use crate::platform::fmt::{self, BufWriter};
     use crate::virtual_dom::Collectable;
 
     impl VSuspense {
@@ -41,6 +65,12 @@ mod feat_ssr {
             hydratable: bool,
             parent_vtag_kind: VTagKind,
         ) {
+            if let Some(deferred) = DeferredBoundaries::current() {
+                self.render_out_of_order(w, &deferred, parent_scope, hydratable, parent_vtag_kind)
+                    .await;
+                return;
+            }
+
             let collectable = Collectable::Suspense;
 
             if hydratable {
@@ -56,6 +86,147 @@ mod feat_ssr {
                 collectable.write_close_tag(w);
             }
         }
+
+        /// Renders the children in place if none of them suspends. Otherwise, the fallback is
+        /// rendered in place and the children are streamed once they have been resolved.
+        ///
+        /// Boundaries coordinated by a list are always deferred and swapped in by the list.
+        async fn render_out_of_order(
+            &self,
+            w: &mut BufWriter,
+            deferred: &DeferredBoundaries,
+            parent_scope: &AnyScope,
+            hydratable: bool,
+            parent_vtag_kind: VTagKind,
+        ) {
+            let collectable = Collectable::Suspense;
+
+            let nested = deferred.nested();
+            let (mut buf_w, mut buf_r) = fmt::buffer();
+            let mut render = {
+                let children = self.children.clone();
+                let parent_scope = parent_scope.clone();
+
+                Box::pin(nested.clone().run(async move {
+                    children
+                        .render_into_stream(&mut buf_w, &parent_scope, hydratable, parent_vtag_kind)
+                        .await;
+                }))
+            };
+
+            // Children that do not suspend are rendered when they are polled for the first time.
+            let ready =
+                future::poll_fn(|cx| Poll::Ready(render.as_mut().poll(cx).is_ready())).await;
+
+            if let Some(ref slot) = self.reveal {
+                let id = deferred.next_id();
+
+                // The fallback is swapped in by the list if it is not shown right away.
+                let fallback = if slot.fallback_in_place() {
+                    self.write_marked(w, id, Some(parent_scope), hydratable, parent_vtag_kind)
+                        .await;
+                    None
+                } else {
+                    self.write_marked(w, id, None, hydratable, parent_vtag_kind)
+                        .await;
+
+                    let (mut fallback_w, fallback_r) = fmt::buffer();
+                    self.fallback
+                        .render_into_stream(
+                            &mut fallback_w,
+                            parent_scope,
+                            hydratable,
+                            parent_vtag_kind,
+                        )
+                        .await;
+                    drop(fallback_w);
+
+                    Some(fallback_r.collect::<String>().await)
+                };
+
+                let ticket = slot.register(id, fallback);
+                deferred.push(
+                    async move {
+                        render.await;
+                        let html = buf_r.collect::<String>().await;
+                        let swap = swap(id, Some(&html), SwapMode::content(hydratable));
+
+                        ticket.resolve(swap, nested.take());
+                    }
+                    .into_stream()
+                    .filter_map(|()| future::ready(None))
+                    .boxed_local(),
+                );
+
+                return;
+            }
+
+            if ready {
+                deferred.append(&nested);
+
+                if hydratable {
+                    collectable.write_open_tag(w);
+                }
+
+                while let Some(m) = buf_r.next().await {
+                    let _ = w.write_str(m.as_str());
+                }
+
+                if hydratable {
+                    collectable.write_close_tag(w);
+                }
+
+                return;
+            }
+
+            let id = deferred.defer(
+                async move {
+                    render.await;
+                    buf_r.collect::<String>().await
+                },
+                nested,
+                hydratable,
+            );
+
+            self.write_marked(w, id, Some(parent_scope), hydratable, parent_vtag_kind)
+                .await;
+        }
+
+        /// Writes the markers of the pending boundary with `id`, with the fallback in between if
+        /// it is rendered with `parent_scope`.
+        async fn write_marked(
+            &self,
+            w: &mut BufWriter,
+            id: usize,
+            parent_scope: Option<&AnyScope>,
+            hydratable: bool,
+            parent_vtag_kind: VTagKind,
+        ) {
+            let collectable = Collectable::Suspense;
+
+            // Pending boundaries are marked with their id, so that their content can be swapped
+            // in later. The markers are written even if the output is not hydratable.
+            let _ = write!(
+                w,
+                "<!--{}{}{}-->",
+                collectable.open_start_mark(),
+                id,
+                collectable.end_mark()
+            );
+
+            if let Some(parent_scope) = parent_scope {
+                self.fallback
+                    .render_into_stream(w, parent_scope, hydratable, parent_vtag_kind)
+                    .await;
+            }
+
+            let _ = write!(
+                w,
+                "<!--{}{}-->",
+                collectable.close_start_mark(),
+                collectable.end_mark()
+            );
+        }
     }
 }
 
@@ -157,4 +328,95 @@ mod ssr_tests {
             "<div>Hello, Jane!</div><div>Hello, John!</div><div>Hello, Josh!</div>"
         );
     }
+
+    #[derive(PartialEq, Properties, Debug)]
+    struct SlowProps {
+        name: &'static str,
+        delay: u64,
+    }
+
+    #[component]
+    fn Slow(props: &SlowProps) -> HtmlResult {
+        let delay = props.delay;
+        crate::suspense::use_future(move || sleep(Duration::from_millis(delay)))?;
+
+        Ok(html! { <div>{props.name}</div> })
+    }
+
+    #[component]
+    fn Page() -> Html {
+        html! {
+            <div>{"header"}</div>
+            <Suspense fallback={html! {"loading feed..."}}>
+                <Slow name="feed" delay=30 />
+                <Suspense fallback={html! {"loading comments..."}}>
+                    <Slow name="comments" delay=60 />
+                </Suspense>
+            </Suspense>
+            <Suspense fallback={html! {"loading sidebar..."}}>
+                <div>{"sidebar"}</div>
+            </Suspense>
+            <div>{"footer"}</div>
+        }
+    }
+
+    #[cfg(not(target_os = "wasi"))]
+    #[test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_out_of_order() {
+        use futures::StreamExt;
+
+        let s = ServerRenderer::<Page>::new()
+            .hydratable(false)
+            .out_of_order(true)
+            .render_stream();
+        futures::pin_mut!(s);
+
+        // The shell is streamed before any of the suspended children have been resolved.
+        let mut shell = String::new();
+        while !shell.contains("<script>") {
+            shell.push_str(&s.next().await.unwrap());
+        }
+        let (shell, rest) = shell.split_at(shell.find("<script>").unwrap());
+        assert_eq!(
+            shell,
+            "<div>header</div><!--<?1>-->loading \
+             feed...<!--</?>--><div>sidebar</div><div>footer</div>"
+        );
+
+        let rest = rest.to_string() + &s.collect::<String>().await;
+        let rest = &rest[rest.find("</script>").unwrap() + "</script>".len()..];
+
+        // Nested boundaries are streamed after their parent.
+        assert_eq!(
+            rest,
+            concat!(
+                r#"<template id="yew-s-1"><div>feed</div><!--<?0>-->loading comments...<!--</?>--></template>"#,
+                "<script>__yew_swap(1,0)</script>",
+                r#"<template id="yew-s-0"><div>comments</div></template>"#,
+                "<script>__yew_swap(0,0)</script>",
+            )
+        );
+    }
+
+    #[cfg(not(target_os = "wasi"))]
+    #[test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_out_of_order_hydratable() {
+        #[component]
+        fn Comp() -> Html {
+            html! {
+                <Suspense fallback={html! {"loading..."}}>
+                    <Slow name="content" delay=10 />
+                </Suspense>
+            }
+        }
+
+        let s = ServerRenderer::<Comp>::new()
+            .out_of_order(true)
+            .render()
+            .await;
+
+        // The swapped in content keeps the markers of the boundary for the hydration.
+        assert!(s.contains("<!--<?0>-->"));
+        assert!(s.ends_with("<script>__yew_swap(0,1)</script>"));
+    }
 }
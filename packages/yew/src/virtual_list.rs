@@ -0,0 +1,445 @@
+//! This module contains the [`VirtualList`] component, which renders long lists by only
+//! rendering the items that are visible.
+
+use std::cell::RefCell;
+use std::fmt;
+use std::ops::Range;
+use std::rc::Rc;
+
+use wasm_bindgen::JsCast;
+use web_sys::{Element, Event, HtmlElement};
+
+use crate::callback::Callback;
+use crate::functional::{use_effect, use_effect_with, use_force_update, use_mut_ref, use_node_ref};
+use crate::html::{Classes, Html, Properties};
+use crate::{component, html};
+
+/// How the heights of the items of a [`VirtualList`] are determined.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ItemHeight {
+    /// Every item is this many pixels high.
+    Fixed(f64),
+    /// The items are measured once they have been rendered.
+    ///
+    /// Items which have not been measured yet are estimated to be this many pixels high.
+    Measured(f64),
+}
+
+/// A handle to scroll a [`VirtualList`].
+///
+/// Like a [`NodeRef`](crate::html::NodeRef), the handle is attached to a list by passing it as
+/// its `handle` property.
+#[derive(Default, Clone)]
+pub struct VirtualListHandle(Rc<RefCell<Option<Callback<usize>>>>);
+
+impl PartialEq for VirtualListHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for VirtualListHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VirtualListHandle")
+            .field("attached", &self.0.borrow().is_some())
+            .finish()
+    }
+}
+
+impl VirtualListHandle {
+    /// Scrolls the list so that the item at `index` is at the top of the list.
+    ///
+    /// With [`ItemHeight::Measured`], the position of the item is based on the estimated height
+    /// of the items before it which have not been measured yet.
+    ///
+    /// Does nothing if the handle is not attached to a rendered list.
+    pub fn scroll_to_index(&self, index: usize) {
+        let scroll_to = self.0.borrow().clone();
+        if let Some(m) = scroll_to {
+            m.emit(index);
+        }
+    }
+}
+
+/// Properties for [VirtualList].
+#[derive(Properties, PartialEq, Debug, Clone)]
+pub struct VirtualListProps {
+    /// The number of items in the list.
+    pub item_count: usize,
+
+    /// Renders the item at an index.
+    pub item: Callback<usize, Html>,
+
+    /// How the heights of the items are determined.
+    pub item_height: ItemHeight,
+
+    /// The height of the list in pixels.
+    pub height: f64,
+
+    /// The number of items rendered before and after the visible items, so they do not have to
+    /// be rendered while scrolling just a bit.
+    #[prop_or(2)]
+    pub overscan: usize,
+
+    /// A handle to scroll the list.
+    #[prop_or_default]
+    pub handle: VirtualListHandle,
+
+    /// The classes of the scroll container.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// The positions of the items of a list.
+#[derive(Debug)]
+struct Layout {
+    item_count: usize,
+    item_height: ItemHeight,
+    /// The measured heights of the items, `None` for items which have not been measured yet.
+    measured: Vec<Option<f64>>,
+    /// The offset of every item followed by the total height, only used for measured heights.
+    offsets: Vec<f64>,
+}
+
+impl Layout {
+    fn new(item_count: usize, item_height: ItemHeight) -> Self {
+        let mut layout = Self {
+            item_count: 0,
+            item_height,
+            measured: Vec::new(),
+            offsets: vec![0.0],
+        };
+        layout.update(item_count, item_height);
+
+        layout
+    }
+
+    /// Updates the layout for a new number of items or a new way to determine their heights.
+    ///
+    /// Measurements are kept unless the item height has changed.
+    fn update(&mut self, item_count: usize, item_height: ItemHeight) {
+        if self.item_height != item_height {
+            self.measured.clear();
+        } else if self.item_count == item_count {
+            return;
+        }
+
+        self.item_count = item_count;
+        self.item_height = item_height;
+        if let ItemHeight::Measured(_) = item_height {
+            self.measured.resize(item_count, None);
+            self.compute_offsets(0);
+        }
+    }
+
+    fn compute_offsets(&mut self, from: usize) {
+        let ItemHeight::Measured(estimate) = self.item_height else {
+            return;
+        };
+
+        self.offsets.resize(self.item_count + 1, 0.0);
+        for i in from..self.item_count {
+            self.offsets[i + 1] = self.offsets[i] + self.measured[i].unwrap_or(estimate);
+        }
+    }
+
+    /// Returns the offset of the item at `index` from the top of the list.
+    fn offset(&self, index: usize) -> f64 {
+        let index = index.min(self.item_count);
+        match self.item_height {
+            ItemHeight::Fixed(height) => index as f64 * height,
+            ItemHeight::Measured(_) => self.offsets[index],
+        }
+    }
+
+    fn total_height(&self) -> f64 {
+        self.offset(self.item_count)
+    }
+
+    /// Returns the items visible in a viewport of `height` scrolled to `scroll_top`, with
+    /// `overscan` more items on both sides.
+    fn window(&self, scroll_top: f64, height: f64, overscan: usize) -> Range<usize> {
+        let bottom = scroll_top + height;
+        let (start, end) = match self.item_height {
+            // Float to integer casts saturate, a negative offset is the first item.
+            ItemHeight::Fixed(item_height) if item_height > 0.0 => (
+                (scroll_top / item_height).floor() as usize,
+                (bottom / item_height).ceil() as usize,
+            ),
+            ItemHeight::Fixed(_) => (0, self.item_count),
+            ItemHeight::Measured(_) => (
+                // The first item ending below the top and the first item starting at the bottom.
+                self.offsets[1..].partition_point(|m| *m <= scroll_top),
+                self.offsets[..self.item_count].partition_point(|m| *m < bottom),
+            ),
+        };
+
+        let end = end.saturating_add(overscan).min(self.item_count);
+        let start = start.saturating_sub(overscan).min(end);
+
+        start..end
+    }
+
+    /// Records the heights of the items starting at `start`.
+    ///
+    /// Returns `true` if the offsets of the items have changed.
+    fn measure(&mut self, start: usize, heights: impl IntoIterator<Item = f64>) -> bool {
+        if let ItemHeight::Fixed(_) = self.item_height {
+            return false;
+        }
+
+        let mut changed = None;
+        for (i, height) in (start..self.item_count).zip(heights) {
+            if self.measured[i] != Some(height) {
+                self.measured[i] = Some(height);
+                changed.get_or_insert(i);
+            }
+        }
+
+        match changed {
+            Some(from) => {
+                self.compute_offsets(from);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// A list which only renders the items that are visible in its scroll container.
+///
+/// Mounting and reconciling a list with thousands of items is slow, even if only a few of them
+/// fit on the screen. A `VirtualList` renders the visible items, `overscan` items before and
+/// after them, and a spacer which gives the list the height of all items. Each item is rendered
+/// by calling `item` with its index and is wrapped in an absolutely positioned element keyed by
+/// that index, so the items which stay in view keep their DOM nodes while scrolling and only the
+/// items scrolled into and out of view are created and removed.
+///
+/// The heights of the items are either [fixed](ItemHeight::Fixed) or
+/// [measured](ItemHeight::Measured) after the items have been rendered. The list can be scrolled
+/// to an item with a [`VirtualListHandle`].
+///
+/// During server-side rendering, the items visible at the top of the list are rendered.
+///
+/// # Example
+///
+/// ```
+/// # use yew::prelude::*;
+/// use yew::virtual_list::{ItemHeight, VirtualList, VirtualListHandle};
+///
+/// #[component]
+/// fn App() -> Html {
+///     let handle = use_state(VirtualListHandle::default);
+///     let item = Callback::from(|index: usize| html! { <p>{format!("Row {index}")}</p> });
+///     let onclick = {
+///         let handle = (*handle).clone();
+///         move |_| handle.scroll_to_index(5000)
+///     };
+///
+///     html! {
+///         <button {onclick}>{"Go to row 5000"}</button>
+///         <VirtualList
+///             item_count={10_000}
+///             {item}
+///             item_height={ItemHeight::Fixed(24.0)}
+///             height={480.0}
+///             handle={(*handle).clone()}
+///         />
+///     }
+/// }
+/// ```
+#[component]
+pub fn VirtualList(props: &VirtualListProps) -> Html {
+    let VirtualListProps {
+        item_count,
+        ref item,
+        item_height,
+        height,
+        overscan,
+        ref handle,
+        ref class,
+    } = *props;
+
+    let layout = use_mut_ref(|| Layout::new(item_count, item_height));
+    let scroll_top = use_mut_ref(|| 0.0);
+    let rendered = use_mut_ref(|| 0..0);
+    let container = use_node_ref();
+    let content = use_node_ref();
+    let force_update = use_force_update();
+
+    let window = {
+        let mut layout = layout.borrow_mut();
+        layout.update(item_count, item_height);
+        layout.window(*scroll_top.borrow(), height, overscan)
+    };
+    *rendered.borrow_mut() = window.clone();
+
+    let onscroll = {
+        let layout = layout.clone();
+        let container = container.clone();
+        let force_update = force_update.clone();
+        move |_: Event| {
+            let Some(container) = container.cast::<Element>() else {
+                return;
+            };
+            *scroll_top.borrow_mut() = container.scroll_top().into();
+
+            // Scrolling within the rendered items does not need to render the list again.
+            let window = layout
+                .borrow()
+                .window(*scroll_top.borrow(), height, overscan);
+            if window != *rendered.borrow() {
+                force_update.force_update();
+            }
+        }
+    };
+
+    {
+        let layout = layout.clone();
+        let container = container.clone();
+        use_effect_with(handle.clone(), move |handle| {
+            *handle.0.borrow_mut() = Some(Callback::from(move |index| {
+                if let Some(container) = container.cast::<Element>() {
+                    // Setting the scroll position dispatches a scroll event which renders the
+                    // items at the new position.
+                    container.set_scroll_top(layout.borrow().offset(index) as i32);
+                }
+            }));
+
+            let handle = handle.clone();
+            move || *handle.0.borrow_mut() = None
+        });
+    }
+
+    {
+        let layout = layout.clone();
+        let content = content.clone();
+        let start = window.start;
+        use_effect(move || {
+            let Some(content) = content.cast::<Element>() else {
+                return;
+            };
+
+            let items = content.children();
+            let heights = (0..items.length())
+                .filter_map(|i| items.item(i)?.dyn_into::<HtmlElement>().ok())
+                .map(|m| m.offset_height().into());
+            if layout.borrow_mut().measure(start, heights) {
+                force_update.force_update();
+            }
+        });
+    }
+
+    let layout = layout.borrow();
+    let items = window.map(|index| {
+        let top = layout.offset(index);
+        let style = match item_height {
+            ItemHeight::Fixed(height) => {
+                format!("position:absolute;top:{top}px;left:0;right:0;height:{height}px;")
+            }
+            ItemHeight::Measured(_) => format!("position:absolute;top:{top}px;left:0;right:0;"),
+        };
+
+        html! {
+            <div key={index} {style}>{item.emit(index)}</div>
+        }
+    });
+
+    html! {
+        <div
+            ref={container}
+            class={class.clone()}
+            style={format!("overflow-y:auto;height:{height}px;")}
+            {onscroll}
+        >
+            <div
+                ref={content}
+                style={format!("position:relative;height:{}px;", layout.total_height())}
+            >
+                { for items }
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_window() {
+        let layout = Layout::new(100, ItemHeight::Fixed(10.0));
+        assert_eq!(layout.window(0.0, 25.0, 0), 0..3);
+        assert_eq!(layout.window(0.0, 25.0, 2), 0..5);
+        assert_eq!(layout.window(55.0, 25.0, 2), 3..10);
+        assert_eq!(layout.window(990.0, 25.0, 2), 97..100);
+        assert_eq!(layout.window(-20.0, 25.0, 0), 0..1);
+        assert_eq!(layout.total_height(), 1000.0);
+    }
+
+    #[test]
+    fn measured_window() {
+        let mut layout = Layout::new(10, ItemHeight::Measured(10.0));
+        assert_eq!(layout.window(0.0, 25.0, 0), 0..3);
+
+        assert!(layout.measure(0, [30.0, 5.0]));
+        assert!(!layout.measure(0, [30.0, 5.0]));
+        assert_eq!(layout.offset(2), 35.0);
+        assert_eq!(layout.total_height(), 115.0);
+        assert_eq!(layout.window(0.0, 25.0, 0), 0..1);
+        assert_eq!(layout.window(32.0, 10.0, 0), 1..3);
+        assert_eq!(layout.window(32.0, 10.0, 1), 0..4);
+
+        // Measurements are kept when items are added.
+        layout.update(20, ItemHeight::Measured(10.0));
+        assert_eq!(layout.total_height(), 215.0);
+        layout.update(20, ItemHeight::Measured(20.0));
+        assert_eq!(layout.total_height(), 400.0);
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32"), feature = "ssr"))]
+mod ssr_tests {
+    use tokio::test;
+
+    use super::*;
+    use crate::LocalServerRenderer;
+    use crate::prelude::*;
+
+    #[component]
+    fn App() -> Html {
+        let item = Callback::from(|index: usize| html! { <span>{index}</span> });
+
+        html! {
+            <VirtualList
+                item_count={100}
+                {item}
+                item_height={ItemHeight::Fixed(10.0)}
+                height={25.0}
+                overscan={1}
+            />
+        }
+    }
+
+    #[test]
+    async fn renders_initial_window() {
+        let s = LocalServerRenderer::<App>::new()
+            .hydratable(false)
+            .render()
+            .await;
+
+        let item = |index: usize| {
+            format!(
+                r#"<div style="position:absolute;top:{}px;left:0;right:0;height:10px;"><span>{index}</span></div>"#,
+                index * 10
+            )
+        };
+        assert_eq!(
+            s,
+            format!(
+                r#"<div style="overflow-y:auto;height:25px;"><div style="position:relative;height:1000px;">{}</div></div>"#,
+                (0..4).map(item).collect::<String>()
+            )
+        );
+    }
+}
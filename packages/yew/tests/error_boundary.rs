@@ -0,0 +1,169 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::cell::Cell;
+use std::fmt;
+use std::rc::Rc;
+
+use common::{obtain_result, output_element};
+use gloo::utils::document;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::HtmlElement;
+use yew::error_boundary::RenderErrorInfo;
+use yew::html::RenderError;
+use yew::prelude::*;
+use yew::scheduler;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Debug)]
+struct FetchError;
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to fetch")
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+#[derive(Properties, PartialEq)]
+struct AppProps {
+    children: Html,
+}
+
+#[component]
+fn App(props: &AppProps) -> Html {
+    let fallback = Callback::from(|info: RenderErrorInfo| {
+        html! {
+            <div id="result">{info.error().to_string()}</div>
+            <button id="reset" onclick={info.reset_callback()}>{"Try again"}</button>
+        }
+    });
+
+    html! {
+        <ErrorBoundary {fallback}>
+            {props.children.clone()}
+        </ErrorBoundary>
+    }
+}
+
+async fn render(children: Html) {
+    yew::Renderer::<App>::with_root_and_props(output_element(), AppProps { children }).render();
+    scheduler::flush().await;
+}
+
+#[wasm_bindgen_test]
+async fn failed_render_shows_fallback() {
+    #[component]
+    fn Failing() -> HtmlResult {
+        Err(RenderError::Failed(Rc::new(FetchError)))
+    }
+
+    render(html! { <Failing /> }).await;
+    assert_eq!(obtain_result(), "failed to fetch");
+}
+
+#[wasm_bindgen_test]
+async fn reset_remounts_children() {
+    thread_local! {
+        static MOUNTS: Cell<u32> = const { Cell::new(0) };
+    }
+
+    #[component]
+    fn FailsOnce() -> HtmlResult {
+        let mount = *use_state(|| {
+            MOUNTS.with(|m| m.set(m.get() + 1));
+            MOUNTS.with(Cell::get)
+        });
+
+        if mount == 1 {
+            return Err(RenderError::Failed(Rc::new(FetchError)));
+        }
+        Ok(html! { <div id="result">{"mounted again"}</div> })
+    }
+
+    render(html! { <FailsOnce /> }).await;
+    assert_eq!(obtain_result(), "failed to fetch");
+
+    document()
+        .get_element_by_id("reset")
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap()
+        .click();
+    scheduler::flush().await;
+
+    assert_eq!(obtain_result(), "mounted again");
+    assert_eq!(MOUNTS.with(Cell::get), 2);
+}
+
+// Panics can only be caught if the tests are built with `-C panic=unwind`.
+#[cfg(panic = "unwind")]
+mod panics {
+    use super::*;
+
+    #[wasm_bindgen_test]
+    async fn panic_in_view_shows_fallback() {
+        #[component]
+        fn Panicking() -> Html {
+            panic!("view panicked");
+        }
+
+        render(html! { <Panicking /> }).await;
+        assert_eq!(obtain_result(), "component panicked: view panicked");
+    }
+
+    #[wasm_bindgen_test]
+    async fn panic_in_update_shows_fallback() {
+        struct Panicking;
+
+        impl Component for Panicking {
+            type Message = ();
+            type Properties = ();
+
+            fn create(ctx: &Context<Self>) -> Self {
+                ctx.link().send_message(());
+                Self
+            }
+
+            fn update(&mut self, _ctx: &Context<Self>, _msg: Self::Message) -> bool {
+                panic!("update panicked");
+            }
+
+            fn view(&self, _ctx: &Context<Self>) -> Html {
+                html! {}
+            }
+        }
+
+        render(html! { <Panicking /> }).await;
+        assert_eq!(obtain_result(), "component panicked: update panicked");
+    }
+
+    #[wasm_bindgen_test]
+    async fn panic_in_rendered_shows_fallback() {
+        struct Panicking;
+
+        impl Component for Panicking {
+            type Message = ();
+            type Properties = ();
+
+            fn create(_ctx: &Context<Self>) -> Self {
+                Self
+            }
+
+            fn view(&self, _ctx: &Context<Self>) -> Html {
+                html! {}
+            }
+
+            fn rendered(&mut self, _ctx: &Context<Self>, _first_render: bool) {
+                panic!("rendered panicked");
+            }
+        }
+
+        render(html! { <Panicking /> }).await;
+        assert_eq!(obtain_result(), "component panicked: rendered panicked");
+    }
+}
@@ -1260,3 +1260,450 @@ async fn hydration_suspended_child_does_not_trap_sibling_slot() {
         r#"<p class="new-sibling">new sibling</p><div class="suspended">child</div>"#,
     );
 }
+
+#[wasm_bindgen_test]
+async fn hydration_with_pending_out_of_order_suspense() {
+    #[component(Content)]
+    fn content() -> HtmlResult {
+        use_future(|| sleep(Duration::from_millis(30)))?;
+
+        Ok(html! { <div>{"done"}</div> })
+    }
+
+    #[component(App)]
+    fn app() -> Html {
+        let fallback = html! {<div>{"wait..."}</div>};
+
+        html! {
+            <div id="result">
+                <Suspense {fallback}>
+                    <Content />
+                </Suspense>
+            </div>
+        }
+    }
+
+    let s = ServerRenderer::<App>::new()
+        .out_of_order(true)
+        .render()
+        .await;
+
+    let output_element = gloo::utils::document().get_element_by_id("output").unwrap();
+    // Inline scripts are not executed, so the content is never swapped in.
+    output_element.set_inner_html(&s);
+
+    scheduler::flush().await;
+
+    Renderer::<App>::with_root(output_element.clone()).hydrate();
+
+    // The streamed fallback is kept while the client renders the content.
+    sleep(Duration::from_millis(10)).await;
+    let result = obtain_result();
+    assert!(result.contains("wait..."), "{result}");
+    assert!(!output_element.inner_html().contains("<template"));
+
+    sleep(Duration::from_millis(50)).await;
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "<div>done</div>");
+}
+
+#[wasm_bindgen_test]
+async fn hydration_deferred_until_interaction() {
+    use yew::hydration::{Hydrate, HydrateOn};
+
+    #[component(Comp)]
+    fn comp() -> Html {
+        let ctr = use_state_eq(|| 0);
+
+        let onclick = {
+            let ctr = ctr.clone();
+
+            Callback::from(move |_| {
+                ctr.set(*ctr + 1);
+            })
+        };
+
+        html! {
+            <div>
+                {"Counter: "}{*ctr}
+                <button {onclick} class="increase">{"+1"}</button>
+            </div>
+        }
+    }
+
+    #[component(App)]
+    fn app() -> Html {
+        html! {
+            <div id="result">
+                <Hydrate on={HydrateOn::Interaction}>
+                    <Comp />
+                </Hydrate>
+            </div>
+        }
+    }
+
+    let s = ServerRenderer::<App>::new().render().await;
+
+    let output_element = gloo::utils::document().get_element_by_id("output").unwrap();
+    output_element.set_inner_html(&s);
+
+    scheduler::flush().await;
+
+    Renderer::<App>::with_root(output_element).hydrate();
+
+    sleep(Duration::from_millis(10)).await;
+
+    let button = gloo::utils::document()
+        .query_selector(".increase")
+        .unwrap()
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap();
+
+    // The server-side rendered content is kept, but is not hydrated yet.
+    button.click();
+    sleep(Duration::from_millis(10)).await;
+    let result = obtain_result();
+    assert!(result.contains("Counter: 0"), "{result}");
+
+    button
+        .parent_element()
+        .unwrap()
+        .dispatch_event(&web_sys::Event::new("pointerover").unwrap())
+        .unwrap();
+    sleep(Duration::from_millis(10)).await;
+
+    let button = gloo::utils::document()
+        .query_selector(".increase")
+        .unwrap()
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap();
+    button.click();
+    sleep(Duration::from_millis(10)).await;
+
+    let result = obtain_result();
+    assert_eq!(
+        result.as_str(),
+        r#"<div>Counter: 1<button class="increase">+1</button></div>"#
+    );
+}
+
+#[wasm_bindgen_test]
+async fn hydration_recovers_from_mismatch() {
+    use std::cell::RefCell;
+
+    use yew::hydration::{HydrationMismatch, MismatchPolicy};
+
+    #[derive(Properties, PartialEq, Clone)]
+    struct ContentProps {
+        client: bool,
+    }
+
+    #[component(Content)]
+    fn content(props: &ContentProps) -> Html {
+        if props.client {
+            html! { <div>{"client"}</div> }
+        } else {
+            html! { <span>{"server"}</span> }
+        }
+    }
+
+    #[component(App)]
+    fn app(props: &ContentProps) -> Html {
+        html! {
+            <div id="result">
+                <p>{"hydrated"}</p>
+                <Content client={props.client} />
+            </div>
+        }
+    }
+
+    let s = ServerRenderer::<App>::with_props(|| ContentProps { client: false })
+        .render()
+        .await;
+
+    let output_element = gloo::utils::document().get_element_by_id("output").unwrap();
+    output_element.set_inner_html(&s);
+
+    scheduler::flush().await;
+
+    let mismatches: Rc<RefCell<Vec<HydrationMismatch>>> = Rc::default();
+    let policy = {
+        let mismatches = mismatches.clone();
+        MismatchPolicy::Callback(Callback::from(move |m| mismatches.borrow_mut().push(m)))
+    };
+
+    Renderer::<App>::with_root_and_props(output_element, ContentProps { client: true })
+        .hydration_mismatch(policy)
+        .hydrate();
+
+    sleep(Duration::from_millis(10)).await;
+
+    assert_eq!(mismatches.borrow().len(), 1);
+    assert_eq!(
+        mismatches.borrow()[0].message(),
+        "expected element of kind div, found SPAN."
+    );
+
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "<p>hydrated</p><div>client</div>");
+}
+
+#[wasm_bindgen_test]
+async fn hydration_use_id_matches_server() {
+    use std::cell::RefCell;
+
+    thread_local! {
+        static CLIENT_IDS: RefCell<Vec<AttrValue>> = const { RefCell::new(Vec::new()) };
+    }
+
+    #[component(Field)]
+    fn field() -> Html {
+        let id = use_id();
+        CLIENT_IDS.with(|m| m.borrow_mut().push(id.clone()));
+
+        html! { <input id={id} /> }
+    }
+
+    #[component(App)]
+    fn app() -> Html {
+        html! {
+            <div id="result">
+                <Field />
+                <Field />
+            </div>
+        }
+    }
+
+    let s = ServerRenderer::<App>::new()
+        .id_prefix("app-")
+        .render()
+        .await;
+
+    let output_element = gloo::utils::document().get_element_by_id("output").unwrap();
+    output_element.set_inner_html(&s);
+
+    scheduler::flush().await;
+    CLIENT_IDS.with(|m| m.borrow_mut().clear());
+
+    Renderer::<App>::with_root(output_element)
+        .id_prefix("app-")
+        .hydrate();
+
+    sleep(Duration::from_millis(10)).await;
+
+    // Components are rendered twice during hydration.
+    let mut ids = CLIENT_IDS.with(|m| m.borrow().iter().map(|m| m.to_string()).collect::<Vec<_>>());
+    ids.sort();
+    ids.dedup();
+    assert_eq!(ids, ["app-0-0_0", "app-0-1_0"]);
+
+    let result = obtain_result();
+    assert_eq!(
+        result.as_str(),
+        r#"<input id="app-0-0_0"><input id="app-0-1_0">"#
+    );
+}
+
+#[cfg(feature = "hydration_diagnostics")]
+#[wasm_bindgen_test]
+async fn hydration_diagnostics_report_text_and_attributes() {
+    use std::cell::RefCell;
+
+    use yew::hydration::{HydrationMismatch, MismatchPolicy};
+
+    #[derive(Properties, PartialEq, Clone)]
+    struct ContentProps {
+        client: bool,
+    }
+
+    #[component(Content)]
+    fn content(props: &ContentProps) -> Html {
+        let (class, text) = if props.client {
+            ("client", "client")
+        } else {
+            ("server", "server")
+        };
+
+        html! { <div {class}>{text}</div> }
+    }
+
+    #[component(App)]
+    fn app(props: &ContentProps) -> Html {
+        html! {
+            <div id="result">
+                <Content client={props.client} />
+            </div>
+        }
+    }
+
+    let s = ServerRenderer::<App>::with_props(|| ContentProps { client: false })
+        .render()
+        .await;
+
+    let output_element = gloo::utils::document().get_element_by_id("output").unwrap();
+    output_element.set_inner_html(&s);
+
+    scheduler::flush().await;
+
+    let mismatches: Rc<RefCell<Vec<HydrationMismatch>>> = Rc::default();
+    let policy = {
+        let mismatches = mismatches.clone();
+        MismatchPolicy::Callback(Callback::from(move |m| mismatches.borrow_mut().push(m)))
+    };
+
+    Renderer::<App>::with_root_and_props(output_element, ContentProps { client: true })
+        .hydration_mismatch(policy)
+        .hydrate();
+
+    sleep(Duration::from_millis(10)).await;
+
+    let mismatches = mismatches.borrow();
+    assert_eq!(mismatches.len(), 2);
+    assert!(mismatches.iter().all(|m| m.repaired()));
+    assert_eq!(
+        mismatches[0].message(),
+        r#"expected attribute class="client" on div, found "server"."#
+    );
+    assert_eq!(mismatches[1].message(), r#"expected text "client"."#);
+    assert_eq!(mismatches[1].actual(), Some(r#""server""#));
+
+    let path = mismatches[1].component_path();
+    assert!(path.last().unwrap().ends_with("Content"), "{path:?}");
+
+    // Repaired differences do not cause the component to be rendered on the client side.
+    let result = obtain_result();
+    assert_eq!(result.as_str(), r#"<div class="client">client</div>"#);
+}
+
+#[cfg(feature = "hydration_diagnostics")]
+#[wasm_bindgen_test]
+async fn hydration_diagnostics_repaired_differences_do_not_panic() {
+    #[derive(Properties, PartialEq, Clone)]
+    struct AppProps {
+        client: bool,
+    }
+
+    #[component(App)]
+    fn app(props: &AppProps) -> Html {
+        let text = if props.client { "client" } else { "server" };
+
+        html! {
+            <div id="result">
+                <p class={text}>{text}</p>
+            </div>
+        }
+    }
+
+    let s = ServerRenderer::<App>::with_props(|| AppProps { client: false })
+        .render()
+        .await;
+
+    let output_element = gloo::utils::document().get_element_by_id("output").unwrap();
+    output_element.set_inner_html(&s);
+
+    scheduler::flush().await;
+
+    // The default policy panics on mismatches in debug builds.
+    Renderer::<App>::with_root_and_props(output_element, AppProps { client: true }).hydrate();
+
+    sleep(Duration::from_millis(10)).await;
+
+    let result = obtain_result();
+    assert_eq!(result.as_str(), r#"<p class="client">client</p>"#);
+}
+
+#[wasm_bindgen_test]
+async fn hydration_of_error_boundary_fallback() {
+    use std::cell::Cell;
+    use std::fmt;
+
+    use yew::error_boundary::{RenderErrorInfo, ServerRenderError};
+    use yew::html::RenderError;
+
+    #[derive(Debug)]
+    struct NotFound;
+
+    impl fmt::Display for NotFound {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "user 42 not found")
+        }
+    }
+
+    impl std::error::Error for NotFound {}
+
+    thread_local! {
+        static HYDRATED_FALLBACK: Cell<bool> = const { Cell::new(false) };
+    }
+
+    #[derive(Properties, PartialEq, Clone)]
+    struct AppProps {
+        server: bool,
+    }
+
+    #[component(Content)]
+    fn content(props: &AppProps) -> HtmlResult {
+        if props.server {
+            return Err(RenderError::failed(NotFound));
+        }
+
+        Ok(html! { <p>{"loaded"}</p> })
+    }
+
+    #[component(App)]
+    fn app(props: &AppProps) -> Html {
+        let fallback = Callback::from(|info: RenderErrorInfo| {
+            if info.error().is::<ServerRenderError>() {
+                HYDRATED_FALLBACK.with(|m| m.set(true));
+            }
+
+            html! {
+                <button onclick={info.reset_callback()}>{info.error().to_string()}</button>
+            }
+        });
+
+        html! {
+            <div id="result">
+                <ErrorBoundary {fallback}>
+                    <Content server={props.server} />
+                </ErrorBoundary>
+            </div>
+        }
+    }
+
+    let s = ServerRenderer::<App>::with_props(|| AppProps { server: true })
+        .render()
+        .await;
+
+    let output_element = gloo::utils::document().get_element_by_id("output").unwrap();
+    output_element.set_inner_html(&s);
+
+    scheduler::flush().await;
+
+    // The fallback is hydrated under the default policy, which panics on mismatches.
+    Renderer::<App>::with_root_and_props(output_element, AppProps { server: false }).hydrate();
+
+    sleep(Duration::from_millis(10)).await;
+
+    let result = obtain_result();
+    // Only the failure is sent to the client side, not the original error.
+    assert_eq!(
+        result.as_str(),
+        "<button>a component failed during server-side rendering</button>"
+    );
+    assert!(HYDRATED_FALLBACK.with(Cell::get));
+
+    gloo::utils::document()
+        .query_selector("#result button")
+        .unwrap()
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap()
+        .click();
+
+    scheduler::flush().await;
+
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "<p>loaded</p>");
+}
@@ -38,3 +38,125 @@ async fn props_are_passed() {
     let result = obtain_result();
     assert_eq!(result.as_str(), "done");
 }
+
+#[wasm_bindgen_test]
+async fn memo_components_compare_props() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    thread_local! {
+        static RENDERS: Cell<(u32, u32)> = const { Cell::new((0, 0)) };
+    }
+
+    #[derive(Properties, PartialEq)]
+    #[shallow_eq]
+    struct RowProps {
+        id: u32,
+        label: String,
+        rows: Rc<Vec<u32>>,
+    }
+
+    fn same_id(a: &RowProps, b: &RowProps) -> bool {
+        a.id == b.id
+    }
+
+    #[component(memo = same_id)]
+    fn ById(props: &RowProps) -> Html {
+        RENDERS.with(|m| m.set((m.get().0 + 1, m.get().1)));
+        html! { <span>{&props.label}</span> }
+    }
+
+    #[component(memo(shallow))]
+    fn Shallow(props: &RowProps) -> Html {
+        RENDERS.with(|m| m.set((m.get().0, m.get().1 + 1)));
+        html! { <span>{props.rows.len()}</span> }
+    }
+
+    #[component]
+    fn App() -> Html {
+        let step = use_state(|| 0);
+        let rows = use_memo(*step / 2, |_| vec![1, 2, 3]);
+
+        {
+            let step = step.clone();
+            use_effect(move || {
+                if *step < 3 {
+                    step.set(*step + 1);
+                }
+            });
+        }
+
+        html! {
+            <div id="result">
+                <ById id={*step / 2} label={step.to_string()} rows={rows.clone()} />
+                <Shallow id={0} label={String::new()} {rows} />
+            </div>
+        }
+    }
+
+    yew::Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+    scheduler::flush().await;
+
+    // Steps 0 to 3 are rendered, `id` and `rows` change at step 2.
+    assert_eq!(RENDERS.with(Cell::get), (2, 2));
+    assert_eq!(obtain_result(), "<span>2</span><span>3</span>");
+}
+
+#[cfg(feature = "profiling")]
+#[wasm_bindgen_test]
+async fn memo_components_only_record_updates_with_changed_props() {
+    use yew::profiler;
+
+    #[derive(Properties, PartialEq)]
+    struct LabelProps {
+        id: u32,
+        label: String,
+    }
+
+    fn same_id(a: &LabelProps, b: &LabelProps) -> bool {
+        a.id == b.id
+    }
+
+    #[component(memo = same_id)]
+    fn Label(props: &LabelProps) -> Html {
+        html! { <span>{&props.label}</span> }
+    }
+
+    #[component]
+    fn App() -> Html {
+        let step = use_state(|| 0);
+
+        {
+            let step = step.clone();
+            use_effect(move || {
+                if *step < 3 {
+                    step.set(*step + 1);
+                }
+            });
+        }
+
+        html! {
+            <div id="result">
+                <Label id={*step / 2} label={step.to_string()} />
+            </div>
+        }
+    }
+
+    profiler::start();
+    yew::Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+    scheduler::flush().await;
+    let profile = profiler::snapshot();
+    profiler::stop();
+
+    // The label receives new properties at steps 1 to 3, but `id` only changes at step 2.
+    let label = profile
+        .by_type()
+        .into_iter()
+        .find(|m| m.name.ends_with("::Label"))
+        .unwrap();
+    assert_eq!(label.stats.updated, 1);
+    assert_eq!(label.stats.rendered, 2);
+    assert_eq!(obtain_result(), "<span>2</span>");
+}
@@ -0,0 +1,182 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use common::{obtain_result, output_element};
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::scheduler::{self, Priority, batch, flush_sync, with_priority};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn rendered_rows() -> u32 {
+    gloo::utils::document()
+        .get_elements_by_tag_name("li")
+        .length()
+}
+
+#[wasm_bindgen_test]
+async fn transitions_yield_to_urgent_updates() {
+    thread_local! {
+        static ROWS_RENDERED: Cell<u32> = const { Cell::new(0) };
+        static ROWS_RENDERED_BEFORE_CLICK: Cell<Option<u32>> = const { Cell::new(None) };
+        static CLICK: RefCell<Option<UseStateSetter<u32>>> = const { RefCell::new(None) };
+    }
+
+    #[derive(Properties, PartialEq)]
+    struct RowProps {
+        i: usize,
+    }
+
+    #[component]
+    fn Row(props: &RowProps) -> Html {
+        ROWS_RENDERED.with(|m| m.set(m.get() + 1));
+
+        // Simulates a click while the rows are being rendered.
+        use_effect_with(props.i, |i| {
+            if *i == 10 {
+                let click = CLICK.with(|m| m.borrow().clone()).unwrap();
+                with_priority(Priority::Discrete, || click.set(1));
+            }
+        });
+
+        html! { <li>{props.i}</li> }
+    }
+
+    #[component]
+    fn App() -> Html {
+        let rows = use_state(|| 0);
+        let clicks = use_state(|| 0);
+        CLICK.with(|m| *m.borrow_mut() = Some(clicks.setter()));
+
+        if *clicks == 1 {
+            ROWS_RENDERED_BEFORE_CLICK.with(|m| {
+                if m.get().is_none() {
+                    m.set(Some(ROWS_RENDERED.with(Cell::get)));
+                }
+            });
+        }
+
+        {
+            let rows = rows.setter();
+            use_effect_with((), move |_| {
+                with_priority(Priority::Transition, || rows.set(200));
+            });
+        }
+
+        html! {
+            <div id="result">{*clicks}</div>
+            <ul>{ for (0..*rows).map(|i| html! { <Row key={i} {i} /> }) }</ul>
+        }
+    }
+
+    yew::Renderer::<App>::with_root(output_element()).render();
+    scheduler::flush().await;
+
+    // The click is rendered before the rows that are still pending.
+    let rendered_before_click = ROWS_RENDERED_BEFORE_CLICK.with(Cell::get).unwrap();
+    assert!(rendered_before_click < 200, "{rendered_before_click}");
+
+    assert_eq!(obtain_result(), "1");
+    assert_eq!(rendered_rows(), 200);
+}
+
+#[wasm_bindgen_test]
+async fn transition_effects_see_rendered_children() {
+    thread_local! {
+        static ROWS_SEEN: RefCell<Vec<u32>> = const { RefCell::new(Vec::new()) };
+    }
+
+    #[derive(Properties, PartialEq)]
+    struct RowProps {
+        i: usize,
+    }
+
+    #[component]
+    fn Row(props: &RowProps) -> Html {
+        html! { <li>{props.i}</li> }
+    }
+
+    #[component]
+    fn App() -> Html {
+        let rows = use_state(|| 0);
+
+        {
+            let rows = rows.setter();
+            use_effect_with((), move |_| {
+                with_priority(Priority::Transition, || rows.set(50));
+            });
+        }
+        use_effect_with(*rows, |_| {
+            ROWS_SEEN.with(|m| m.borrow_mut().push(rendered_rows()));
+        });
+
+        html! {
+            <ul>{ for (0..*rows).map(|i| html! { <Row key={i} {i} /> }) }</ul>
+        }
+    }
+
+    yew::Renderer::<App>::with_root(output_element()).render();
+    scheduler::flush().await;
+
+    // The effect of the transition runs once all of its rows have been rendered.
+    assert_eq!(ROWS_SEEN.with(|m| m.take()), [0, 50]);
+}
+
+#[wasm_bindgen_test]
+async fn flush_sync_renders_before_returning() {
+    type Setters = Rc<RefCell<Option<(UseStateSetter<u32>, UseStateSetter<u32>)>>>;
+
+    #[derive(Properties, Clone)]
+    struct Props {
+        setters: Setters,
+        renders: Rc<RefCell<u32>>,
+    }
+
+    impl PartialEq for Props {
+        fn eq(&self, other: &Self) -> bool {
+            Rc::ptr_eq(&self.setters, &other.setters)
+        }
+    }
+
+    #[component]
+    fn Counter(props: &Props) -> Html {
+        let a = use_state(|| 0);
+        let b = use_state(|| 0);
+        *props.renders.borrow_mut() += 1;
+        *props.setters.borrow_mut() = Some((a.setter(), b.setter()));
+
+        html! { <div id="result">{*a}{"/"}{*b}</div> }
+    }
+
+    let setters = Setters::default();
+    let renders = Rc::new(RefCell::new(0));
+    yew::Renderer::<Counter>::with_root_and_props(
+        output_element(),
+        Props {
+            setters: setters.clone(),
+            renders: renders.clone(),
+        },
+    )
+    .render();
+    scheduler::flush().await;
+    assert_eq!(obtain_result(), "0/0");
+
+    let (a, b) = setters.borrow().clone().unwrap();
+    flush_sync(|| a.set(1));
+    assert_eq!(obtain_result(), "1/0");
+    assert_eq!(*renders.borrow(), 2);
+
+    // Both updates are rendered at once.
+    flush_sync(|| {
+        batch(|| {
+            a.set(2);
+            b.set(2);
+        })
+    });
+    assert_eq!(obtain_result(), "2/2");
+    assert_eq!(*renders.borrow(), 3);
+}
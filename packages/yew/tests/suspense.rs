@@ -13,7 +13,10 @@ use web_sys::{HtmlElement, HtmlTextAreaElement};
 use yew::platform::spawn_local;
 use yew::platform::time::sleep;
 use yew::prelude::*;
-use yew::suspense::{Suspension, SuspensionResult, use_future, use_future_with};
+use yew::suspense::{
+    FutureState, Suspension, SuspensionResult, use_future, use_future_state, use_future_with,
+    use_transition,
+};
 use yew::{UseStateHandle, scheduler};
 
 wasm_bindgen_test_configure!(run_in_browser);
@@ -982,3 +985,501 @@ async fn sibling_suspensions_effects_see_dom() {
         );
     }
 }
+
+#[wasm_bindgen_test]
+async fn transition_keeps_content_while_suspended() {
+    #[derive(PartialEq, Properties)]
+    struct ContentProps {
+        page: u64,
+    }
+
+    #[component(Content)]
+    fn content(ContentProps { page }: &ContentProps) -> HtmlResult {
+        let page = use_future_with(*page, |page| async move {
+            sleep(Duration::from_millis(50)).await;
+            *page
+        })?;
+
+        Ok(html! { <div>{*page}</div> })
+    }
+
+    #[component(App)]
+    fn app() -> Html {
+        let page = use_state(|| 0);
+        let (is_pending, start_transition) = use_transition();
+
+        let onclick = {
+            let page = page.clone();
+            move |_| {
+                let page = page.clone();
+                start_transition.run(move || page.set(*page + 1));
+            }
+        };
+
+        let fallback = html! {<div>{"wait..."}</div>};
+
+        html! {
+            <button class="next" {onclick}>{"Next"}</button>
+            <div id="result">
+                if is_pending {
+                    <span>{"pending"}</span>
+                }
+                <Suspense {fallback}>
+                    <Content page={*page} />
+                </Suspense>
+            </div>
+        }
+    }
+
+    yew::Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+
+    // The first mount falls back as usual.
+    sleep(Duration::from_millis(10)).await;
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "<div>wait...</div>");
+
+    sleep(Duration::from_millis(60)).await;
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "<div>0</div>");
+
+    gloo::utils::document()
+        .query_selector(".next")
+        .unwrap()
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap()
+        .click();
+
+    // The previous content stays visible while the transition is pending.
+    sleep(Duration::from_millis(10)).await;
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "<span>pending</span><div>0</div>");
+
+    sleep(Duration::from_millis(60)).await;
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "<div>1</div>");
+}
+
+#[wasm_bindgen_test]
+async fn fallback_respects_delay_and_min_duration() {
+    #[derive(PartialEq, Properties)]
+    struct ContentProps {
+        page: u64,
+    }
+
+    #[component(Content)]
+    fn content(ContentProps { page }: &ContentProps) -> HtmlResult {
+        let page = use_future_with(*page, |page| async move {
+            // The first page loads faster than the delay of the suspense.
+            let ms = if *page == 0 { 20 } else { 100 };
+            sleep(Duration::from_millis(ms)).await;
+            *page
+        })?;
+
+        Ok(html! { <div>{*page}</div> })
+    }
+
+    #[component(App)]
+    fn app() -> Html {
+        let page = use_state(|| 0);
+
+        let onclick = {
+            let page = page.clone();
+            move |_| page.set(*page + 1)
+        };
+
+        let fallback = html! {<div>{"wait..."}</div>};
+
+        html! {
+            <button class="next" {onclick}>{"Next"}</button>
+            <div id="result">
+                <Suspense
+                    {fallback}
+                    delay={Duration::from_millis(50)}
+                    min_duration={Duration::from_millis(100)}
+                >
+                    <Content page={*page} />
+                </Suspense>
+            </div>
+        }
+    }
+
+    yew::Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+
+    // The fallback is not shown for suspensions shorter than the delay.
+    sleep(Duration::from_millis(10)).await;
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "");
+
+    sleep(Duration::from_millis(30)).await;
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "<div>0</div>");
+
+    gloo::utils::document()
+        .query_selector(".next")
+        .unwrap()
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap()
+        .click();
+
+    // The previous content stays visible until the delay has elapsed.
+    sleep(Duration::from_millis(30)).await;
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "<div>0</div>");
+
+    sleep(Duration::from_millis(50)).await;
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "<div>wait...</div>");
+
+    // The content is ready, but the fallback is held for its minimum duration.
+    sleep(Duration::from_millis(40)).await;
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "<div>wait...</div>");
+
+    sleep(Duration::from_millis(60)).await;
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "<div>1</div>");
+}
+
+#[wasm_bindgen_test]
+async fn use_future_with_aborts_outdated_futures() {
+    thread_local! {
+        static COMPLETED: RefCell<Vec<u64>> = const { RefCell::new(Vec::new()) };
+    }
+
+    #[derive(PartialEq, Properties)]
+    struct ContentProps {
+        delay_millis: u64,
+    }
+
+    #[component]
+    fn Content(ContentProps { delay_millis }: &ContentProps) -> HtmlResult {
+        let delayed_result = use_future_with(*delay_millis, |delay_millis| async move {
+            sleep(Duration::from_millis(*delay_millis)).await;
+            COMPLETED.with(|m| m.borrow_mut().push(*delay_millis));
+            *delay_millis
+        })?;
+
+        let onclick = {
+            let delayed_result = delayed_result.clone();
+            Callback::from(move |_| delayed_result.restart())
+        };
+
+        Ok(html! {
+            <div>
+                <button class="restart" {onclick}>{"restart"}</button>
+                {*delayed_result}
+            </div>
+        })
+    }
+
+    #[component]
+    fn App() -> Html {
+        let delay_millis = use_state(|| 50);
+
+        {
+            let delay_millis = delay_millis.setter();
+            use_effect_with((), move |_| {
+                spawn_local(async move {
+                    sleep(Duration::from_millis(10)).await;
+                    delay_millis.set(30);
+                });
+            });
+        }
+
+        html! {
+            <div id="result">
+                <Suspense fallback={html! {<div>{"wait..."}</div>}}>
+                    <Content delay_millis={*delay_millis} />
+                </Suspense>
+            </div>
+        }
+    }
+
+    yew::Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+
+    sleep(Duration::from_millis(100)).await;
+    let result = obtain_result();
+    assert_eq!(
+        result.as_str(),
+        r#"<div><button class="restart">restart</button>30</div>"#
+    );
+    // The future for the first delay has been aborted.
+    COMPLETED.with(|m| assert_eq!(*m.borrow(), [30]));
+
+    gloo::utils::document()
+        .query_selector(".restart")
+        .unwrap()
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap()
+        .click();
+
+    sleep(Duration::from_millis(10)).await;
+    let result = obtain_result();
+    assert_eq!(result.as_str(), "<div>wait...</div>");
+
+    sleep(Duration::from_millis(50)).await;
+    let result = obtain_result();
+    assert_eq!(
+        result.as_str(),
+        r#"<div><button class="restart">restart</button>30</div>"#
+    );
+    COMPLETED.with(|m| assert_eq!(*m.borrow(), [30, 30]));
+}
+
+#[wasm_bindgen_test]
+async fn use_future_state_retries_failed_futures() {
+    thread_local! {
+        static ATTEMPTS: RefCell<u32> = const { RefCell::new(0) };
+    }
+
+    #[component]
+    fn App() -> Html {
+        let res = use_future_state(|| async {
+            sleep(Duration::from_millis(20)).await;
+            let attempt = ATTEMPTS.with(|m| {
+                *m.borrow_mut() += 1;
+                *m.borrow()
+            });
+
+            if attempt == 1 {
+                Err("failed")
+            } else {
+                Ok(attempt)
+            }
+        });
+
+        let onclick = {
+            let res = res.clone();
+            Callback::from(move |_| res.retry())
+        };
+
+        let content = match &*res {
+            FutureState::Loading => html! { "loading" },
+            FutureState::Ready(m) => html! { {*m} },
+            FutureState::Failed(e) => html! { {*e} },
+        };
+
+        html! {
+            <div id="result">
+                <button class="retry" {onclick}>{"retry"}</button>
+                {content}
+            </div>
+        }
+    }
+
+    yew::Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+
+    scheduler::flush().await;
+    let result = obtain_result();
+    assert_eq!(
+        result.as_str(),
+        r#"<button class="retry">retry</button>loading"#
+    );
+
+    sleep(Duration::from_millis(40)).await;
+    let result = obtain_result();
+    assert_eq!(
+        result.as_str(),
+        r#"<button class="retry">retry</button>failed"#
+    );
+
+    gloo::utils::document()
+        .query_selector(".retry")
+        .unwrap()
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap()
+        .click();
+
+    scheduler::flush().await;
+    let result = obtain_result();
+    assert_eq!(
+        result.as_str(),
+        r#"<button class="retry">retry</button>loading"#
+    );
+
+    sleep(Duration::from_millis(40)).await;
+    let result = obtain_result();
+    assert_eq!(result.as_str(), r#"<button class="retry">retry</button>2"#);
+}
+
+mod suspense_list {
+    use yew::suspense::{RevealOrder, SuspenseList, SuspenseListTail, SuspensionHandle};
+
+    use super::*;
+
+    /// Suspends items until they are resumed by the test.
+    #[derive(Default, Clone)]
+    struct Gates {
+        resumed: Rc<RefCell<Vec<&'static str>>>,
+        pending: Rc<RefCell<Vec<(&'static str, SuspensionHandle)>>>,
+    }
+
+    impl PartialEq for Gates {
+        fn eq(&self, other: &Self) -> bool {
+            Rc::ptr_eq(&self.resumed, &other.resumed)
+        }
+    }
+
+    impl Gates {
+        fn wait(&self, name: &'static str) -> SuspensionResult<()> {
+            if self.resumed.borrow().contains(&name) {
+                return Ok(());
+            }
+
+            let (s, handle) = Suspension::new();
+            self.pending.borrow_mut().push((name, handle));
+            Err(s)
+        }
+
+        async fn resume(&self, name: &'static str) {
+            self.resumed.borrow_mut().push(name);
+
+            let handles = self
+                .pending
+                .borrow_mut()
+                .extract_if(.., |(m, _)| *m == name)
+                .collect::<Vec<_>>();
+            for (_, handle) in handles {
+                handle.resume();
+            }
+
+            scheduler::flush().await;
+        }
+    }
+
+    #[derive(Properties, PartialEq)]
+    struct ItemProps {
+        name: &'static str,
+        gates: Gates,
+    }
+
+    #[component]
+    fn Item(props: &ItemProps) -> HtmlResult {
+        props.gates.wait(props.name)?;
+
+        Ok(html! { <b>{props.name}</b> })
+    }
+
+    #[derive(Properties, PartialEq)]
+    struct AppProps {
+        reveal_order: RevealOrder,
+        tail: SuspenseListTail,
+        gates: Gates,
+    }
+
+    #[component]
+    fn App(props: &AppProps) -> Html {
+        let items = ["a", "b", "c"].map(|name| {
+            html! {
+                <Suspense fallback={html! { <i>{name}</i> }}>
+                    <Item {name} gates={props.gates.clone()} />
+                </Suspense>
+            }
+        });
+
+        html! {
+            <div id="result">
+                <SuspenseList reveal_order={props.reveal_order} tail={props.tail}>
+                    {for items}
+                </SuspenseList>
+            </div>
+        }
+    }
+
+    async fn render(reveal_order: RevealOrder, tail: SuspenseListTail) -> Gates {
+        let gates = Gates::default();
+
+        yew::Renderer::<App>::with_root_and_props(
+            gloo::utils::document().get_element_by_id("output").unwrap(),
+            AppProps {
+                reveal_order,
+                tail,
+                gates: gates.clone(),
+            },
+        )
+        .render();
+        scheduler::flush().await;
+
+        gates
+    }
+
+    #[wasm_bindgen_test]
+    async fn reveals_forwards() {
+        let gates = render(RevealOrder::Forwards, SuspenseListTail::Visible).await;
+        assert_eq!(obtain_result(), "<i>a</i><i>b</i><i>c</i>");
+
+        // b is only revealed once a is.
+        gates.resume("b").await;
+        assert_eq!(obtain_result(), "<i>a</i><i>b</i><i>c</i>");
+
+        gates.resume("a").await;
+        assert_eq!(obtain_result(), "<b>a</b><b>b</b><i>c</i>");
+
+        gates.resume("c").await;
+        assert_eq!(obtain_result(), "<b>a</b><b>b</b><b>c</b>");
+    }
+
+    #[wasm_bindgen_test]
+    async fn reveals_backwards() {
+        let gates = render(RevealOrder::Backwards, SuspenseListTail::Visible).await;
+
+        gates.resume("a").await;
+        assert_eq!(obtain_result(), "<i>a</i><i>b</i><i>c</i>");
+
+        gates.resume("c").await;
+        assert_eq!(obtain_result(), "<i>a</i><i>b</i><b>c</b>");
+
+        gates.resume("b").await;
+        assert_eq!(obtain_result(), "<b>a</b><b>b</b><b>c</b>");
+    }
+
+    #[wasm_bindgen_test]
+    async fn reveals_together() {
+        let gates = render(RevealOrder::Together, SuspenseListTail::Visible).await;
+
+        gates.resume("c").await;
+        gates.resume("a").await;
+        assert_eq!(obtain_result(), "<i>a</i><i>b</i><i>c</i>");
+
+        gates.resume("b").await;
+        assert_eq!(obtain_result(), "<b>a</b><b>b</b><b>c</b>");
+    }
+
+    #[wasm_bindgen_test]
+    async fn collapsed_tail_shows_next_fallback() {
+        let gates = render(RevealOrder::Forwards, SuspenseListTail::Collapsed).await;
+        assert_eq!(obtain_result(), "<i>a</i>");
+
+        gates.resume("c").await;
+        assert_eq!(obtain_result(), "<i>a</i>");
+
+        gates.resume("a").await;
+        assert_eq!(obtain_result(), "<b>a</b><i>b</i>");
+
+        gates.resume("b").await;
+        assert_eq!(obtain_result(), "<b>a</b><b>b</b><b>c</b>");
+    }
+
+    #[wasm_bindgen_test]
+    async fn hidden_tail_shows_no_fallback() {
+        let gates = render(RevealOrder::Forwards, SuspenseListTail::Hidden).await;
+        assert_eq!(obtain_result(), "");
+
+        gates.resume("b").await;
+        assert_eq!(obtain_result(), "");
+
+        gates.resume("a").await;
+        assert_eq!(obtain_result(), "<b>a</b><b>b</b>");
+
+        gates.resume("c").await;
+        assert_eq!(obtain_result(), "<b>a</b><b>b</b><b>c</b>");
+    }
+}
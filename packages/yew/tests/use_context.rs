@@ -264,3 +264,80 @@ async fn use_context_update_works() {
         "current: hello world!, total: 4"
     );
 }
+
+#[wasm_bindgen_test]
+async fn use_context_selector_renders_on_slice_change() {
+    #[derive(Clone, Debug, PartialEq)]
+    struct AppState {
+        name: String,
+        counter: u32,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Properties)]
+    struct SelectorProps {
+        id: String,
+    }
+
+    #[component]
+    fn NameOutlet(props: &SelectorProps) -> Html {
+        let counter = use_mut_ref(|| 0);
+        *counter.borrow_mut() += 1;
+
+        let name = use_context_selector::<AppState, _>(|m| m.name.clone())
+            .expect("context not initialized");
+
+        html! {
+            <div id={props.id.clone()}>
+                {format!("current: {}, total: {}", name, counter.borrow())}
+            </div>
+        }
+    }
+
+    #[component]
+    fn TestComponent() -> Html {
+        let state = use_state(|| AppState {
+            name: "hello".into(),
+            counter: 0,
+        });
+        let rendered = use_mut_ref(|| 0);
+
+        {
+            let state = state.clone();
+            use_effect(move || {
+                let count = *rendered.borrow();
+                let next = match count {
+                    0 | 1 => Some(AppState {
+                        counter: state.counter + 1,
+                        ..(*state).clone()
+                    }),
+                    2 => Some(AppState {
+                        name: "world".into(),
+                        ..(*state).clone()
+                    }),
+                    _ => None,
+                };
+                if let Some(next) = next {
+                    *rendered.borrow_mut() += 1;
+                    state.set(next);
+                }
+                || {}
+            });
+        }
+
+        html! {
+            <ContextProvider<AppState> context={(*state).clone()}>
+                <NameOutlet id="test-0" />
+            </ContextProvider<AppState>>
+        }
+    }
+
+    yew::Renderer::<TestComponent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+
+    scheduler::flush().await;
+
+    // 1 initial + 1 name update, counter updates are ignored.
+    assert_eq!(obtain_result_by_id("test-0"), "current: world, total: 2");
+}
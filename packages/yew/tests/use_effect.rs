@@ -185,3 +185,189 @@ async fn use_effect_refires_on_dependency_change() {
 
     assert_eq!(result.as_str(), "11");
 }
+
+#[wasm_bindgen_test]
+async fn use_layout_effect_runs_before_use_effect() {
+    thread_local! {
+        static CALLS: std::cell::RefCell<Vec<String>> = const { std::cell::RefCell::new(Vec::new()) };
+    }
+
+    #[component]
+    fn Child() -> Html {
+        use_layout_effect(|| CALLS.with(|m| m.borrow_mut().push("child layout".into())));
+
+        html! {}
+    }
+
+    #[component]
+    fn Measured() -> Html {
+        let node = use_node_ref();
+        let width = use_state_eq(|| 0);
+
+        {
+            let node = node.clone();
+            let width = width.clone();
+            use_layout_effect_with((), move |_| {
+                let measured = node
+                    .cast::<web_sys::HtmlElement>()
+                    .map(|m| m.text_content().unwrap_or_default().len())
+                    .unwrap_or_default();
+                CALLS.with(|m| m.borrow_mut().push(format!("layout {measured}")));
+                width.set(measured);
+            });
+        }
+
+        {
+            let width = *width;
+            use_effect(move || CALLS.with(|m| m.borrow_mut().push(format!("effect {width}"))));
+        }
+
+        html! {
+            <div id="result">
+                <Child />
+                <span ref={node}>{"hello"}</span>
+                {*width}
+            </div>
+        }
+    }
+
+    yew::Renderer::<Measured>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    scheduler::flush().await;
+
+    assert_eq!(obtain_result(), "<span>hello</span>5");
+    CALLS.with(|m| {
+        assert_eq!(
+            *m.borrow(),
+            [
+                "child layout",
+                "layout 5",
+                "effect 0",
+                "child layout",
+                "effect 5"
+            ]
+        )
+    });
+}
+
+#[wasm_bindgen_test]
+async fn use_layout_effect_measures_updated_children() {
+    thread_local! {
+        static MEASURED: std::cell::RefCell<Vec<usize>> = const { std::cell::RefCell::new(Vec::new()) };
+    }
+
+    #[derive(Properties, PartialEq)]
+    struct LabelProps {
+        text: AttrValue,
+    }
+
+    #[component]
+    fn Label(props: &LabelProps) -> Html {
+        html! { <span>{props.text.clone()}</span> }
+    }
+
+    #[component]
+    fn Parent() -> Html {
+        let node = use_node_ref();
+        let text = use_state(|| AttrValue::from("a"));
+
+        {
+            let node = node.clone();
+            use_layout_effect(move || {
+                let measured = node
+                    .cast::<web_sys::HtmlElement>()
+                    .and_then(|m| m.text_content())
+                    .unwrap_or_default()
+                    .len();
+                MEASURED.with(|m| m.borrow_mut().push(measured));
+            });
+        }
+
+        {
+            let text = text.setter();
+            use_effect_with((), move |_| text.set("hello".into()));
+        }
+
+        html! {
+            <div ref={node}><Label text={(*text).clone()} /></div>
+        }
+    }
+
+    yew::Renderer::<Parent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    scheduler::flush().await;
+
+    // The layout effect of the parent sees the updated child.
+    MEASURED.with(|m| assert_eq!(*m.borrow(), [1, 5]));
+}
+
+#[wasm_bindgen_test]
+async fn use_async_effect_with_drops_outdated_futures() {
+    use std::time::Duration;
+
+    use yew::platform::time::sleep;
+
+    thread_local! {
+        static CALLS: std::cell::RefCell<Vec<String>> = const { std::cell::RefCell::new(Vec::new()) };
+    }
+
+    #[derive(Properties, PartialEq)]
+    struct Props {
+        query: u32,
+    }
+
+    #[component]
+    fn Search(props: &Props) -> Html {
+        use_async_effect_with(props.query, |query| {
+            let query = *query;
+            async move {
+                sleep(Duration::from_millis(20)).await;
+                CALLS.with(|m| m.borrow_mut().push(format!("searched {query}")));
+
+                move || async move {
+                    CALLS.with(|m| m.borrow_mut().push(format!("cleaned {query}")));
+                }
+            }
+        });
+
+        html! {}
+    }
+
+    #[component]
+    fn App() -> Html {
+        let query = use_state(|| 1);
+        let show = use_state(|| true);
+
+        {
+            let query = query.setter();
+            let show = show.setter();
+            use_effect_with((), move |_| {
+                yew::platform::spawn_local(async move {
+                    sleep(Duration::from_millis(5)).await;
+                    query.set(2);
+                    sleep(Duration::from_millis(40)).await;
+                    show.set(false);
+                });
+            });
+        }
+
+        html! {
+            <div id="result">
+                if *show {
+                    <Search query={*query} />
+                }
+            </div>
+        }
+    }
+
+    yew::Renderer::<App>::with_root(gloo::utils::document().get_element_by_id("output").unwrap())
+        .render();
+
+    sleep(Duration::from_millis(100)).await;
+
+    CALLS.with(|m| assert_eq!(*m.borrow(), ["searched 2", "cleaned 2"]));
+}
@@ -248,3 +248,94 @@ async fn use_reducer_does_not_rerender_when_rc_is_reused() {
     let result = obtain_result();
     assert_eq!(result.as_str(), "2");
 }
+
+enum FetchAction {
+    Load(u32),
+    Loaded(u32),
+}
+
+#[derive(Default)]
+struct FetchState {
+    loading: bool,
+    value: Option<u32>,
+}
+
+impl Reducible for FetchState {
+    type Action = FetchAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        match action {
+            FetchAction::Load(_) => Self {
+                loading: true,
+                value: None,
+            },
+            FetchAction::Loaded(m) => Self {
+                loading: false,
+                value: Some(m),
+            },
+        }
+        .into()
+    }
+}
+
+thread_local! {
+    static EFFECTS_COMPLETED: std::cell::Cell<u32> = const { std::cell::Cell::new(0) };
+}
+
+impl AsyncReducible for FetchState {
+    fn effect(self: &Rc<Self>, action: &Self::Action) -> Option<ReducerEffect<Self::Action>> {
+        match *action {
+            FetchAction::Load(m) => Some(Box::pin(async move {
+                yew::platform::time::sleep(std::time::Duration::from_millis(20)).await;
+                EFFECTS_COMPLETED.with(|c| c.set(c.get() + 1));
+                FetchAction::Loaded(m * 2)
+            })),
+            FetchAction::Loaded(_) => None,
+        }
+    }
+}
+
+#[component]
+fn FetchComponent() -> Html {
+    let state = use_async_reducer(FetchState::default);
+
+    {
+        let state = state.clone();
+        use_effect_with((), move |_| state.dispatch(FetchAction::Load(21)));
+    }
+
+    let result = match (state.loading, state.value) {
+        (true, _) => "loading".to_string(),
+        (false, Some(m)) => m.to_string(),
+        (false, None) => "idle".to_string(),
+    };
+
+    html! { <div id="result">{result}</div> }
+}
+
+#[wasm_bindgen_test]
+async fn use_async_reducer_dispatches_effect_results() {
+    yew::Renderer::<FetchComponent>::with_root(document().get_element_by_id("output").unwrap())
+        .render();
+    scheduler::flush().await;
+    assert_eq!(obtain_result(), "loading");
+
+    yew::platform::time::sleep(std::time::Duration::from_millis(50)).await;
+    scheduler::flush().await;
+    assert_eq!(obtain_result(), "42");
+}
+
+#[wasm_bindgen_test]
+async fn use_async_reducer_cancels_effects_on_destroy() {
+    EFFECTS_COMPLETED.with(|c| c.set(0));
+
+    let app =
+        yew::Renderer::<FetchComponent>::with_root(document().get_element_by_id("output").unwrap())
+            .render();
+    scheduler::flush().await;
+    app.destroy();
+    scheduler::flush().await;
+
+    yew::platform::time::sleep(std::time::Duration::from_millis(50)).await;
+    assert_eq!(EFFECTS_COMPLETED.with(|c| c.get()), 0);
+}
@@ -0,0 +1,65 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::cell::RefCell;
+use std::time::Duration;
+
+use common::{obtain_result, output_element};
+use futures::channel::mpsc::{self, UnboundedSender};
+use wasm_bindgen_test::*;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+use yew::scheduler;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+thread_local! {
+    static SENDERS: RefCell<Vec<UnboundedSender<u32>>> = const { RefCell::new(Vec::new()) };
+}
+
+fn sender_closed(index: usize) -> bool {
+    SENDERS.with(|s| s.borrow()[index].is_closed())
+}
+
+#[derive(Properties, PartialEq)]
+struct Props {
+    deps: u32,
+}
+
+#[component]
+fn Latest(props: &Props) -> Html {
+    let latest = use_stream(props.deps, |_| {
+        let (tx, rx) = mpsc::unbounded();
+        SENDERS.with(|s| s.borrow_mut().push(tx));
+        rx
+    });
+
+    html! { <div id="result">{latest.map(|m| *m)}</div> }
+}
+
+#[wasm_bindgen_test]
+async fn use_stream_is_aborted_and_restarted() {
+    let mut app =
+        yew::Renderer::<Latest>::with_root_and_props(output_element(), Props { deps: 1 }).render();
+    scheduler::flush().await;
+
+    SENDERS.with(|s| s.borrow()[0].unbounded_send(7).unwrap());
+    sleep(Duration::ZERO).await;
+    scheduler::flush().await;
+    assert_eq!(obtain_result(), "7");
+
+    // Changing the dependencies aborts the first stream and starts a new one.
+    app.update(Props { deps: 2 });
+    scheduler::flush().await;
+    sleep(Duration::ZERO).await;
+    assert!(sender_closed(0));
+    assert_eq!(SENDERS.with(|s| s.borrow().len()), 2);
+    assert!(!sender_closed(1));
+
+    // Unmounting aborts the second stream.
+    app.destroy();
+    scheduler::flush().await;
+    sleep(Duration::ZERO).await;
+    assert!(sender_closed(1));
+}
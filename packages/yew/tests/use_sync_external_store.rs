@@ -0,0 +1,69 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::cell::{Cell, RefCell};
+
+use common::obtain_result;
+use wasm_bindgen_test::*;
+use yew::prelude::*;
+use yew::scheduler;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+thread_local! {
+    static VALUE: Cell<u32> = const { Cell::new(0) };
+    static LISTENERS: RefCell<Vec<Callback<()>>> = const { RefCell::new(Vec::new()) };
+    static RENDERS: Cell<u32> = const { Cell::new(0) };
+}
+
+fn set_value(value: u32) {
+    VALUE.with(|m| m.set(value));
+    LISTENERS.with(|m| m.borrow().iter().for_each(|m| m.emit(())));
+}
+
+#[component(UseComponent)]
+fn use_sync_external_store_comp() -> Html {
+    let value = use_sync_external_store(
+        |on_change| {
+            LISTENERS.with(|m| m.borrow_mut().push(on_change.clone()));
+            move || LISTENERS.with(|m| m.borrow_mut().retain(|m| m != &on_change))
+        },
+        || VALUE.with(|m| m.get()),
+        || 0,
+    );
+    RENDERS.with(|m| m.set(m.get() + 1));
+
+    html! {
+        <div>
+            {"Test Output: "}
+            <div id="result">{value}</div>
+            {"\n"}
+        </div>
+    }
+}
+
+#[wasm_bindgen_test]
+async fn use_sync_external_store_works() {
+    let app = yew::Renderer::<UseComponent>::with_root(
+        gloo::utils::document().get_element_by_id("output").unwrap(),
+    )
+    .render();
+    scheduler::flush().await;
+    assert_eq!(obtain_result().as_str(), "0");
+    let renders = RENDERS.with(|m| m.get());
+
+    set_value(3);
+    scheduler::flush().await;
+    assert_eq!(obtain_result().as_str(), "3");
+    assert_eq!(RENDERS.with(|m| m.get()), renders + 1);
+
+    // Notifications that do not change the snapshot do not re-render.
+    set_value(3);
+    scheduler::flush().await;
+    assert_eq!(RENDERS.with(|m| m.get()), renders + 1);
+
+    app.destroy();
+    scheduler::flush().await;
+    assert!(LISTENERS.with(|m| m.borrow().is_empty()));
+}
@@ -0,0 +1,125 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use common::{obtain_result, output_element};
+use wasm_bindgen_test::*;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+use yew::scheduler;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn use_interval_stops_on_destroy() {
+    #[derive(Properties, Clone)]
+    struct Props {
+        ticks: Rc<Cell<u32>>,
+    }
+
+    impl PartialEq for Props {
+        fn eq(&self, other: &Self) -> bool {
+            Rc::ptr_eq(&self.ticks, &other.ticks)
+        }
+    }
+
+    #[component]
+    fn Ticker(props: &Props) -> Html {
+        let ticks = props.ticks.clone();
+        use_interval(20, move || ticks.set(ticks.get() + 1));
+
+        html! { <div id="result">{"ticking"}</div> }
+    }
+
+    let ticks = Rc::new(Cell::new(0));
+    let app = yew::Renderer::<Ticker>::with_root_and_props(
+        output_element(),
+        Props {
+            ticks: ticks.clone(),
+        },
+    )
+    .render();
+
+    sleep(Duration::from_millis(70)).await;
+    let ticked = ticks.get();
+    assert!((2..=4).contains(&ticked), "ticked {ticked} times");
+
+    app.destroy();
+    scheduler::flush().await;
+
+    sleep(Duration::from_millis(60)).await;
+    assert_eq!(ticks.get(), ticked);
+}
+
+#[wasm_bindgen_test]
+async fn use_timeout_can_be_cancelled_and_reset() {
+    #[component]
+    fn Notification() -> Html {
+        let visible = use_state(|| true);
+        let timeout = {
+            let visible = visible.clone();
+            use_timeout(30, move || visible.set(false))
+        };
+
+        {
+            let timeout = timeout.clone();
+            use_effect_with((), move |_| {
+                yew::platform::spawn_local(async move {
+                    sleep(Duration::from_millis(10)).await;
+                    timeout.cancel();
+                    sleep(Duration::from_millis(40)).await;
+                    timeout.reset();
+                });
+            });
+        }
+
+        html! { <div id="result">{*visible}</div> }
+    }
+
+    yew::Renderer::<Notification>::with_root(output_element()).render();
+
+    // The timeout is cancelled before it elapses.
+    sleep(Duration::from_millis(40)).await;
+    assert_eq!(obtain_result(), "true");
+
+    // The timeout is reset after 50ms and elapses after 80ms.
+    sleep(Duration::from_millis(20)).await;
+    assert_eq!(obtain_result(), "true");
+    sleep(Duration::from_millis(40)).await;
+    assert_eq!(obtain_result(), "false");
+}
+
+#[wasm_bindgen_test]
+async fn use_debounced_value_waits_for_value_to_settle() {
+    #[component]
+    fn Search() -> Html {
+        let query = use_state(|| "y".to_owned());
+        let debounced = use_debounced_value((*query).clone(), 30);
+
+        {
+            let query = query.setter();
+            use_effect_with((), move |_| {
+                yew::platform::spawn_local(async move {
+                    sleep(Duration::from_millis(10)).await;
+                    query.set("ye".to_owned());
+                    sleep(Duration::from_millis(10)).await;
+                    query.set("yew".to_owned());
+                });
+            });
+        }
+
+        html! { <div id="result">{debounced}</div> }
+    }
+
+    yew::Renderer::<Search>::with_root(output_element()).render();
+
+    sleep(Duration::from_millis(40)).await;
+    assert_eq!(obtain_result(), "y");
+
+    sleep(Duration::from_millis(30)).await;
+    assert_eq!(obtain_result(), "yew");
+}
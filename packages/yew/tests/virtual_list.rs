@@ -0,0 +1,84 @@
+#![cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+
+mod common;
+
+use std::time::Duration;
+
+use common::output_element;
+use wasm_bindgen_test::*;
+use yew::platform::time::sleep;
+use yew::prelude::*;
+use yew::scheduler;
+use yew::virtual_list::{ItemHeight, VirtualList, VirtualListHandle};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn rendered_rows() -> Vec<String> {
+    let rows = gloo::utils::document().get_elements_by_tag_name("p");
+    (0..rows.length())
+        .filter_map(|i| rows.item(i)?.text_content())
+        .collect()
+}
+
+#[derive(Properties, PartialEq)]
+struct AppProps {
+    item_height: ItemHeight,
+    handle: VirtualListHandle,
+}
+
+#[component]
+fn App(props: &AppProps) -> Html {
+    let item = Callback::from(|index: usize| {
+        // Every tenth row is twice as high.
+        let height = if index % 10 == 0 { 40 } else { 20 };
+        html! { <p style={format!("margin:0;height:{height}px;")}>{index}</p> }
+    });
+
+    html! {
+        <VirtualList
+            item_count={10_000}
+            {item}
+            item_height={props.item_height}
+            height={100.0}
+            overscan={1}
+            handle={props.handle.clone()}
+        />
+    }
+}
+
+async fn scroll_to_index(item_height: ItemHeight) -> Vec<String> {
+    let handle = VirtualListHandle::default();
+    yew::Renderer::<App>::with_root_and_props(
+        output_element(),
+        AppProps {
+            item_height,
+            handle: handle.clone(),
+        },
+    )
+    .render();
+    scheduler::flush().await;
+    assert!(rendered_rows().len() < 10);
+
+    handle.scroll_to_index(5000);
+    // Wait for the scroll event.
+    sleep(Duration::from_millis(50)).await;
+    scheduler::flush().await;
+
+    rendered_rows()
+}
+
+#[wasm_bindgen_test]
+async fn fixed_heights_render_visible_rows() {
+    let rows = scroll_to_index(ItemHeight::Fixed(20.0)).await;
+    assert_eq!(
+        rows,
+        (4999..5006).map(|i| i.to_string()).collect::<Vec<_>>()
+    );
+}
+
+#[wasm_bindgen_test]
+async fn measured_heights_render_visible_rows() {
+    let rows = scroll_to_index(ItemHeight::Measured(20.0)).await;
+    assert!(rows.len() < 10);
+    assert!(rows.contains(&"5000".to_owned()));
+}
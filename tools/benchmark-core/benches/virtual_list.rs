@@ -0,0 +1,61 @@
+//! Compares server-side rendering a keyed list of all rows with rendering a `VirtualList`.
+//!
+//! This measures rendering to a string with `LocalServerRenderer`. Mounting and scrolling the lists
+//! in the browser is measured by `packages/yew/benches/virtual_list.rs`.
+
+use tokio::runtime::{Builder, Runtime};
+use yew::LocalServerRenderer;
+use yew::prelude::*;
+use yew::virtual_list::{ItemHeight, VirtualList};
+
+fn main() {
+    divan::main();
+}
+
+const ROWS: usize = 10_000;
+
+fn row(index: usize) -> Html {
+    html! {
+        <div class="row">
+            <span>{index}</span>
+            <strong>{"A row of a long list"}</strong>
+        </div>
+    }
+}
+
+#[component]
+fn KeyedList() -> Html {
+    html! {
+        <div>
+            { for (0..ROWS).map(|index| html! { <div key={index}>{row(index)}</div> }) }
+        </div>
+    }
+}
+
+#[component]
+fn VirtualizedList() -> Html {
+    html! {
+        <VirtualList
+            item_count={ROWS}
+            item={Callback::from(row)}
+            item_height={ItemHeight::Fixed(24.0)}
+            height={600.0}
+        />
+    }
+}
+
+fn runtime() -> Runtime {
+    Builder::new_current_thread().build().unwrap()
+}
+
+#[divan::bench(sample_size = 10)]
+fn keyed_list_render(bencher: divan::Bencher) {
+    let rt = runtime();
+    bencher.bench_local(|| rt.block_on(LocalServerRenderer::<KeyedList>::new().render()));
+}
+
+#[divan::bench(sample_size = 10)]
+fn virtual_list_render(bencher: divan::Bencher) {
+    let rt = runtime();
+    bencher.bench_local(|| rt.block_on(LocalServerRenderer::<VirtualizedList>::new().render()));
+}
@@ -95,6 +95,7 @@ module.exports = {
                 'concepts/contexts',
                 'concepts/router',
                 'concepts/suspense',
+                'concepts/error-boundaries',
             ],
         },
         {